@@ -2,6 +2,15 @@
 //!
 //! Basic DNA encoding: 00→A(0), 01→T(1), 10→C(2), 11→G(3)
 //! Each byte produces 4 DNA bases (2 bits per base).
+//!
+//! Nanopore, Secure, and SplitKey circuits below mirror the bit-level
+//! transform of their off-chain counterparts in `dna::nanopore`,
+//! `dna::secure`, and `dna::split_key`, but only that core transform:
+//! Arcis circuits operate on fixed-size encrypted arrays, so the
+//! variable-length padding, markers, Argon2id/AES-GCM envelopes, and
+//! compression those modules also do off-chain have no circuit
+//! equivalent here, same as `encode_basic`/`decode_basic` never apply
+//! markers either.
 
 use arcis::*;
 
@@ -10,11 +19,44 @@ const MSG_LEN: usize = 4;
 /// DNA output length (4 bases per byte)
 const DNA_LEN: usize = MSG_LEN * 4;
 
+/// Message length for the Nanopore circuit: 3 bytes (24 bits) divides
+/// evenly into eight 3-bit triplets, unlike `MSG_LEN`'s 32 bits — a fixed
+/// circuit can't pad mid-computation the way `NanoporeDNACrypto` does
+/// off-chain, so this picks a size that needs no padding instead.
+const NANOPORE_MSG_LEN: usize = 3;
+/// DNA output length for the Nanopore circuit (3 bases per triplet, 8 triplets).
+const NANOPORE_DNA_LEN: usize = NANOPORE_MSG_LEN * 8;
+
+/// Length of the symmetric key XOR-keystreamed against the message in the
+/// Secure circuit, matching `SecureDNACrypto::KEY_SIZE` so a caller can
+/// reuse the same 32-byte key material shape even though only the first
+/// `MSG_LEN` bytes are consumed as keystream per block.
+const SECURE_KEY_LEN: usize = 32;
+/// `encode_secure` input: message bytes followed by the key bytes, packed
+/// into a single `Enc<Shared, _>` the same way `decode_basic` packs many
+/// single-byte ciphertexts under one client pubkey/nonce.
+const SECURE_ENCODE_INPUT_LEN: usize = MSG_LEN + SECURE_KEY_LEN;
+/// `decode_secure` input: DNA bytes followed by the key bytes.
+const SECURE_DECODE_INPUT_LEN: usize = DNA_LEN + SECURE_KEY_LEN;
+
+/// Length of each split-key share (K1, K2), matching `SecureDNACrypto::KEY_SIZE`.
+const SPLIT_KEY_SHARE_LEN: usize = 32;
+/// `encode_splitkey` input: message bytes, then the K1 share, then the K2
+/// share. The circuit reconstructs `K = K1 XOR K2` and keystreams the
+/// message with it, so neither share alone — even revealed — yields `K`.
+const SPLIT_KEY_ENCODE_INPUT_LEN: usize = MSG_LEN + 2 * SPLIT_KEY_SHARE_LEN;
+/// `decode_splitkey` input: DNA bytes, then the K1 share, then the K2 share.
+const SPLIT_KEY_DECODE_INPUT_LEN: usize = DNA_LEN + 2 * SPLIT_KEY_SHARE_LEN;
+
 #[encrypted]
 mod circuits {
     use arcis::*;
 
-    use super::{DNA_LEN, MSG_LEN};
+    use super::{
+        DNA_LEN, MSG_LEN, NANOPORE_DNA_LEN, NANOPORE_MSG_LEN, SECURE_DECODE_INPUT_LEN,
+        SECURE_ENCODE_INPUT_LEN, SPLIT_KEY_DECODE_INPUT_LEN, SPLIT_KEY_ENCODE_INPUT_LEN,
+        SPLIT_KEY_SHARE_LEN,
+    };
 
     /// Encode a message to DNA sequence using Basic mode.
     ///
@@ -71,4 +113,204 @@ mod circuits {
 
         input_ctxt.owner.from_arcis(message)
     }
+
+    /// Encode a message to DNA using Nanopore mode's homopolymer-avoiding
+    /// triplet table (see `NanoporeDNACrypto::NANOPORE_ENCODE`): every 3
+    /// bits of the message become a 3-base triplet with no repeated base,
+    /// instead of Basic mode's direct 2-bit-per-base mapping.
+    ///
+    /// # Arguments
+    /// * `input_ctxt` - Encrypted message bytes (3 bytes = 24 bits = 8 triplets)
+    ///
+    /// # Returns
+    /// * Encrypted DNA sequence as [u8; 24] where 0=A, 1=T, 2=C, 3=G
+    #[instruction]
+    pub fn encode_nanopore(
+        input_ctxt: Enc<Shared, [u8; NANOPORE_MSG_LEN]>,
+    ) -> Enc<Shared, [u8; NANOPORE_DNA_LEN]> {
+        let message = input_ctxt.to_arcis();
+        let combined: u32 =
+            ((message[0] as u32) << 16) | ((message[1] as u32) << 8) | (message[2] as u32);
+        let mut dna = [0u8; NANOPORE_DNA_LEN];
+
+        for i in 0..8 {
+            let shift = 21 - i * 3;
+            let triplet = ((combined >> shift) & 0b111u32) as u8;
+            let (a, b, c) = match triplet {
+                0 => (0u8, 1u8, 2u8), // ATC
+                1 => (0u8, 1u8, 3u8), // ATG
+                2 => (0u8, 2u8, 1u8), // ACT
+                3 => (0u8, 2u8, 3u8), // ACG
+                4 => (1u8, 0u8, 3u8), // TAG
+                5 => (1u8, 0u8, 2u8), // TAC
+                6 => (1u8, 2u8, 3u8), // TCG
+                _ => (1u8, 2u8, 0u8), // TCA
+            };
+            dna[i * 3] = a;
+            dna[i * 3 + 1] = b;
+            dna[i * 3 + 2] = c;
+        }
+
+        input_ctxt.owner.from_arcis(dna)
+    }
+
+    /// Decode a Nanopore-mode DNA sequence back to message bytes, reversing
+    /// [`encode_nanopore`]'s triplet table.
+    ///
+    /// # Arguments
+    /// * `input_ctxt` - Encrypted DNA sequence [u8; 24] (0=A, 1=T, 2=C, 3=G)
+    ///
+    /// # Returns
+    /// * Encrypted message bytes [u8; 3]
+    #[instruction]
+    pub fn decode_nanopore(
+        input_ctxt: Enc<Shared, [u8; NANOPORE_DNA_LEN]>,
+    ) -> Enc<Shared, [u8; NANOPORE_MSG_LEN]> {
+        let dna = input_ctxt.to_arcis();
+        let mut combined: u32 = 0u32;
+
+        for i in 0..8 {
+            let a = dna[i * 3];
+            let b = dna[i * 3 + 1];
+            let c = dna[i * 3 + 2];
+            let bits = match (a, b, c) {
+                (0, 1, 2) => 0u8, // ATC
+                (0, 1, 3) => 1u8, // ATG
+                (0, 2, 1) => 2u8, // ACT
+                (0, 2, 3) => 3u8, // ACG
+                (1, 0, 3) => 4u8, // TAG
+                (1, 0, 2) => 5u8, // TAC
+                (1, 2, 3) => 6u8, // TCG
+                _ => 7u8,         // TCA
+            };
+            combined = (combined << 3) | (bits as u32);
+        }
+
+        let message = [
+            ((combined >> 16) & 0xffu32) as u8,
+            ((combined >> 8) & 0xffu32) as u8,
+            (combined & 0xffu32) as u8,
+        ];
+
+        input_ctxt.owner.from_arcis(message)
+    }
+
+    /// Encode a message to DNA under Secure mode: the message is first
+    /// XOR-keystreamed with a caller-supplied key (both stay inside the
+    /// MPC the whole time, so the plaintext is never reconstructed
+    /// unencrypted), then the keyed bytes go through Basic mode's 2-bit
+    /// mapping. This is a circuit-sized stand-in for `SecureDNACrypto`'s
+    /// full AES-256-GCM/Argon2id envelope, which needs variable-length
+    /// ciphertext and isn't expressible as a fixed arithmetic circuit.
+    ///
+    /// # Arguments
+    /// * `input_ctxt` - Encrypted message bytes (4) followed by the key (32)
+    ///
+    /// # Returns
+    /// * Encrypted DNA sequence as [u8; 16] where 0=A, 1=T, 2=C, 3=G
+    #[instruction]
+    pub fn encode_secure(
+        input_ctxt: Enc<Shared, [u8; SECURE_ENCODE_INPUT_LEN]>,
+    ) -> Enc<Shared, [u8; DNA_LEN]> {
+        let input = input_ctxt.to_arcis();
+        let mut dna = [0u8; DNA_LEN];
+
+        for i in 0..MSG_LEN {
+            let byte = input[i] ^ input[MSG_LEN + i];
+            dna[i * 4] = (byte >> 6) & 3u8;
+            dna[i * 4 + 1] = (byte >> 4) & 3u8;
+            dna[i * 4 + 2] = (byte >> 2) & 3u8;
+            dna[i * 4 + 3] = byte & 3u8;
+        }
+
+        input_ctxt.owner.from_arcis(dna)
+    }
+
+    /// Decode a Secure-mode DNA sequence back to message bytes, reversing
+    /// [`encode_secure`]'s keystream.
+    ///
+    /// # Arguments
+    /// * `input_ctxt` - Encrypted DNA sequence (16) followed by the key (32)
+    ///
+    /// # Returns
+    /// * Encrypted message bytes [u8; 4]
+    #[instruction]
+    pub fn decode_secure(
+        input_ctxt: Enc<Shared, [u8; SECURE_DECODE_INPUT_LEN]>,
+    ) -> Enc<Shared, [u8; MSG_LEN]> {
+        let input = input_ctxt.to_arcis();
+        let mut message = [0u8; MSG_LEN];
+
+        for i in 0..MSG_LEN {
+            let b0 = input[i * 4] & 3u8;
+            let b1 = input[i * 4 + 1] & 3u8;
+            let b2 = input[i * 4 + 2] & 3u8;
+            let b3 = input[i * 4 + 3] & 3u8;
+            let keyed = (b0 << 6) | (b1 << 4) | (b2 << 2) | b3;
+            message[i] = keyed ^ input[DNA_LEN + i];
+        }
+
+        input_ctxt.owner.from_arcis(message)
+    }
+
+    /// Encode a message to DNA under SplitKey mode: two independent key
+    /// shares (K1, K2) are XORed together inside the MPC to reconstruct
+    /// `K`, which then keystreams the message before Basic mode's 2-bit
+    /// mapping — mirroring `SplitKeyDNACrypto`'s `K = K1 XOR K2` scheme.
+    /// Neither share alone, even if later revealed, yields `K` or the
+    /// plaintext, since the XOR only ever happens inside the computation.
+    ///
+    /// # Arguments
+    /// * `input_ctxt` - Encrypted message bytes (4), then K1 (32), then K2 (32)
+    ///
+    /// # Returns
+    /// * Encrypted DNA sequence as [u8; 16] where 0=A, 1=T, 2=C, 3=G
+    #[instruction]
+    pub fn encode_splitkey(
+        input_ctxt: Enc<Shared, [u8; SPLIT_KEY_ENCODE_INPUT_LEN]>,
+    ) -> Enc<Shared, [u8; DNA_LEN]> {
+        let input = input_ctxt.to_arcis();
+        let mut dna = [0u8; DNA_LEN];
+
+        for i in 0..MSG_LEN {
+            let k1 = input[MSG_LEN + i];
+            let k2 = input[MSG_LEN + SPLIT_KEY_SHARE_LEN + i];
+            let byte = input[i] ^ k1 ^ k2;
+            dna[i * 4] = (byte >> 6) & 3u8;
+            dna[i * 4 + 1] = (byte >> 4) & 3u8;
+            dna[i * 4 + 2] = (byte >> 2) & 3u8;
+            dna[i * 4 + 3] = byte & 3u8;
+        }
+
+        input_ctxt.owner.from_arcis(dna)
+    }
+
+    /// Decode a SplitKey-mode DNA sequence back to message bytes, reversing
+    /// [`encode_splitkey`]'s `K1 XOR K2` keystream.
+    ///
+    /// # Arguments
+    /// * `input_ctxt` - Encrypted DNA sequence (16), then K1 (32), then K2 (32)
+    ///
+    /// # Returns
+    /// * Encrypted message bytes [u8; 4]
+    #[instruction]
+    pub fn decode_splitkey(
+        input_ctxt: Enc<Shared, [u8; SPLIT_KEY_DECODE_INPUT_LEN]>,
+    ) -> Enc<Shared, [u8; MSG_LEN]> {
+        let input = input_ctxt.to_arcis();
+        let mut message = [0u8; MSG_LEN];
+
+        for i in 0..MSG_LEN {
+            let b0 = input[i * 4] & 3u8;
+            let b1 = input[i * 4 + 1] & 3u8;
+            let b2 = input[i * 4 + 2] & 3u8;
+            let b3 = input[i * 4 + 3] & 3u8;
+            let keyed = (b0 << 6) | (b1 << 4) | (b2 << 2) | b3;
+            let k1 = input[DNA_LEN + i];
+            let k2 = input[DNA_LEN + SPLIT_KEY_SHARE_LEN + i];
+            message[i] = keyed ^ k1 ^ k2;
+        }
+
+        input_ctxt.owner.from_arcis(message)
+    }
 }
@@ -4,10 +4,18 @@
 //! computation. Uses Basic mode: 00→A, 01→T, 10→C, 11→G.
 
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
 use arcium_anchor::prelude::*;
 
 const COMP_DEF_OFFSET_ENCODE_BASIC: u32 = comp_def_offset("encode_basic");
 const COMP_DEF_OFFSET_DECODE_BASIC: u32 = comp_def_offset("decode_basic");
+const COMP_DEF_OFFSET_ENCODE_NANOPORE: u32 = comp_def_offset("encode_nanopore");
+const COMP_DEF_OFFSET_DECODE_NANOPORE: u32 = comp_def_offset("decode_nanopore");
+const COMP_DEF_OFFSET_ENCODE_SECURE: u32 = comp_def_offset("encode_secure");
+const COMP_DEF_OFFSET_DECODE_SECURE: u32 = comp_def_offset("decode_secure");
+const COMP_DEF_OFFSET_ENCODE_SPLITKEY: u32 = comp_def_offset("encode_splitkey");
+const COMP_DEF_OFFSET_DECODE_SPLITKEY: u32 = comp_def_offset("decode_splitkey");
 
 declare_id!("EneGTgWJJwnxLeBkD128NtpuGQVCmq14cUnPCNEVyueE");
 
@@ -27,7 +35,56 @@ pub mod biocypher_mxe {
         Ok(())
     }
 
+    /// Initialize the encode_nanopore computation definition.
+    pub fn init_encode_nanopore_comp_def(ctx: Context<InitEncodeNanoporeCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the decode_nanopore computation definition.
+    pub fn init_decode_nanopore_comp_def(ctx: Context<InitDecodeNanoporeCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the encode_secure computation definition.
+    pub fn init_encode_secure_comp_def(ctx: Context<InitEncodeSecureCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the decode_secure computation definition.
+    pub fn init_decode_secure_comp_def(ctx: Context<InitDecodeSecureCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the encode_splitkey computation definition.
+    pub fn init_encode_splitkey_comp_def(ctx: Context<InitEncodeSplitkeyCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
+    /// Initialize the decode_splitkey computation definition.
+    pub fn init_decode_splitkey_comp_def(ctx: Context<InitDecodeSplitkeyCompDef>) -> Result<()> {
+        init_comp_def(ctx.accounts, None, None)?;
+        Ok(())
+    }
+
     /// Queue encrypted DNA encoding. Message (4 bytes) stays encrypted; MPC produces encrypted DNA (16 bases).
+    ///
+    /// `commitment` is `blake3(message_bytes || nonce_le)` over the
+    /// plaintext message, computed by the caller (see
+    /// `DNACrypto::compute_commitment` off-chain) since the Arcis circuit
+    /// only ever sees secret-shared ciphertext and can't derive it on-chain.
+    /// It's recorded on [`DNAResultAccount`] so a decoder can later recompute
+    /// it over the decoded message (`DNACrypto::verify_commitment`) and
+    /// detect tampering or a mismatched ciphertext set.
+    ///
+    /// When `downstream_program` is set, the accounts trailing the named ones
+    /// in this instruction are forwarded untouched to the callback and, once
+    /// the result is verified, CPI'd into that program alongside the result
+    /// (see [`forward_result_cpi`]).
     pub fn encode_basic(
         ctx: Context<EncodeBasic>,
         computation_offset: u64,
@@ -37,6 +94,8 @@ pub mod biocypher_mxe {
         ciphertext_3: [u8; 32],
         pub_key: [u8; 32],
         nonce: u128,
+        commitment: [u8; 32],
+        downstream_program: Option<Pubkey>,
     ) -> Result<()> {
         let args = ArgBuilder::new()
             .x25519_pubkey(pub_key)
@@ -48,100 +107,1098 @@ pub mod biocypher_mxe {
             .build();
 
         ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+        ctx.accounts.result_account.commitment = commitment;
+        emit!(EncodeBasicCommitmentEvent {
+            result_account: ctx.accounts.result_account.key(),
+            commitment,
+        });
+
+        let mut callback_accounts = vec![AccountMeta::new(ctx.accounts.result_account.key(), false)];
+        callback_accounts.extend(downstream_callback_accounts(downstream_program, ctx.remaining_accounts));
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            vec![EncodeBasicCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &callback_accounts,
+            )?],
+            1,
+            0,
+        )?;
+        Ok(())
+    }
+
+    /// Queue encrypted DNA decoding. DNA sequence (16 bases) stays encrypted; MPC produces encrypted message (4 bytes).
+    ///
+    /// See [`encode_basic`] for `downstream_program` forwarding.
+    pub fn decode_basic(
+        ctx: Context<DecodeBasic>,
+        computation_offset: u64,
+        ciphertexts: [[u8; 32]; 16],
+        pub_key: [u8; 32],
+        nonce: u128,
+        downstream_program: Option<Pubkey>,
+    ) -> Result<()> {
+        let mut builder = ArgBuilder::new()
+            .x25519_pubkey(pub_key)
+            .plaintext_u128(nonce);
+        for ct in ciphertexts.iter() {
+            builder = builder.encrypted_u8(*ct);
+        }
+        let args = builder.build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        let mut callback_accounts = vec![AccountMeta::new(ctx.accounts.result_account.key(), false)];
+        callback_accounts.extend(downstream_callback_accounts(downstream_program, ctx.remaining_accounts));
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            vec![DecodeBasicCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &callback_accounts,
+            )?],
+            1,
+            0,
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "encode_basic")]
+    pub fn encode_basic_callback(
+        ctx: Context<EncodeBasicCallback>,
+        output: SignedComputationOutputs<EncodeBasicOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(EncodeBasicOutput { field_0 }) => field_0,
+            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+        };
+        emit!(EncodeBasicEvent {
+            ciphertexts: o.ciphertexts,
+            nonce: o.nonce.to_le_bytes(),
+        });
+
+        let mut result = Account::<DNAResultAccount>::try_from(&ctx.accounts.result_account.to_account_info())?;
+        result.ciphertexts = o.ciphertexts;
+        result.len = 16;
+        result.nonce = o.nonce.to_le_bytes();
+        result.exit(&crate::ID)?;
+
+        forward_result_cpi(&ctx.accounts.sign_pda_account, ctx.remaining_accounts, &o.ciphertexts, o.nonce)?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "decode_basic")]
+    pub fn decode_basic_callback(
+        ctx: Context<DecodeBasicCallback>,
+        output: SignedComputationOutputs<DecodeBasicOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(DecodeBasicOutput { field_0 }) => field_0,
+            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+        };
+        emit!(DecodeBasicEvent {
+            ciphertexts: o.ciphertexts,
+            nonce: o.nonce.to_le_bytes(),
+        });
+
+        let mut result = Account::<DNAResultAccount>::try_from(&ctx.accounts.result_account.to_account_info())?;
+        result.ciphertexts[..4].copy_from_slice(&o.ciphertexts);
+        result.len = 4;
+        result.nonce = o.nonce.to_le_bytes();
+        result.exit(&crate::ID)?;
+
+        forward_result_cpi(&ctx.accounts.sign_pda_account, ctx.remaining_accounts, &o.ciphertexts, o.nonce)?;
+        Ok(())
+    }
+
+    /// Queue encrypted Nanopore-mode DNA encoding. Message (3 bytes) stays
+    /// encrypted; MPC produces encrypted DNA (24 bases).
+    pub fn encode_nanopore(
+        ctx: Context<EncodeNanopore>,
+        computation_offset: u64,
+        ciphertext_0: [u8; 32],
+        ciphertext_1: [u8; 32],
+        ciphertext_2: [u8; 32],
+        pub_key: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        let args = ArgBuilder::new()
+            .x25519_pubkey(pub_key)
+            .plaintext_u128(nonce)
+            .encrypted_u8(ciphertext_0)
+            .encrypted_u8(ciphertext_1)
+            .encrypted_u8(ciphertext_2)
+            .build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            vec![EncodeNanoporeCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[],
+            )?],
+            1,
+            0,
+        )?;
+        Ok(())
+    }
+
+    /// Queue encrypted Nanopore-mode DNA decoding. DNA sequence (24 bases)
+    /// stays encrypted; MPC produces encrypted message (3 bytes).
+    pub fn decode_nanopore(
+        ctx: Context<DecodeNanopore>,
+        computation_offset: u64,
+        ciphertexts: [[u8; 32]; 24],
+        pub_key: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        let mut builder = ArgBuilder::new()
+            .x25519_pubkey(pub_key)
+            .plaintext_u128(nonce);
+        for ct in ciphertexts.iter() {
+            builder = builder.encrypted_u8(*ct);
+        }
+        let args = builder.build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            vec![DecodeNanoporeCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[],
+            )?],
+            1,
+            0,
+        )?;
+        Ok(())
+    }
+
+    /// Queue encrypted Secure-mode DNA encoding. Message (4 bytes) and key
+    /// (32 bytes) stay encrypted; MPC XOR-keystreams the message with the
+    /// key before producing encrypted DNA (16 bases).
+    pub fn encode_secure(
+        ctx: Context<EncodeSecure>,
+        computation_offset: u64,
+        ciphertexts: [[u8; 32]; 36],
+        pub_key: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        let mut builder = ArgBuilder::new()
+            .x25519_pubkey(pub_key)
+            .plaintext_u128(nonce);
+        for ct in ciphertexts.iter() {
+            builder = builder.encrypted_u8(*ct);
+        }
+        let args = builder.build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            vec![EncodeSecureCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[],
+            )?],
+            1,
+            0,
+        )?;
+        Ok(())
+    }
+
+    /// Queue encrypted Secure-mode DNA decoding. DNA sequence (16 bases)
+    /// and key (32 bytes) stay encrypted; MPC produces encrypted message
+    /// (4 bytes).
+    pub fn decode_secure(
+        ctx: Context<DecodeSecure>,
+        computation_offset: u64,
+        ciphertexts: [[u8; 32]; 48],
+        pub_key: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        let mut builder = ArgBuilder::new()
+            .x25519_pubkey(pub_key)
+            .plaintext_u128(nonce);
+        for ct in ciphertexts.iter() {
+            builder = builder.encrypted_u8(*ct);
+        }
+        let args = builder.build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            vec![DecodeSecureCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[],
+            )?],
+            1,
+            0,
+        )?;
+        Ok(())
+    }
+
+    /// Queue encrypted SplitKey-mode DNA encoding. Message (4 bytes) and
+    /// both key shares K1/K2 (32 bytes each) stay encrypted; MPC
+    /// reconstructs K = K1 XOR K2, keystreams the message with it, and
+    /// produces encrypted DNA (16 bases) — neither share alone reveals K
+    /// or the plaintext.
+    pub fn encode_splitkey(
+        ctx: Context<EncodeSplitkey>,
+        computation_offset: u64,
+        ciphertexts: [[u8; 32]; 68],
+        pub_key: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        let mut builder = ArgBuilder::new()
+            .x25519_pubkey(pub_key)
+            .plaintext_u128(nonce);
+        for ct in ciphertexts.iter() {
+            builder = builder.encrypted_u8(*ct);
+        }
+        let args = builder.build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            vec![EncodeSplitkeyCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[],
+            )?],
+            1,
+            0,
+        )?;
+        Ok(())
+    }
+
+    /// Queue encrypted SplitKey-mode DNA decoding. DNA sequence (16 bases)
+    /// and both key shares (32 bytes each) stay encrypted; MPC produces
+    /// encrypted message (4 bytes).
+    pub fn decode_splitkey(
+        ctx: Context<DecodeSplitkey>,
+        computation_offset: u64,
+        ciphertexts: [[u8; 32]; 80],
+        pub_key: [u8; 32],
+        nonce: u128,
+    ) -> Result<()> {
+        let mut builder = ArgBuilder::new()
+            .x25519_pubkey(pub_key)
+            .plaintext_u128(nonce);
+        for ct in ciphertexts.iter() {
+            builder = builder.encrypted_u8(*ct);
+        }
+        let args = builder.build();
+
+        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+
+        queue_computation(
+            ctx.accounts,
+            computation_offset,
+            args,
+            vec![DecodeSplitkeyCallback::callback_ix(
+                computation_offset,
+                &ctx.accounts.mxe_account,
+                &[],
+            )?],
+            1,
+            0,
+        )?;
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "encode_nanopore")]
+    pub fn encode_nanopore_callback(
+        ctx: Context<EncodeNanoporeCallback>,
+        output: SignedComputationOutputs<EncodeNanoporeOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(EncodeNanoporeOutput { field_0 }) => field_0,
+            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+        };
+        emit!(EncodeNanoporeEvent {
+            ciphertexts: o.ciphertexts,
+            nonce: o.nonce.to_le_bytes(),
+        });
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "decode_nanopore")]
+    pub fn decode_nanopore_callback(
+        ctx: Context<DecodeNanoporeCallback>,
+        output: SignedComputationOutputs<DecodeNanoporeOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(DecodeNanoporeOutput { field_0 }) => field_0,
+            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+        };
+        emit!(DecodeNanoporeEvent {
+            ciphertexts: o.ciphertexts,
+            nonce: o.nonce.to_le_bytes(),
+        });
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "encode_secure")]
+    pub fn encode_secure_callback(
+        ctx: Context<EncodeSecureCallback>,
+        output: SignedComputationOutputs<EncodeSecureOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(EncodeSecureOutput { field_0 }) => field_0,
+            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+        };
+        emit!(EncodeSecureEvent {
+            ciphertexts: o.ciphertexts,
+            nonce: o.nonce.to_le_bytes(),
+        });
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "decode_secure")]
+    pub fn decode_secure_callback(
+        ctx: Context<DecodeSecureCallback>,
+        output: SignedComputationOutputs<DecodeSecureOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(DecodeSecureOutput { field_0 }) => field_0,
+            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+        };
+        emit!(DecodeSecureEvent {
+            ciphertexts: o.ciphertexts,
+            nonce: o.nonce.to_le_bytes(),
+        });
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "encode_splitkey")]
+    pub fn encode_splitkey_callback(
+        ctx: Context<EncodeSplitkeyCallback>,
+        output: SignedComputationOutputs<EncodeSplitkeyOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(EncodeSplitkeyOutput { field_0 }) => field_0,
+            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+        };
+        emit!(EncodeSplitkeyEvent {
+            ciphertexts: o.ciphertexts,
+            nonce: o.nonce.to_le_bytes(),
+        });
+        Ok(())
+    }
+
+    #[arcium_callback(encrypted_ix = "decode_splitkey")]
+    pub fn decode_splitkey_callback(
+        ctx: Context<DecodeSplitkeyCallback>,
+        output: SignedComputationOutputs<DecodeSplitkeyOutput>,
+    ) -> Result<()> {
+        let o = match output.verify_output(
+            &ctx.accounts.cluster_account,
+            &ctx.accounts.computation_account,
+        ) {
+            Ok(DecodeSplitkeyOutput { field_0 }) => field_0,
+            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
+        };
+        emit!(DecodeSplitkeyEvent {
+            ciphertexts: o.ciphertexts,
+            nonce: o.nonce.to_le_bytes(),
+        });
+        Ok(())
+    }
+}
+
+/// Build the extra `AccountMeta`s a queue instruction appends to its
+/// callback invocation so [`forward_result_cpi`] can later reach a
+/// caller-chosen downstream program: the program id itself (read-only, not
+/// a signer) followed by every account the caller passed as
+/// `remaining_accounts`, carrying over their original signer/writable bits.
+fn downstream_callback_accounts(
+    downstream_program: Option<Pubkey>,
+    remaining_accounts: &[AccountInfo],
+) -> Vec<AccountMeta> {
+    let Some(program_id) = downstream_program else {
+        return Vec::new();
+    };
+    let mut metas = vec![AccountMeta::new_readonly(program_id, false)];
+    metas.extend(remaining_accounts.iter().map(|account| AccountMeta {
+        pubkey: *account.key,
+        is_signer: account.is_signer,
+        is_writable: account.is_writable,
+    }));
+    metas
+}
+
+/// CPI the verified ciphertexts + nonce into the downstream program a queue
+/// instruction named via `downstream_program`, if any. `remaining_accounts`
+/// is `[downstream_program, ...forwarded_accounts]`, mirroring what
+/// [`downstream_callback_accounts`] appended at queue time; a no-op when
+/// empty (the caller didn't opt into CPI forwarding).
+///
+/// Signs with the MXE's `sign_pda_account` seeds via `invoke_signed`, so the
+/// downstream program sees the same PDA authority the Arcium framework uses
+/// elsewhere, not the original queuer.
+fn forward_result_cpi(
+    sign_pda_account: &Account<ArciumSignerAccount>,
+    remaining_accounts: &[AccountInfo],
+    ciphertexts: &[[u8; 32]],
+    nonce: u128,
+) -> Result<()> {
+    let Some((downstream_program, forwarded)) = remaining_accounts.split_first() else {
+        return Ok(());
+    };
+
+    let mut data = Vec::with_capacity(ciphertexts.len() * 32 + 16);
+    for ciphertext in ciphertexts {
+        data.extend_from_slice(ciphertext);
+    }
+    data.extend_from_slice(&nonce.to_le_bytes());
+
+    let accounts = forwarded
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: *account.key,
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        })
+        .collect();
+
+    let ix = Instruction {
+        program_id: *downstream_program.key,
+        accounts,
+        data,
+    };
+
+    let mut account_infos: Vec<AccountInfo> = forwarded.to_vec();
+    account_infos.push(downstream_program.clone());
+
+    invoke_signed(&ix, &account_infos, &[&[&SIGN_PDA_SEED, &[sign_pda_account.bump]]])?;
+    Ok(())
+}
+
+// --- Encode Basic Accounts ---
+
+#[queue_computation_accounts("encode_basic", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct EncodeBasic<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ENCODE_BASIC))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DNAResultAccount::SPACE,
+        seeds = [b"result", payer.key().as_ref(), &computation_offset.to_le_bytes()],
+        bump,
+    )]
+    pub result_account: Account<'info, DNAResultAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("encode_basic")]
+#[derive(Accounts)]
+pub struct EncodeBasicCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ENCODE_BASIC))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    /// CHECK: computation_account, checked by arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// CHECK: result_account, its address was derived and the account was initialized
+    /// by the matching `encode_basic` queue instruction; written here after output verification
+    pub result_account: UncheckedAccount<'info>,
+    #[account(seeds = [&SIGN_PDA_SEED], bump = sign_pda_account.bump)]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+}
+
+#[init_computation_definition_accounts("encode_basic", payer)]
+#[derive(Accounts)]
+pub struct InitEncodeBasicCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table, checked by arcium program
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// --- Decode Basic Accounts ---
+
+#[queue_computation_accounts("decode_basic", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct DecodeBasic<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DECODE_BASIC))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DNAResultAccount::SPACE,
+        seeds = [b"result", payer.key().as_ref(), &computation_offset.to_le_bytes()],
+        bump,
+    )]
+    pub result_account: Account<'info, DNAResultAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("decode_basic")]
+#[derive(Accounts)]
+pub struct DecodeBasicCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DECODE_BASIC))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+    #[account(mut)]
+    /// CHECK: result_account, its address was derived and the account was initialized
+    /// by the matching `decode_basic` queue instruction; written here after output verification
+    pub result_account: UncheckedAccount<'info>,
+    #[account(seeds = [&SIGN_PDA_SEED], bump = sign_pda_account.bump)]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+}
+
+#[init_computation_definition_accounts("decode_basic", payer)]
+#[derive(Accounts)]
+pub struct InitDecodeBasicCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// --- Encode Nanopore Accounts ---
+
+#[queue_computation_accounts("encode_nanopore", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct EncodeNanopore<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ENCODE_NANOPORE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("encode_nanopore")]
+#[derive(Accounts)]
+pub struct EncodeNanoporeCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ENCODE_NANOPORE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    /// CHECK: computation_account, checked by arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[init_computation_definition_accounts("encode_nanopore", payer)]
+#[derive(Accounts)]
+pub struct InitEncodeNanoporeCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// --- Decode Nanopore Accounts ---
+
+#[queue_computation_accounts("decode_nanopore", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct DecodeNanopore<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DECODE_NANOPORE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
+
+#[callback_accounts("decode_nanopore")]
+#[derive(Accounts)]
+pub struct DecodeNanoporeCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DECODE_NANOPORE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[init_computation_definition_accounts("decode_nanopore", payer)]
+#[derive(Accounts)]
+pub struct InitDecodeNanoporeCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
+
+// --- Encode Secure Accounts ---
+
+#[queue_computation_accounts("encode_secure", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct EncodeSecure<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account, checked by the arcium program
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool, checked by the arcium program
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account, checked by the arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ENCODE_SECURE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
 
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            vec![EncodeBasicCallback::callback_ix(
-                computation_offset,
-                &ctx.accounts.mxe_account,
-                &[],
-            )?],
-            1,
-            0,
-        )?;
-        Ok(())
-    }
+#[callback_accounts("encode_secure")]
+#[derive(Accounts)]
+pub struct EncodeSecureCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ENCODE_SECURE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    /// CHECK: computation_account, checked by arcium program
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar, checked by the account constraint
+    pub instructions_sysvar: AccountInfo<'info>,
+}
 
-    /// Queue encrypted DNA decoding. DNA sequence (16 bases) stays encrypted; MPC produces encrypted message (4 bytes).
-    pub fn decode_basic(
-        ctx: Context<DecodeBasic>,
-        computation_offset: u64,
-        ciphertexts: [[u8; 32]; 16],
-        pub_key: [u8; 32],
-        nonce: u128,
-    ) -> Result<()> {
-        let mut builder = ArgBuilder::new()
-            .x25519_pubkey(pub_key)
-            .plaintext_u128(nonce);
-        for ct in ciphertexts.iter() {
-            builder = builder.encrypted_u8(*ct);
-        }
-        let args = builder.build();
+#[init_computation_definition_accounts("encode_secure", payer)]
+#[derive(Accounts)]
+pub struct InitEncodeSecureCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
+}
 
-        ctx.accounts.sign_pda_account.bump = ctx.bumps.sign_pda_account;
+// --- Decode Secure Accounts ---
 
-        queue_computation(
-            ctx.accounts,
-            computation_offset,
-            args,
-            vec![DecodeBasicCallback::callback_ix(
-                computation_offset,
-                &ctx.accounts.mxe_account,
-                &[],
-            )?],
-            1,
-            0,
-        )?;
-        Ok(())
-    }
+#[queue_computation_accounts("decode_secure", payer)]
+#[derive(Accounts)]
+#[instruction(computation_offset: u64)]
+pub struct DecodeSecure<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        space = 9,
+        payer = payer,
+        seeds = [&SIGN_PDA_SEED],
+        bump,
+        address = derive_sign_pda!(),
+    )]
+    pub sign_pda_account: Account<'info, ArciumSignerAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    #[account(
+        mut,
+        address = derive_mempool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: mempool_account
+    pub mempool_account: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_execpool_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: executing_pool
+    pub executing_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        address = derive_comp_pda!(computation_offset, mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DECODE_SECURE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(
+        mut,
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(mut, address = ARCIUM_FEE_POOL_ACCOUNT_ADDRESS)]
+    pub pool_account: Account<'info, FeePool>,
+    #[account(mut, address = ARCIUM_CLOCK_ACCOUNT_ADDRESS)]
+    pub clock_account: Account<'info, ClockAccount>,
+    pub system_program: Program<'info, System>,
+    pub arcium_program: Program<'info, Arcium>,
+}
 
-    #[arcium_callback(encrypted_ix = "encode_basic")]
-    pub fn encode_basic_callback(
-        ctx: Context<EncodeBasicCallback>,
-        output: SignedComputationOutputs<EncodeBasicOutput>,
-    ) -> Result<()> {
-        let o = match output.verify_output(
-            &ctx.accounts.cluster_account,
-            &ctx.accounts.computation_account,
-        ) {
-            Ok(EncodeBasicOutput { field_0 }) => field_0,
-            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
-        };
-        emit!(EncodeBasicEvent {
-            ciphertexts: o.ciphertexts,
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
+#[callback_accounts("decode_secure")]
+#[derive(Accounts)]
+pub struct DecodeSecureCallback<'info> {
+    pub arcium_program: Program<'info, Arcium>,
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DECODE_SECURE))]
+    pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
+    #[account(address = derive_mxe_pda!())]
+    pub mxe_account: Account<'info, MXEAccount>,
+    /// CHECK: computation_account
+    pub computation_account: UncheckedAccount<'info>,
+    #[account(
+        address = derive_cluster_pda!(mxe_account, ErrorCode::ClusterNotSet)
+    )]
+    pub cluster_account: Account<'info, Cluster>,
+    #[account(address = ::anchor_lang::solana_program::sysvar::instructions::ID)]
+    /// CHECK: instructions_sysvar
+    pub instructions_sysvar: AccountInfo<'info>,
+}
 
-    #[arcium_callback(encrypted_ix = "decode_basic")]
-    pub fn decode_basic_callback(
-        ctx: Context<DecodeBasicCallback>,
-        output: SignedComputationOutputs<DecodeBasicOutput>,
-    ) -> Result<()> {
-        let o = match output.verify_output(
-            &ctx.accounts.cluster_account,
-            &ctx.accounts.computation_account,
-        ) {
-            Ok(DecodeBasicOutput { field_0 }) => field_0,
-            Err(_) => return Err(ErrorCode::AbortedComputation.into()),
-        };
-        emit!(DecodeBasicEvent {
-            ciphertexts: o.ciphertexts,
-            nonce: o.nonce.to_le_bytes(),
-        });
-        Ok(())
-    }
+#[init_computation_definition_accounts("decode_secure", payer)]
+#[derive(Accounts)]
+pub struct InitDecodeSecureCompDef<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mut, address = derive_mxe_pda!())]
+    pub mxe_account: Box<Account<'info, MXEAccount>>,
+    #[account(mut)]
+    /// CHECK: comp_def_account, checked by arcium program. Not initialized yet.
+    pub comp_def_account: UncheckedAccount<'info>,
+    #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
+    /// CHECK: address_lookup_table
+    pub address_lookup_table: UncheckedAccount<'info>,
+    #[account(address = LUT_PROGRAM_ID)]
+    /// CHECK: lut_program
+    pub lut_program: UncheckedAccount<'info>,
+    pub arcium_program: Program<'info, Arcium>,
+    pub system_program: Program<'info, System>,
 }
 
-// --- Encode Basic Accounts ---
+// --- Encode Splitkey Accounts ---
 
-#[queue_computation_accounts("encode_basic", payer)]
+#[queue_computation_accounts("encode_splitkey", payer)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64)]
-pub struct EncodeBasic<'info> {
+pub struct EncodeSplitkey<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -173,7 +1230,7 @@ pub struct EncodeBasic<'info> {
     )]
     /// CHECK: computation_account, checked by the arcium program
     pub computation_account: UncheckedAccount<'info>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ENCODE_BASIC))]
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ENCODE_SPLITKEY))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(
         mut,
@@ -188,11 +1245,11 @@ pub struct EncodeBasic<'info> {
     pub arcium_program: Program<'info, Arcium>,
 }
 
-#[callback_accounts("encode_basic")]
+#[callback_accounts("encode_splitkey")]
 #[derive(Accounts)]
-pub struct EncodeBasicCallback<'info> {
+pub struct EncodeSplitkeyCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ENCODE_BASIC))]
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_ENCODE_SPLITKEY))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(address = derive_mxe_pda!())]
     pub mxe_account: Account<'info, MXEAccount>,
@@ -207,9 +1264,9 @@ pub struct EncodeBasicCallback<'info> {
     pub instructions_sysvar: AccountInfo<'info>,
 }
 
-#[init_computation_definition_accounts("encode_basic", payer)]
+#[init_computation_definition_accounts("encode_splitkey", payer)]
 #[derive(Accounts)]
-pub struct InitEncodeBasicCompDef<'info> {
+pub struct InitEncodeSplitkeyCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(mut, address = derive_mxe_pda!())]
@@ -218,7 +1275,7 @@ pub struct InitEncodeBasicCompDef<'info> {
     /// CHECK: comp_def_account, checked by arcium program. Not initialized yet.
     pub comp_def_account: UncheckedAccount<'info>,
     #[account(mut, address = derive_mxe_lut_pda!(mxe_account.lut_offset_slot))]
-    /// CHECK: address_lookup_table, checked by arcium program
+    /// CHECK: address_lookup_table
     pub address_lookup_table: UncheckedAccount<'info>,
     #[account(address = LUT_PROGRAM_ID)]
     /// CHECK: lut_program
@@ -227,12 +1284,12 @@ pub struct InitEncodeBasicCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
-// --- Decode Basic Accounts ---
+// --- Decode Splitkey Accounts ---
 
-#[queue_computation_accounts("decode_basic", payer)]
+#[queue_computation_accounts("decode_splitkey", payer)]
 #[derive(Accounts)]
 #[instruction(computation_offset: u64)]
-pub struct DecodeBasic<'info> {
+pub struct DecodeSplitkey<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(
@@ -264,7 +1321,7 @@ pub struct DecodeBasic<'info> {
     )]
     /// CHECK: computation_account
     pub computation_account: UncheckedAccount<'info>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DECODE_BASIC))]
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DECODE_SPLITKEY))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(
         mut,
@@ -279,11 +1336,11 @@ pub struct DecodeBasic<'info> {
     pub arcium_program: Program<'info, Arcium>,
 }
 
-#[callback_accounts("decode_basic")]
+#[callback_accounts("decode_splitkey")]
 #[derive(Accounts)]
-pub struct DecodeBasicCallback<'info> {
+pub struct DecodeSplitkeyCallback<'info> {
     pub arcium_program: Program<'info, Arcium>,
-    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DECODE_BASIC))]
+    #[account(address = derive_comp_def_pda!(COMP_DEF_OFFSET_DECODE_SPLITKEY))]
     pub comp_def_account: Account<'info, ComputationDefinitionAccount>,
     #[account(address = derive_mxe_pda!())]
     pub mxe_account: Account<'info, MXEAccount>,
@@ -298,9 +1355,9 @@ pub struct DecodeBasicCallback<'info> {
     pub instructions_sysvar: AccountInfo<'info>,
 }
 
-#[init_computation_definition_accounts("decode_basic", payer)]
+#[init_computation_definition_accounts("decode_splitkey", payer)]
 #[derive(Accounts)]
-pub struct InitDecodeBasicCompDef<'info> {
+pub struct InitDecodeSplitkeyCompDef<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     #[account(mut, address = derive_mxe_pda!())]
@@ -318,6 +1375,31 @@ pub struct InitDecodeBasicCompDef<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Durable, queryable counterpart to [`EncodeBasicEvent`]/[`DecodeBasicEvent`]:
+/// a PDA at `[b"result", payer, computation_offset]` so a client can read
+/// back a Basic-mode encode/decode result later instead of needing to have
+/// been listening for the event when the callback fired. Sized for the
+/// larger of the two results (encode's 16-base DNA sequence); decode only
+/// populates the first `len` entries.
+#[account]
+pub struct DNAResultAccount {
+    pub ciphertexts: [[u8; 32]; 16],
+    pub len: u8,
+    pub nonce: [u8; 16],
+    /// `blake3(message_bytes || nonce_le)` over the plaintext Basic-mode
+    /// message, supplied by the `encode_basic` caller (who holds the
+    /// plaintext) and recorded verbatim: the Arcis circuit operates only on
+    /// secret-shared ciphertext, so this can't be recomputed on-chain. A
+    /// decoder recomputes it client-side via `DNACrypto::verify_commitment`
+    /// after decoding to detect tampering or a mismatched ciphertext set.
+    /// All-zero until `encode_basic` sets it (decode results don't have one).
+    pub commitment: [u8; 32],
+}
+
+impl DNAResultAccount {
+    pub const SPACE: usize = 8 + 32 * 16 + 1 + 16 + 32;
+}
+
 // --- Events ---
 
 #[event]
@@ -332,6 +1414,50 @@ pub struct DecodeBasicEvent {
     pub nonce: [u8; 16],
 }
 
+/// Emitted from `encode_basic` itself (not its callback): the commitment is
+/// supplied at queue time, before the MPC computation has even run.
+#[event]
+pub struct EncodeBasicCommitmentEvent {
+    pub result_account: Pubkey,
+    pub commitment: [u8; 32],
+}
+
+#[event]
+pub struct EncodeNanoporeEvent {
+    pub ciphertexts: [[u8; 32]; 24],
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct DecodeNanoporeEvent {
+    pub ciphertexts: [[u8; 32]; 3],
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct EncodeSecureEvent {
+    pub ciphertexts: [[u8; 32]; 16],
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct DecodeSecureEvent {
+    pub ciphertexts: [[u8; 32]; 4],
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct EncodeSplitkeyEvent {
+    pub ciphertexts: [[u8; 32]; 16],
+    pub nonce: [u8; 16],
+}
+
+#[event]
+pub struct DecodeSplitkeyEvent {
+    pub ciphertexts: [[u8; 32]; 4],
+    pub nonce: [u8; 16],
+}
+
 #[error_code]
 pub enum ErrorCode {
     #[msg("The computation was aborted")]
@@ -0,0 +1,109 @@
+//! Batch attestation: commit many `hash_sequence` leaves under one Merkle
+//! root, then serve each item's inclusion proof so it remains
+//! independently verifiable against that root.
+
+use actix_web::{web, HttpResponse};
+use validator::Validate;
+
+use crate::api::build_attest::resolve_operation_fields;
+use crate::error::Result;
+use crate::models::{
+    AttestBatchItemResult, AttestBatchRequest, AttestBatchResponse, AttestProofResponse,
+    AttestProofStep,
+};
+use crate::solana::merkle::{attest_leaf_hash, from_hex, to_hex, AttestMerkleTree};
+use crate::solana::{hash_sequence, AttestProofStore};
+
+fn proof_steps(proof: &crate::solana::merkle::MerkleProof) -> Vec<AttestProofStep> {
+    proof
+        .steps
+        .iter()
+        .map(|step| AttestProofStep {
+            sibling: to_hex(&step.sibling),
+            sibling_is_left: step.sibling_is_left,
+        })
+        .collect()
+}
+
+pub async fn batch_attest(
+    req: web::Json<AttestBatchRequest>,
+    proofs: web::Data<AttestProofStore>,
+) -> Result<HttpResponse> {
+    if let Err(errors) = req.validate() {
+        return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "Validation failed",
+            "details": errors
+        })));
+    }
+
+    let mut leaves = Vec::with_capacity(req.items.len());
+    for item in &req.items {
+        if let Err(errors) = item.validate() {
+            return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": "Validation failed",
+                "details": errors
+            })));
+        }
+        if let Err(resp) = resolve_operation_fields(&item.operation, item.mode, item.status) {
+            return Ok(resp);
+        }
+        let seq_hash = hash_sequence(&item.sequence);
+        leaves.push(attest_leaf_hash(seq_hash));
+    }
+
+    let tree = AttestMerkleTree::build(leaves.clone());
+    let root = tree.root();
+
+    let mut items = Vec::with_capacity(leaves.len());
+    let mut entries = Vec::with_capacity(leaves.len());
+    for (index, leaf) in leaves.into_iter().enumerate() {
+        let proof = tree
+            .proof(index)
+            .expect("index is within the tree built from the same leaves");
+        entries.push((leaf, proof.clone()));
+        items.push(AttestBatchItemResult {
+            leaf_index: index,
+            leaf: to_hex(&leaf),
+            proof: proof_steps(&proof),
+        });
+    }
+
+    proofs.store(root, entries);
+
+    Ok(HttpResponse::Ok().json(AttestBatchResponse {
+        root: to_hex(&root),
+        batch_size: tree.leaf_count(),
+        tree_height: tree.height(),
+        items,
+    }))
+}
+
+pub async fn get_attest_proof(
+    path: web::Path<(String, usize)>,
+    proofs: web::Data<AttestProofStore>,
+) -> Result<HttpResponse> {
+    let (root_hex, index) = path.into_inner();
+
+    let root_bytes = match from_hex(&root_hex) {
+        Some(bytes) if bytes.len() == 32 => bytes,
+        _ => {
+            return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": "root must be a 32-byte hex string"
+            })))
+        }
+    };
+    let mut root = [0u8; 32];
+    root.copy_from_slice(&root_bytes);
+
+    match proofs.get(root, index) {
+        Some((leaf, proof)) => Ok(HttpResponse::Ok().json(AttestProofResponse {
+            root: root_hex,
+            leaf_index: index,
+            leaf: to_hex(&leaf),
+            proof: proof_steps(&proof),
+        })),
+        None => Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": "No proof found for this root and index"
+        }))),
+    }
+}
@@ -10,7 +10,8 @@ use validator::Validate;
 use crate::dna::EncodingMode;
 use crate::error::Result;
 use crate::models::SafetyStatus;
-use crate::solana::{build_attest_transaction, hash_sequence};
+use crate::policy::PolicyStore;
+use crate::solana::{build_attest_transaction, hash_sequence, NonceConfig};
 
 #[derive(Debug, Deserialize, Validate)]
 pub struct BuildAttestRequest {
@@ -28,10 +29,41 @@ pub struct BuildAttestRequest {
 
     #[validate(length(min = 32, max = 44))]
     pub payer: String,
+
+    /// Durable nonce account to stamp the transaction with instead of a
+    /// live blockhash, for offline or hardware-wallet signing. Must be
+    /// given together with `nonce_authority`.
+    #[serde(default)]
+    #[validate(length(min = 32, max = 44))]
+    pub nonce_account: Option<String>,
+
+    /// Authority of `nonce_account`; must co-sign the built transaction.
+    #[serde(default)]
+    #[validate(length(min = 32, max = 44))]
+    pub nonce_authority: Option<String>,
+}
+
+/// Resolve the `mode`/`status` fields relevant to `operation`, rejecting
+/// any other operation name. Shared by the wallet-signed transaction path
+/// and the verifiable-credential issuance path, since both start from the
+/// same [`BuildAttestRequest`] fields.
+pub(crate) fn resolve_operation_fields(
+    operation: &str,
+    mode: Option<EncodingMode>,
+    status: Option<SafetyStatus>,
+) -> std::result::Result<(Option<EncodingMode>, Option<SafetyStatus>), HttpResponse> {
+    match operation {
+        "encode" | "decode" => Ok((mode, None)),
+        "safety" => Ok((None, status)),
+        _ => Err(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "operation must be encode, decode, or safety"
+        }))),
+    }
 }
 
 pub async fn build_attest_transaction_handler(
     req: web::Json<BuildAttestRequest>,
+    policy: web::Data<PolicyStore>,
 ) -> Result<HttpResponse> {
     if let Err(errors) = req.validate() {
         return Ok(HttpResponse::BadRequest().json(serde_json::json!({
@@ -49,29 +81,58 @@ pub async fn build_attest_transaction_handler(
         }
     };
 
+    let (mode, status) = match resolve_operation_fields(&req.operation, req.mode, req.status) {
+        Ok(v) => v,
+        Err(resp) => return Ok(resp),
+    };
+
+    if let Err(violation) = policy
+        .current()
+        .evaluate(&req.operation, &req.sequence, &payer, mode, status)
+    {
+        return Ok(violation.into_response());
+    }
+
     let seq_hash = hash_sequence(&req.sequence);
 
-    let mode = match req.operation.as_str() {
-        "encode" | "decode" => req.mode,
-        "safety" => None,
+    let nonce = match (&req.nonce_account, &req.nonce_authority) {
+        (Some(account), Some(authority)) => {
+            let nonce_account = match solana_sdk::pubkey::Pubkey::from_str(account) {
+                Ok(p) => p,
+                Err(_) => {
+                    return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                        "error": "Invalid nonce_account pubkey"
+                    })))
+                }
+            };
+            let nonce_authority = match solana_sdk::pubkey::Pubkey::from_str(authority) {
+                Ok(p) => p,
+                Err(_) => {
+                    return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                        "error": "Invalid nonce_authority pubkey"
+                    })))
+                }
+            };
+            Some(NonceConfig {
+                nonce_account,
+                nonce_authority,
+            })
+        }
+        (None, None) => None,
         _ => {
             return Ok(HttpResponse::BadRequest().json(serde_json::json!({
-                "error": "operation must be encode, decode, or safety"
-            })));
+                "error": "nonce_account and nonce_authority must be given together"
+            })))
         }
     };
 
-    let status = match req.operation.as_str() {
-        "safety" => req.status,
-        _ => None,
-    };
-
     let tx_bytes = match build_attest_transaction(
         payer,
         &req.operation,
         seq_hash,
         mode,
         status,
+        nonce,
     )
     .await
     {
@@ -0,0 +1,104 @@
+//! Verifiable Credential attestation endpoints
+//!
+//! Off-chain-verifiable counterpart to [`crate::api::build_attest`]: instead
+//! of a transaction for a wallet to sign on-chain, issues a JWT-encoded W3C
+//! Verifiable Credential signed with the server's own ed25519 key. Works
+//! even when `SOLANA_RPC_URL` is unset, since issuing and verifying a
+//! credential never touches the chain.
+
+use actix_web::{web, HttpResponse};
+use tracing::{error, instrument};
+use validator::Validate;
+
+use crate::api::build_attest::{resolve_operation_fields, BuildAttestRequest};
+use crate::credential::{issue_credential, verify_credential};
+use crate::error::Result;
+use crate::models::{CredentialResponse, VerifyCredentialRequest, VerifyCredentialResponse};
+use crate::solana::{hash_sequence, SolanaClient};
+
+/// Issue a Verifiable Credential attesting to an encode/decode/safety operation.
+#[instrument(skip(req))]
+pub async fn issue_attestation_credential(
+    req: web::Json<BuildAttestRequest>,
+) -> Result<HttpResponse> {
+    if let Err(errors) = req.validate() {
+        error!("Validation errors: {:?}", errors);
+        return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "Validation failed",
+            "details": errors
+        })));
+    }
+
+    let (mode, status) = match resolve_operation_fields(&req.operation, req.mode, req.status) {
+        Ok(v) => v,
+        Err(resp) => return Ok(resp),
+    };
+
+    let client = match SolanaClient::from_env() {
+        Some(client) => client,
+        None => {
+            error!("Credential issuance requested but no signing keypair configured");
+            return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": "No signing keypair configured. Set SOLANA_KEYPAIR_PATH."
+            })));
+        }
+    };
+
+    let seq_hash = hash_sequence(&req.sequence);
+    let issued_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+    let pubkey = client
+        .wallet_pubkey()
+        .expect("client.is_available() implied by from_env() returning Some")
+        .to_bytes();
+
+    let jws = match issue_credential(
+        &pubkey,
+        |msg| client.sign_bytes(msg),
+        &req.operation,
+        seq_hash,
+        mode,
+        status,
+        issued_at,
+    ) {
+        Ok(jws) => jws,
+        Err(e) => {
+            error!("Credential issuance failed: {}", e);
+            return Ok(HttpResponse::InternalServerError().json(serde_json::json!({
+                "error": e.to_string()
+            })));
+        }
+    };
+
+    Ok(HttpResponse::Ok().json(CredentialResponse { jws }))
+}
+
+/// Verify a Verifiable Credential JWS previously issued by
+/// [`issue_attestation_credential`].
+#[instrument(skip(req))]
+pub async fn verify_attestation_credential(
+    req: web::Json<VerifyCredentialRequest>,
+) -> Result<HttpResponse> {
+    if let Err(errors) = req.validate() {
+        error!("Validation errors: {:?}", errors);
+        return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "Validation failed",
+            "details": errors
+        })));
+    }
+
+    let response = match verify_credential(&req.jws) {
+        Ok(claims) => VerifyCredentialResponse {
+            valid: true,
+            claims: Some(claims),
+        },
+        Err(_) => VerifyCredentialResponse {
+            valid: false,
+            claims: None,
+        },
+    };
+
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -1,24 +1,43 @@
 //! Decode API endpoint
 
-use actix_web::{web, HttpResponse};
+use actix_web::{web, HttpRequest, HttpResponse};
+use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::{info, error, instrument};
 use validator::Validate;
 
+use crate::auth::middleware::extract_token_from_headers;
+use crate::auth::{verify_chain, TrustedRootDid};
 use crate::dna::{
     basic::DNACrypto,
     nanopore::NanoporeDNACrypto,
+    openpgp::OpenPgpDNACrypto,
     secure::SecureDNACrypto,
     split_key::SplitKeyDNACrypto,
     traits::{DNACoder, SequenceStats as TraitsSequenceStats},
 };
 use crate::error::Result;
 use crate::models::{DecodeRequest, DecodeResponse, SequenceStats};
+use crate::solana::merkle::to_hex;
 use crate::solana::{hash_sequence, SolanaClient};
 
+/// Prefix a decode capability is named with, so a token can only ever grant
+/// decoding rights (never be confused with a `screen`/`admin`/... capability
+/// issued for another endpoint). The suffix is the hex-encoded SHA-256 hash
+/// of the one sequence the capability authorizes decoding.
+const DECODE_CAPABILITY_PREFIX: &str = "decode:";
+
 /// Decode DNA sequence to message
-#[instrument(skip(req))]
+///
+/// Requires a capability token in the `Authorization: Bearer <base64 JSON
+/// delegation chain>` header, rooted at `TrustedRootDid` and granting
+/// `decode:<sha256 hex of the requested sequence>`, so a token only ever
+/// authorizes decoding the one sequence it names and can't be replayed
+/// against another.
+#[instrument(skip(req, http_req, trusted_root))]
 pub async fn decode_message(
     req: web::Json<DecodeRequest>,
+    http_req: HttpRequest,
+    trusted_root: web::Data<TrustedRootDid>,
 ) -> Result<HttpResponse> {
     // Validate request
     if let Err(errors) = req.validate() {
@@ -35,9 +54,20 @@ pub async fn decode_message(
         password,
         k1_base64,
         k2_base64,
+        key_share_format,
         decode_on_chain,
     } = req.into_inner();
 
+    let required_capability = format!("{}{}", DECODE_CAPABILITY_PREFIX, to_hex(&hash_sequence(&sequence)));
+    let chain = extract_token_from_headers(http_req.headers())?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    verify_chain(&chain, &trusted_root.0, &required_capability, now)?;
+
+    let key_share_format = key_share_format.unwrap_or(crate::dna::split_key::KeyShareFormat::Base64);
+
     info!("Decoding sequence (mode: {}, length: {})", mode, sequence.len());
 
     // Check password for secure mode
@@ -60,7 +90,7 @@ pub async fn decode_message(
 
     // Decode based on mode
     let decoded_message = match mode {
-        crate::dna::EncodingMode::Basic => DNACrypto::decode_sequence(&sequence)?,
+        crate::dna::EncodingMode::Basic => DNACrypto::decode_sequence_with_compression(&sequence)?,
         crate::dna::EncodingMode::Nanopore => NanoporeDNACrypto::decode_sequence(&sequence)?,
         crate::dna::EncodingMode::Secure => {
             let pwd = password.as_ref().expect("password validated above");
@@ -69,8 +99,9 @@ pub async fn decode_message(
         crate::dna::EncodingMode::SplitKey => {
             let k1 = k1_base64.as_ref().expect("k1 validated above");
             let k2 = k2_base64.as_ref().expect("k2 validated above");
-            SplitKeyDNACrypto::decode_with_split_keys(&sequence, k1, k2)?
+            SplitKeyDNACrypto::decode_with_split_keys_and_format(&sequence, k1, k2, key_share_format)?
         }
+        crate::dna::EncodingMode::OpenPgp => OpenPgpDNACrypto::decode_sequence(&sequence)?,
     };
 
     // Get statistics
@@ -79,6 +110,7 @@ pub async fn decode_message(
         crate::dna::EncodingMode::Nanopore => NanoporeDNACrypto::get_sequence_stats(&sequence),
         crate::dna::EncodingMode::Secure => SecureDNACrypto::get_sequence_stats(&sequence),
         crate::dna::EncodingMode::SplitKey => SplitKeyDNACrypto::get_sequence_stats(&sequence),
+        crate::dna::EncodingMode::OpenPgp => OpenPgpDNACrypto::get_sequence_stats(&sequence),
     };
 
     let transaction_signature = if decode_on_chain {
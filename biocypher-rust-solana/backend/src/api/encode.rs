@@ -7,6 +7,7 @@ use validator::Validate;
 use crate::dna::{
     basic::DNACrypto,
     nanopore::NanoporeDNACrypto,
+    openpgp::OpenPgpDNACrypto,
     secure::SecureDNACrypto,
     split_key::SplitKeyDNACrypto,
     traits::{DNACoder, SequenceStats as TraitsSequenceStats},
@@ -34,10 +35,17 @@ pub async fn encode_message(
         message,
         mode,
         password,
+        cipher,
+        compression,
+        key_share_format,
         store_on_chain,
         escrow_url: _escrow_url,
     } = req.into_inner();
 
+    let cipher = cipher.unwrap_or(crate::dna::secure::Cipher::AesGcm);
+    let compression = compression.unwrap_or(crate::dna::secure::Compression::None);
+    let key_share_format = key_share_format.unwrap_or(crate::dna::split_key::KeyShareFormat::Base64);
+
     info!("Encoding message (mode: {}, length: {})", mode, message.len());
 
     // Check password for secure mode
@@ -49,23 +57,38 @@ pub async fn encode_message(
     }
 
     // Encode based on mode
-    let (dna_sequence, k1_base64, k2_base64) = match mode {
+    let (dna_sequence, k1_base64, k2_base64, raw_bytes, compressed_bytes) = match mode {
         crate::dna::EncodingMode::Basic => {
-            let seq = DNACrypto::encode_message(&message)?;
-            (seq, None, None)
+            // Always goes through the self-describing framed header (even
+            // for `Compression::None`) so decode never needs to guess
+            // whether a given sequence was compressed.
+            let (seq, raw, compressed) =
+                DNACrypto::encode_message_with_compression(&message, compression)?;
+            (seq, None, None, Some(raw), Some(compressed))
         }
         crate::dna::EncodingMode::Nanopore => {
             let seq = NanoporeDNACrypto::encode_message(&message)?;
-            (seq, None, None)
+            (seq, None, None, None, None)
         }
         crate::dna::EncodingMode::Secure => {
             let pwd = password.as_ref().expect("password validated above");
-            let seq = SecureDNACrypto::encode_with_password(&message, pwd)?;
-            (seq, None, None)
+            let (seq, raw, compressed) = SecureDNACrypto::encode_with_password_cipher_and_compression(
+                &message, pwd, cipher, compression,
+            )?;
+            (seq, None, None, Some(raw), Some(compressed))
         }
         crate::dna::EncodingMode::SplitKey => {
-            let (seq, k1, k2) = SplitKeyDNACrypto::encode_with_split_keys(&message)?;
-            (seq, Some(k1), Some(k2))
+            let (seq, k1, k2, raw, compressed) = SplitKeyDNACrypto::encode_with_split_keys_and_format(
+                &message, cipher, compression, key_share_format,
+            )?;
+            (seq, Some(k1), Some(k2), Some(raw), Some(compressed))
+        }
+        crate::dna::EncodingMode::OpenPgp => {
+            // The message itself is the ASCII-armored PGP block; this
+            // crate never touches its cryptography, so no password/cipher
+            // handling applies here.
+            let seq = OpenPgpDNACrypto::encode_message(&message)?;
+            (seq, None, None, None, None)
         }
     };
 
@@ -75,6 +98,7 @@ pub async fn encode_message(
         crate::dna::EncodingMode::Nanopore => NanoporeDNACrypto::get_sequence_stats(&dna_sequence),
         crate::dna::EncodingMode::Secure => SecureDNACrypto::get_sequence_stats(&dna_sequence),
         crate::dna::EncodingMode::SplitKey => SplitKeyDNACrypto::get_sequence_stats(&dna_sequence),
+        crate::dna::EncodingMode::OpenPgp => OpenPgpDNACrypto::get_sequence_stats(&dna_sequence),
     };
 
     let transaction_signature = if store_on_chain {
@@ -120,6 +144,8 @@ pub async fn encode_message(
                 g: stats.bases.g,
             },
             gc_content: stats.gc_content,
+            raw_bytes,
+            compressed_bytes,
         },
         k1_base64,
         k2_base64,
@@ -1,7 +1,11 @@
 //! API module - HTTP request handlers
 
+pub mod attest_batch;
 pub mod build_attest;
+pub mod credential;
 pub mod encode;
 pub mod decode;
+pub mod mxe;
+pub mod policy;
 pub mod safety;
 pub mod solana;
@@ -0,0 +1,100 @@
+//! Confidential (MPC-encrypted) DNA encoding endpoints.
+//!
+//! `POST /api/encode-private` queues a computation on the Arcium MXE and
+//! `GET /api/mxe/orders/{id}` reports its current status. The order itself
+//! is advanced by the background poller spawned in `main.rs`; see
+//! [`crate::arcium::orders`] for the lifecycle.
+
+use actix_web::{web, HttpResponse};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use tracing::{error, instrument};
+use validator::Validate;
+
+use crate::arcium::orders::{MxeOrder, MxeOrderStore};
+use crate::error::Result;
+use crate::models::{EncodePrivateRequest, MxeOrderResponse};
+
+fn to_response(order: MxeOrder) -> MxeOrderResponse {
+    MxeOrderResponse {
+        id: order.id,
+        status: order.status,
+        status_url: order.status_url,
+        result_ciphertext: order.result_ciphertext.map(|c| BASE64.encode(c)),
+        error: order.error,
+        retry_after_secs: order.retry_after_secs,
+    }
+}
+
+/// Queue a confidential encoding computation. Returns the freshly created
+/// order, still `PENDING`; poll `status_url` to watch it move to
+/// `PROCESSING` and on to `VALID`/`INVALID`.
+#[instrument(skip(req))]
+pub async fn queue_private_encode(
+    req: web::Json<EncodePrivateRequest>,
+    orders: web::Data<MxeOrderStore>,
+) -> Result<HttpResponse> {
+    if let Err(errors) = req.validate() {
+        error!("Validation errors: {:?}", errors);
+        return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "Validation failed",
+            "details": errors
+        })));
+    }
+
+    let ciphertext_bytes = match BASE64.decode(&req.ciphertext) {
+        Ok(bytes) if !bytes.is_empty() && bytes.len() % 32 == 0 => bytes,
+        _ => {
+            return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": "ciphertext must be base64-encoded 32-byte words"
+            })))
+        }
+    };
+    let ciphertext: Vec<[u8; 32]> = ciphertext_bytes
+        .chunks_exact(32)
+        .map(|chunk| chunk.try_into().expect("chunks_exact(32) yields 32-byte slices"))
+        .collect();
+
+    let client_pubkey = match BASE64
+        .decode(&req.client_pubkey)
+        .ok()
+        .and_then(|b| <[u8; 32]>::try_from(b).ok())
+    {
+        Some(key) => key,
+        None => {
+            return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": "client_pubkey must be a base64-encoded 32-byte x25519 key"
+            })))
+        }
+    };
+
+    let nonce = match BASE64
+        .decode(&req.nonce)
+        .ok()
+        .and_then(|b| <[u8; 16]>::try_from(b).ok())
+    {
+        Some(bytes) => u128::from_le_bytes(bytes),
+        None => {
+            return Ok(HttpResponse::BadRequest().json(serde_json::json!({
+                "error": "nonce must be a base64-encoded 16-byte value"
+            })))
+        }
+    };
+
+    let order = orders.create(client_pubkey, nonce, ciphertext);
+    Ok(HttpResponse::Ok().json(to_response(order)))
+}
+
+/// Report the current status of a previously queued order.
+#[instrument]
+pub async fn get_order_status(
+    path: web::Path<String>,
+    orders: web::Data<MxeOrderStore>,
+) -> Result<HttpResponse> {
+    let id = path.into_inner();
+    match orders.get(&id) {
+        Some(order) => Ok(HttpResponse::Ok().json(to_response(order))),
+        None => Ok(HttpResponse::NotFound().json(serde_json::json!({
+            "error": format!("No such order: {}", id)
+        }))),
+    }
+}
@@ -0,0 +1,15 @@
+//! Admin endpoint for hot-reloading the attestation policy.
+
+use actix_web::{web, HttpResponse};
+use tracing::instrument;
+
+use crate::error::Result;
+use crate::policy::PolicyStore;
+
+/// Re-read the policy file from disk and start enforcing it immediately,
+/// without restarting the server. Gated behind the `admin` capability.
+#[instrument(skip(policy))]
+pub async fn reload_policy(policy: web::Data<PolicyStore>) -> Result<HttpResponse> {
+    policy.reload();
+    Ok(HttpResponse::Ok().json(serde_json::json!({ "reloaded": true })))
+}
@@ -1,18 +1,26 @@
 //! Safety screening API endpoint
 
-use actix_web::{web, HttpResponse};
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
 use tracing::{info, error, instrument};
 use validator::Validate;
 
-use crate::error::Result;
-use crate::models::{SafetyScreenRequest, SafetyScreenResponse};
+use crate::auth::GrantedCapabilities;
+use crate::error::{ApiError, Result};
+use crate::models::{MerkleInclusionProof, SafetyScreenRequest, SafetyScreenResponse};
 use crate::safety::DNASafetyScreener;
-use crate::solana::{hash_sequence, SolanaClient};
+use crate::solana::merkle::{self, to_hex};
+use crate::solana::{hash_sequence, SafetyBatcher, SolanaClient};
+
+/// Capability required to write a screening result on-chain, checked in
+/// addition to the `screen` capability the route itself requires.
+const RECORD_ON_CHAIN_CAPABILITY: &str = "record-on-chain";
 
 /// Screen DNA sequence for safety
-#[instrument(skip(req))]
+#[instrument(skip(http_req, req))]
 pub async fn safety_screen(
+    http_req: HttpRequest,
     req: web::Json<SafetyScreenRequest>,
+    batcher: web::Data<SafetyBatcher>,
 ) -> Result<HttpResponse> {
     // Validate request
     if let Err(errors) = req.validate() {
@@ -34,22 +42,35 @@ pub async fn safety_screen(
     let screener = DNASafetyScreener::new();
     let report = screener.perform_comprehensive_screening(&dna_sequence)?;
 
-    let transaction_signature = if verify_on_chain {
-        match SolanaClient::from_env() {
-            Some(client) => {
-                let seq_hash = hash_sequence(&dna_sequence);
-                match client.record_safety(seq_hash, report.safety_status).await {
-                    Ok(sig) => Some(sig),
-                    Err(e) => {
-                        error!("Solana record_safety failed: {}", e);
-                        None
-                    }
-                }
+    if verify_on_chain {
+        let granted = http_req.extensions().get::<GrantedCapabilities>().cloned();
+        if !granted.is_some_and(|g| g.has(RECORD_ON_CHAIN_CAPABILITY)) {
+            return Err(ApiError::Forbidden.into());
+        }
+    }
+
+    let (transaction_signature, merkle_proof) = if verify_on_chain {
+        let client = SolanaClient::from_env();
+        let seq_hash = hash_sequence(&dna_sequence);
+        match batcher.add(client.as_ref(), seq_hash, report.safety_status).await {
+            Ok(Some(commitment)) => {
+                let leaf = merkle::leaf_hash(seq_hash, report.safety_status);
+                let proof = MerkleInclusionProof {
+                    leaf: to_hex(&leaf),
+                    root: to_hex(&commitment.root),
+                    siblings: commitment.proof.steps.iter().map(|s| to_hex(&s.sibling)).collect(),
+                    sibling_is_left: commitment.proof.steps.iter().map(|s| s.sibling_is_left).collect(),
+                };
+                (commitment.transaction_signature, Some(proof))
+            }
+            Ok(None) => (None, None),
+            Err(e) => {
+                error!("Solana safety batch commit failed: {}", e);
+                (None, None)
             }
-            None => None,
         }
     } else {
-        None
+        (None, None)
     };
 
     let response = SafetyScreenResponse {
@@ -61,6 +82,7 @@ pub async fn safety_screen(
         sequence_characteristics: report.sequence_characteristics,
         recommendations: report.recommendations,
         transaction_signature,
+        merkle_proof,
     };
 
     info!("Safety screening complete (status: {:?})", response.safety_status);
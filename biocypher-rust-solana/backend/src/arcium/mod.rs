@@ -28,7 +28,9 @@
 //!    that invokes the MXE. Backend returns encrypted result to client.
 //!
 //! 3. **Hybrid**: Standard encode/decode for non-sensitive data; MXE for
-//!    confidential messages (e.g. `/api/encode-private`).
+//!    confidential messages via `/api/encode-private`, which queues an
+//!    [`orders::MxeOrder`] and polls it through to completion — see that
+//!    module for the order lifecycle.
 //!
 //! ## MXE Project
 //!
@@ -42,9 +44,13 @@
 //!
 //! See [docs/ARCIUM_EDUCATIONAL_GUIDE.md](../../../docs/ARCIUM_EDUCATIONAL_GUIDE.md) for details.
 
-use actix_web::{web, HttpResponse};
+use actix_web::HttpResponse;
 use serde::Serialize;
 
+pub mod orders;
+
+pub use orders::MxeOrderStore;
+
 #[derive(Serialize)]
 pub struct ArciumInfo {
     pub mxe_project: &'static str,
@@ -0,0 +1,300 @@
+//! Async order/polling state machine for confidential (MPC-encrypted) DNA
+//! computations.
+//!
+//! Modeled on an ACME-style order lifecycle: `POST /api/encode-private`
+//! creates an [`MxeOrder`] and queues it on the Arcium MXE (see
+//! `biocypher-mxe/`), `GET /api/mxe/orders/{id}` reports its current
+//! status, and a background poller (spawned in `main.rs`) advances every
+//! open order by checking the chain. Transitions are monotonic and
+//! idempotent — a terminal order never changes again, and advancing a
+//! non-terminal order that hasn't changed on-chain is a no-op:
+//!
+//! ```text
+//! Pending ──(queued on-chain)──> Processing ──(callback lands)──> Valid
+//!    │                               │
+//!    └──(queue failed)───> Invalid <─┘──(timeout / cluster error)
+//! ```
+//!
+//! The client supplies its own x25519 pubkey/nonce on the request; the MXE
+//! computes on ciphertext it never decrypts, so the result stored on the
+//! order is still ciphertext the caller decrypts itself once `Valid`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::solana::SolanaClient;
+
+/// How long an order may sit in `Processing` before a poll gives up and
+/// marks it `Invalid` instead of retrying forever.
+pub const ORDER_TIMEOUT_SECS: i64 = 120;
+
+/// Hint (seconds) a client should wait before polling a non-terminal order
+/// again.
+pub const RETRY_AFTER_SECS: u64 = 2;
+
+/// Status of an [`MxeOrder`]. `Valid` and `Invalid` are terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum MxeOrderStatus {
+    Pending,
+    Processing,
+    Valid,
+    Invalid,
+}
+
+impl MxeOrderStatus {
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, MxeOrderStatus::Valid | MxeOrderStatus::Invalid)
+    }
+}
+
+/// A queued or in-flight confidential encoding computation.
+#[derive(Debug, Clone)]
+pub struct MxeOrder {
+    pub id: String,
+    pub status: MxeOrderStatus,
+    pub status_url: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+
+    /// Client's x25519 pubkey, used by the MXE to derive the shared secret
+    /// the input was encrypted under and its output is re-encrypted to.
+    pub client_pubkey: [u8; 32],
+    pub nonce: u128,
+    pub ciphertext: Vec<[u8; 32]>,
+
+    /// Offset correlating this order with its on-chain computation
+    /// account, assigned once queued. `None` while still `Pending`.
+    pub computation_offset: Option<u64>,
+
+    /// Encrypted DNA result, set once `Valid`. The caller decrypts this
+    /// client-side with the key derived from `client_pubkey`/`nonce`; the
+    /// server never sees plaintext.
+    pub result_ciphertext: Option<Vec<u8>>,
+
+    /// Reason the order became `Invalid`.
+    pub error: Option<String>,
+
+    /// Present while non-terminal: hint for how long to wait before
+    /// polling again.
+    pub retry_after_secs: Option<u64>,
+}
+
+/// Thread-safe store of in-flight orders, shared across requests as
+/// `web::Data<MxeOrderStore>`.
+#[derive(Default)]
+pub struct MxeOrderStore {
+    orders: Mutex<HashMap<String, MxeOrder>>,
+}
+
+impl MxeOrderStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new order in `Pending` and store it.
+    pub fn create(&self, client_pubkey: [u8; 32], nonce: u128, ciphertext: Vec<[u8; 32]>) -> MxeOrder {
+        let id = Uuid::new_v4().to_string();
+        let now = now_unix();
+        let order = MxeOrder {
+            status_url: format!("/api/mxe/orders/{}", id),
+            id: id.clone(),
+            status: MxeOrderStatus::Pending,
+            created_at: now,
+            updated_at: now,
+            client_pubkey,
+            nonce,
+            ciphertext,
+            computation_offset: None,
+            result_ciphertext: None,
+            error: None,
+            retry_after_secs: Some(RETRY_AFTER_SECS),
+        };
+        self.orders
+            .lock()
+            .expect("mxe order store lock poisoned")
+            .insert(id, order.clone());
+        order
+    }
+
+    pub fn get(&self, id: &str) -> Option<MxeOrder> {
+        self.orders
+            .lock()
+            .expect("mxe order store lock poisoned")
+            .get(id)
+            .cloned()
+    }
+
+    /// Ids of every order that hasn't reached a terminal status yet.
+    fn open_ids(&self) -> Vec<String> {
+        self.orders
+            .lock()
+            .expect("mxe order store lock poisoned")
+            .values()
+            .filter(|o| !o.status.is_terminal())
+            .map(|o| o.id.clone())
+            .collect()
+    }
+
+    /// Advance a single order one step, if it isn't terminal yet, and
+    /// persist the result. Returns `None` if `id` doesn't exist. Calling
+    /// this on a terminal order is a no-op that just returns it unchanged.
+    pub async fn advance(&self, id: &str, client: Option<&SolanaClient>) -> Option<MxeOrder> {
+        let order = self.get(id)?;
+        if order.status.is_terminal() {
+            return Some(order);
+        }
+
+        let advanced = match order.status {
+            MxeOrderStatus::Pending => self.try_queue(order, client).await,
+            MxeOrderStatus::Processing => self.try_poll(order, client).await,
+            MxeOrderStatus::Valid | MxeOrderStatus::Invalid => unreachable!("terminal orders returned above"),
+        };
+
+        self.orders
+            .lock()
+            .expect("mxe order store lock poisoned")
+            .insert(id.to_string(), advanced.clone());
+        Some(advanced)
+    }
+
+    /// Advance every open order once. This is what the background poller
+    /// in `main.rs` calls on a timer.
+    pub async fn advance_all(&self, client: Option<&SolanaClient>) {
+        for id in self.open_ids() {
+            self.advance(&id, client).await;
+        }
+    }
+
+    async fn try_queue(&self, mut order: MxeOrder, client: Option<&SolanaClient>) -> MxeOrder {
+        match client {
+            None => {
+                order.status = MxeOrderStatus::Invalid;
+                order.error = Some("No signing keypair configured; cannot queue MXE computation".into());
+                order.retry_after_secs = None;
+            }
+            Some(client) => {
+                match client
+                    .queue_mxe_computation(&order.ciphertext, order.client_pubkey, order.nonce)
+                    .await
+                {
+                    Ok(offset) => {
+                        order.status = MxeOrderStatus::Processing;
+                        order.computation_offset = Some(offset);
+                        order.retry_after_secs = Some(RETRY_AFTER_SECS);
+                    }
+                    Err(e) => {
+                        order.status = MxeOrderStatus::Invalid;
+                        order.error = Some(e.to_string());
+                        order.retry_after_secs = None;
+                    }
+                }
+            }
+        }
+        order.updated_at = now_unix();
+        order
+    }
+
+    async fn try_poll(&self, mut order: MxeOrder, client: Option<&SolanaClient>) -> MxeOrder {
+        let offset = order
+            .computation_offset
+            .expect("Processing orders were queued with an offset");
+        let elapsed = now_unix() - order.created_at;
+
+        let result = match client {
+            None => Err(crate::error::BioCypherError::Solana(
+                "No signing keypair configured; cannot poll MXE computation".into(),
+            )),
+            Some(client) => client.poll_mxe_callback(offset).await,
+        };
+
+        match result {
+            Ok(Some(ciphertext)) => {
+                order.status = MxeOrderStatus::Valid;
+                order.result_ciphertext = Some(ciphertext);
+                order.retry_after_secs = None;
+            }
+            Ok(None) if elapsed >= ORDER_TIMEOUT_SECS => {
+                order.status = MxeOrderStatus::Invalid;
+                order.error = Some(format!(
+                    "Computation did not complete within {}s",
+                    ORDER_TIMEOUT_SECS
+                ));
+                order.retry_after_secs = None;
+            }
+            Ok(None) => {
+                order.retry_after_secs = Some(RETRY_AFTER_SECS);
+            }
+            Err(e) => {
+                order.status = MxeOrderStatus::Invalid;
+                order.error = Some(e.to_string());
+                order.retry_after_secs = None;
+            }
+        }
+        order.updated_at = now_unix();
+        order
+    }
+}
+
+fn now_unix() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_starts_pending_with_retry_hint() {
+        let store = MxeOrderStore::new();
+        let order = store.create([1u8; 32], 7, vec![[2u8; 32]]);
+        assert_eq!(order.status, MxeOrderStatus::Pending);
+        assert_eq!(order.status_url, format!("/api/mxe/orders/{}", order.id));
+        assert_eq!(order.retry_after_secs, Some(RETRY_AFTER_SECS));
+        assert!(store.get(&order.id).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_advance_without_client_marks_invalid() {
+        let store = MxeOrderStore::new();
+        let order = store.create([1u8; 32], 7, vec![[2u8; 32]]);
+        let advanced = store.advance(&order.id, None).await.unwrap();
+        assert_eq!(advanced.status, MxeOrderStatus::Invalid);
+        assert!(advanced.error.is_some());
+        assert!(advanced.retry_after_secs.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_advance_is_idempotent_once_terminal() {
+        let store = MxeOrderStore::new();
+        let order = store.create([1u8; 32], 7, vec![[2u8; 32]]);
+        let first = store.advance(&order.id, None).await.unwrap();
+        let second = store.advance(&order.id, None).await.unwrap();
+        assert_eq!(first.status, second.status);
+        assert_eq!(first.updated_at, second.updated_at);
+    }
+
+    #[tokio::test]
+    async fn test_advance_unknown_id_returns_none() {
+        let store = MxeOrderStore::new();
+        assert!(store.advance("no-such-order", None).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_advance_all_skips_terminal_orders() {
+        let store = MxeOrderStore::new();
+        let order = store.create([1u8; 32], 7, vec![[2u8; 32]]);
+        store.advance(&order.id, None).await;
+        assert!(store.open_ids().is_empty());
+        // Should not panic or change the now-terminal order.
+        store.advance_all(None).await;
+        assert_eq!(store.get(&order.id).unwrap().status, MxeOrderStatus::Invalid);
+    }
+}
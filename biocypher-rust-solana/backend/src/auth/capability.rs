@@ -0,0 +1,260 @@
+//! Capability delegation chain (UCAN-style) and its verification
+//!
+//! A [`CapabilityLink`] is one step in a delegation chain: `issuer` grants
+//! `audience` a set of `capabilities`, valid until `expires_at`, signed by
+//! `issuer`'s private key. A full token is a `Vec<CapabilityLink>` running
+//! from a trusted root issuer down to the caller: link 0's issuer is the
+//! trusted root, link N's issuer is link (N-1)'s audience, and each link's
+//! capabilities must be a subset of its parent's (attenuation only, never
+//! escalation).
+
+use crate::error::{ApiError, BioCypherError, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+const DID_KEY_PREFIX: &str = "did:key:";
+
+/// One link in a capability delegation chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapabilityLink {
+    /// DID of the party delegating access (signs this link).
+    pub issuer: String,
+    /// DID of the party this link is issued to.
+    pub audience: String,
+    /// Named capabilities granted by this link (e.g. `"screen"`).
+    pub capabilities: Vec<String>,
+    /// Unix timestamp (seconds) after which this link is no longer valid.
+    pub expires_at: i64,
+    /// Base64-encoded ed25519 signature over the canonical encoding of
+    /// (issuer, audience, capabilities, expires_at), made with `issuer`'s key.
+    pub signature: String,
+}
+
+/// The fields a [`CapabilityLink`] signs over, excluding the signature itself.
+#[derive(Serialize)]
+struct SignedPayload<'a> {
+    issuer: &'a str,
+    audience: &'a str,
+    capabilities: &'a [String],
+    expires_at: i64,
+}
+
+/// Verify a capability delegation chain, returning the capabilities granted
+/// by its final link on success.
+///
+/// Checks, for every link: the signature is valid for the claimed issuer,
+/// the link has not expired, its issuer matches the previous link's
+/// audience (chain continuity), and its capabilities are a subset of the
+/// previous link's (attenuation). The first link's issuer must be
+/// `trusted_root_did`, and the final link must grant `required_capability`.
+pub fn verify_chain(
+    links: &[CapabilityLink],
+    trusted_root_did: &str,
+    required_capability: &str,
+    now: i64,
+) -> Result<Vec<String>> {
+    let first = links.first().ok_or(ApiError::Unauthorized)?;
+    if first.issuer != trusted_root_did {
+        return Err(ApiError::Unauthorized.into());
+    }
+
+    let mut parent_capabilities: Option<&[String]> = None;
+    for (i, link) in links.iter().enumerate() {
+        if i > 0 && link.issuer != links[i - 1].audience {
+            return Err(ApiError::Unauthorized.into());
+        }
+        if link.expires_at <= now {
+            return Err(ApiError::Unauthorized.into());
+        }
+        verify_link_signature(link)?;
+
+        if let Some(parent) = parent_capabilities {
+            if !link.capabilities.iter().all(|cap| parent.contains(cap)) {
+                return Err(ApiError::Forbidden.into());
+            }
+        }
+        parent_capabilities = Some(&link.capabilities);
+    }
+
+    let granted = links.last().expect("checked non-empty above").capabilities.clone();
+    if !granted.iter().any(|cap| cap == required_capability) {
+        return Err(ApiError::Forbidden.into());
+    }
+
+    Ok(granted)
+}
+
+fn verify_link_signature(link: &CapabilityLink) -> Result<()> {
+    let issuer_key = parse_did_key(&link.issuer)?;
+
+    let payload = SignedPayload {
+        issuer: &link.issuer,
+        audience: &link.audience,
+        capabilities: &link.capabilities,
+        expires_at: link.expires_at,
+    };
+    let message = serde_json::to_vec(&payload)
+        .map_err(|e| BioCypherError::Internal(format!("Failed to canonicalize capability link: {}", e)))?;
+
+    let sig_bytes = BASE64.decode(&link.signature).map_err(|_| ApiError::Unauthorized)?;
+    let sig_arr: [u8; 64] = sig_bytes.try_into().map_err(|_| ApiError::Unauthorized)?;
+    let signature = Signature::from_bytes(&sig_arr);
+
+    issuer_key
+        .verify(&message, &signature)
+        .map_err(|_| ApiError::Unauthorized.into())
+}
+
+/// Parse a `did:key:<base64 ed25519 public key>` DID into its verifying key.
+fn parse_did_key(did: &str) -> Result<VerifyingKey> {
+    let encoded = did.strip_prefix(DID_KEY_PREFIX).ok_or(ApiError::Unauthorized)?;
+    let bytes = BASE64.decode(encoded).map_err(|_| ApiError::Unauthorized)?;
+    let arr: [u8; 32] = bytes.try_into().map_err(|_| ApiError::Unauthorized)?;
+    VerifyingKey::from_bytes(&arr).map_err(|_| ApiError::Unauthorized.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn make_did(key: &SigningKey) -> String {
+        format!("{}{}", DID_KEY_PREFIX, BASE64.encode(key.verifying_key().to_bytes()))
+    }
+
+    fn sign_link(key: &SigningKey, issuer: &str, audience: &str, capabilities: &[&str], expires_at: i64) -> CapabilityLink {
+        let capabilities: Vec<String> = capabilities.iter().map(|c| c.to_string()).collect();
+        let payload = SignedPayload {
+            issuer,
+            audience,
+            capabilities: &capabilities,
+            expires_at,
+        };
+        let message = serde_json::to_vec(&payload).unwrap();
+        let signature = key.sign(&message);
+        CapabilityLink {
+            issuer: issuer.to_string(),
+            audience: audience.to_string(),
+            capabilities,
+            expires_at,
+            signature: BASE64.encode(signature.to_bytes()),
+        }
+    }
+
+    #[test]
+    fn test_single_link_chain_grants_capability() {
+        let root_key = SigningKey::from_bytes(&[1u8; 32]);
+        let caller_key = SigningKey::from_bytes(&[2u8; 32]);
+        let root_did = make_did(&root_key);
+        let caller_did = make_did(&caller_key);
+
+        let link = sign_link(&root_key, &root_did, &caller_did, &["screen"], i64::MAX);
+        let granted = verify_chain(&[link], &root_did, "screen", 0).unwrap();
+        assert_eq!(granted, vec!["screen".to_string()]);
+    }
+
+    #[test]
+    fn test_delegated_chain_attenuates_correctly() {
+        let root_key = SigningKey::from_bytes(&[1u8; 32]);
+        let mid_key = SigningKey::from_bytes(&[3u8; 32]);
+        let caller_key = SigningKey::from_bytes(&[2u8; 32]);
+        let root_did = make_did(&root_key);
+        let mid_did = make_did(&mid_key);
+        let caller_did = make_did(&caller_key);
+
+        let root_link = sign_link(&root_key, &root_did, &mid_did, &["screen", "record-on-chain"], i64::MAX);
+        let delegated_link = sign_link(&mid_key, &mid_did, &caller_did, &["screen"], i64::MAX);
+
+        let granted = verify_chain(&[root_link, delegated_link], &root_did, "screen", 0).unwrap();
+        assert_eq!(granted, vec!["screen".to_string()]);
+    }
+
+    #[test]
+    fn test_escalation_beyond_parent_rejected() {
+        let root_key = SigningKey::from_bytes(&[1u8; 32]);
+        let mid_key = SigningKey::from_bytes(&[3u8; 32]);
+        let caller_key = SigningKey::from_bytes(&[2u8; 32]);
+        let root_did = make_did(&root_key);
+        let mid_did = make_did(&mid_key);
+        let caller_did = make_did(&caller_key);
+
+        let root_link = sign_link(&root_key, &root_did, &mid_did, &["screen"], i64::MAX);
+        let delegated_link = sign_link(&mid_key, &mid_did, &caller_did, &["screen", "record-on-chain"], i64::MAX);
+
+        let result = verify_chain(&[root_link, delegated_link], &root_did, "screen", 0);
+        assert!(matches!(
+            result,
+            Err(BioCypherError::Api(ApiError::Forbidden))
+        ));
+    }
+
+    #[test]
+    fn test_missing_capability_rejected_as_forbidden() {
+        let root_key = SigningKey::from_bytes(&[1u8; 32]);
+        let caller_key = SigningKey::from_bytes(&[2u8; 32]);
+        let root_did = make_did(&root_key);
+        let caller_did = make_did(&caller_key);
+
+        let link = sign_link(&root_key, &root_did, &caller_did, &["screen"], i64::MAX);
+        let result = verify_chain(&[link], &root_did, "record-on-chain", 0);
+        assert!(matches!(
+            result,
+            Err(BioCypherError::Api(ApiError::Forbidden))
+        ));
+    }
+
+    #[test]
+    fn test_expired_link_rejected() {
+        let root_key = SigningKey::from_bytes(&[1u8; 32]);
+        let caller_key = SigningKey::from_bytes(&[2u8; 32]);
+        let root_did = make_did(&root_key);
+        let caller_did = make_did(&caller_key);
+
+        let link = sign_link(&root_key, &root_did, &caller_did, &["screen"], 100);
+        let result = verify_chain(&[link], &root_did, "screen", 200);
+        assert!(matches!(
+            result,
+            Err(BioCypherError::Api(ApiError::Unauthorized))
+        ));
+    }
+
+    #[test]
+    fn test_broken_chain_continuity_rejected() {
+        let root_key = SigningKey::from_bytes(&[1u8; 32]);
+        let mid_key = SigningKey::from_bytes(&[3u8; 32]);
+        let impostor_key = SigningKey::from_bytes(&[4u8; 32]);
+        let caller_key = SigningKey::from_bytes(&[2u8; 32]);
+        let root_did = make_did(&root_key);
+        let mid_did = make_did(&mid_key);
+        let impostor_did = make_did(&impostor_key);
+        let caller_did = make_did(&caller_key);
+
+        let root_link = sign_link(&root_key, &root_did, &mid_did, &["screen"], i64::MAX);
+        // Signed by impostor_key but claims to be issued by mid_did, which it doesn't control.
+        let forged_link = sign_link(&impostor_key, &mid_did, &caller_did, &["screen"], i64::MAX);
+
+        let result = verify_chain(&[root_link, forged_link], &root_did, "screen", 0);
+        assert!(matches!(
+            result,
+            Err(BioCypherError::Api(ApiError::Unauthorized))
+        ));
+        let _ = impostor_did;
+    }
+
+    #[test]
+    fn test_untrusted_root_rejected() {
+        let root_key = SigningKey::from_bytes(&[1u8; 32]);
+        let caller_key = SigningKey::from_bytes(&[2u8; 32]);
+        let root_did = make_did(&root_key);
+        let caller_did = make_did(&caller_key);
+        let other_did = make_did(&SigningKey::from_bytes(&[9u8; 32]));
+
+        let link = sign_link(&root_key, &root_did, &caller_did, &["screen"], i64::MAX);
+        let result = verify_chain(&[link], &other_did, "screen", 0);
+        assert!(matches!(
+            result,
+            Err(BioCypherError::Api(ApiError::Unauthorized))
+        ));
+    }
+}
@@ -0,0 +1,138 @@
+//! actix-web middleware enforcing [`crate::auth::capability::verify_chain`]
+//!
+//! Wraps a route behind a capability token presented as
+//! `Authorization: Bearer <base64 JSON array of CapabilityLink>`. On success
+//! the verified grant is attached to the request as [`GrantedCapabilities`]
+//! so handlers can check for capabilities beyond the one the route itself
+//! required.
+
+use crate::auth::capability::{self, CapabilityLink};
+use crate::error::{ApiError, BioCypherError};
+use actix_web::body::EitherBody;
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::{Error, HttpMessage, HttpResponse};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use futures_util::future::{ready, LocalBoxFuture, Ready};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Capabilities verified for the current request, stashed as a request
+/// extension by [`CapabilityGuard`].
+#[derive(Debug, Clone)]
+pub struct GrantedCapabilities(pub Vec<String>);
+
+impl GrantedCapabilities {
+    /// Whether `capability` was granted to the caller's delegation chain.
+    pub fn has(&self, capability: &str) -> bool {
+        self.0.iter().any(|c| c == capability)
+    }
+}
+
+/// The trusted capability-token issuer DID, shared as `web::Data` with
+/// handlers that verify a delegation chain against a capability only known
+/// once the request body has been parsed (so it can't be expressed as the
+/// single fixed `required_capability` a route-level [`CapabilityGuard`]
+/// takes).
+#[derive(Debug, Clone)]
+pub struct TrustedRootDid(pub Arc<str>);
+
+/// Middleware factory requiring `required_capability` to be present in the
+/// verified delegation chain's final link, rooted at `trusted_root_did`.
+pub struct CapabilityGuard {
+    trusted_root_did: Arc<str>,
+    required_capability: Arc<str>,
+}
+
+impl CapabilityGuard {
+    pub fn new(trusted_root_did: impl Into<Arc<str>>, required_capability: impl Into<Arc<str>>) -> Self {
+        Self {
+            trusted_root_did: trusted_root_did.into(),
+            required_capability: required_capability.into(),
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for CapabilityGuard
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = CapabilityGuardMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(CapabilityGuardMiddleware {
+            service: Rc::new(service),
+            trusted_root_did: self.trusted_root_did.clone(),
+            required_capability: self.required_capability.clone(),
+        }))
+    }
+}
+
+pub struct CapabilityGuardMiddleware<S> {
+    service: Rc<S>,
+    trusted_root_did: Arc<str>,
+    required_capability: Arc<str>,
+}
+
+impl<S, B> Service<ServiceRequest> for CapabilityGuardMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let trusted_root_did = self.trusted_root_did.clone();
+        let required_capability = self.required_capability.clone();
+        let service = self.service.clone();
+        let token = extract_token_from_headers(req.headers());
+
+        Box::pin(async move {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or(0);
+
+            let verdict = token
+                .and_then(|links| capability::verify_chain(&links, &trusted_root_did, &required_capability, now));
+
+            let granted = match verdict {
+                Ok(granted) => granted,
+                Err(err) => {
+                    let response = HttpResponse::from_error(Error::from(err));
+                    return Ok(req.into_response(response.map_into_right_body()));
+                }
+            };
+
+            req.extensions_mut().insert(GrantedCapabilities(granted));
+            service.call(req).await.map(|res| res.map_into_left_body())
+        })
+    }
+}
+
+/// Parse the `Authorization: Bearer <base64 JSON array of CapabilityLink>`
+/// header into the delegation chain it carries. Shared by [`CapabilityGuard`]
+/// and handlers (e.g. `decode_message`) that verify against a per-request
+/// capability the guard can't express.
+pub(crate) fn extract_token_from_headers(
+    headers: &actix_web::http::header::HeaderMap,
+) -> Result<Vec<CapabilityLink>, BioCypherError> {
+    let header = headers
+        .get(actix_web::http::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .ok_or(ApiError::Unauthorized)?;
+    let encoded = header.strip_prefix("Bearer ").ok_or(ApiError::Unauthorized)?;
+    let bytes = BASE64.decode(encoded).map_err(|_| ApiError::Unauthorized)?;
+    serde_json::from_slice(&bytes).map_err(|_| ApiError::Unauthorized.into())
+}
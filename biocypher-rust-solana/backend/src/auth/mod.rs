@@ -0,0 +1,12 @@
+//! Capability-token (UCAN-style) authorization
+//!
+//! Guards biosecurity-sensitive endpoints behind a signed delegation chain
+//! rooted at a trusted issuer DID down to the caller's DID, where each link
+//! grants a named capability (e.g. `screen`, `record-on-chain`) that must be
+//! a subset of every parent link's grant.
+
+pub mod capability;
+pub mod middleware;
+
+pub use capability::{verify_chain, CapabilityLink};
+pub use middleware::{CapabilityGuard, GrantedCapabilities, TrustedRootDid};
@@ -0,0 +1,285 @@
+//! Versioned TOML configuration for the CLI: named profiles supplying
+//! default `--mode`/`--name`/`--output`/password-source flags, selected with
+//! `--profile NAME` and merged under whatever flags are given explicitly on
+//! the command line. Mirrors the file-loading pattern in `policy.rs`, but
+//! profiles are looked up by name instead of there being a single active
+//! policy, and a missing file is an empty default rather than a permissive
+//! one (there's nothing a CLI invocation can safely assume here).
+//!
+//! Example `~/.config/bi0cyph3r.toml`:
+//!
+//! ```toml
+//! version = "2"
+//!
+//! [profiles.default]
+//! mode = "secure"
+//! plasmid_name = "my_plasmid"
+//! output = "fasta"
+//! password_env = "BI0CYPHER_PASSWORD"
+//! ```
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// Current config schema version. Files written at an older version are
+/// migrated in place by [`Config::load`] before use.
+const CURRENT_VERSION: &str = "2";
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("failed to read config file {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse config file {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+    #[error("config file {path} has unsupported version '{version}'")]
+    UnsupportedVersion { path: PathBuf, version: String },
+    #[error("no profile named '{0}' in config")]
+    ProfileNotFound(String),
+    #[error("profile '{profile}' selects {mode} mode but provides no key material (set password_env)")]
+    MissingKeyMaterial { profile: String, mode: String },
+}
+
+/// One named set of CLI defaults. Every field is optional so a profile can
+/// supply only the flags it wants to default and leave the rest to the CLI's
+/// own defaults.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Profile {
+    pub mode: Option<String>,
+    pub plasmid_name: Option<String>,
+    pub output: Option<String>,
+    /// Name of the environment variable holding the password or key
+    /// material — never the literal secret itself.
+    pub password_env: Option<String>,
+}
+
+impl Profile {
+    /// A profile that selects `secure` or `splitkey` mode needs key
+    /// material from somewhere; catch a profile that forgot to set one at
+    /// load time rather than failing deep inside encode/decode.
+    fn validate(&self, name: &str) -> Result<(), ConfigError> {
+        let needs_key_material = matches!(self.mode.as_deref(), Some("secure") | Some("splitkey"));
+        if needs_key_material && self.password_env.is_none() {
+            return Err(ConfigError::MissingKeyMaterial {
+                profile: name.to_string(),
+                mode: self.mode.clone().unwrap_or_default(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Config {
+    pub version: String,
+    #[serde(default)]
+    pub profiles: HashMap<String, Profile>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            version: CURRENT_VERSION.to_string(),
+            profiles: HashMap::new(),
+        }
+    }
+}
+
+/// Pre-v2 layout: a single unnamed profile at the document root, with
+/// `name` instead of `plasmid_name`.
+#[derive(Debug, Deserialize)]
+struct ConfigV1 {
+    mode: Option<String>,
+    name: Option<String>,
+    output: Option<String>,
+    password_env: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VersionProbe {
+    version: String,
+}
+
+impl Config {
+    /// Default config path: `~/.config/bi0cyph3r.toml`. Returns `None` if
+    /// `HOME` isn't set.
+    pub fn default_path() -> Option<PathBuf> {
+        std::env::var_os("HOME").map(|home| Path::new(&home).join(".config").join("bi0cyph3r.toml"))
+    }
+
+    /// Load and validate a config file, migrating it forward to
+    /// [`CURRENT_VERSION`] if it's an older version. A missing file loads as
+    /// an empty default config rather than an error, since running without
+    /// a config file is the common case.
+    pub fn load(path: &Path) -> Result<Config, ConfigError> {
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+
+        let contents = std::fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let probe: VersionProbe = toml::from_str(&contents).map_err(|source| ConfigError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let config = match probe.version.as_str() {
+            "1" => {
+                let legacy: ConfigV1 = toml::from_str(&contents).map_err(|source| ConfigError::Parse {
+                    path: path.to_path_buf(),
+                    source,
+                })?;
+                migrate_v1(legacy)
+            }
+            v if v == CURRENT_VERSION => toml::from_str(&contents).map_err(|source| ConfigError::Parse {
+                path: path.to_path_buf(),
+                source,
+            })?,
+            other => {
+                return Err(ConfigError::UnsupportedVersion {
+                    path: path.to_path_buf(),
+                    version: other.to_string(),
+                })
+            }
+        };
+
+        for (name, profile) in &config.profiles {
+            profile.validate(name)?;
+        }
+
+        Ok(config)
+    }
+
+    /// Look up a profile by name.
+    pub fn profile(&self, name: &str) -> Result<&Profile, ConfigError> {
+        self.profiles
+            .get(name)
+            .ok_or_else(|| ConfigError::ProfileNotFound(name.to_string()))
+    }
+}
+
+/// Upgrade a v1 (single, unnamed profile) config into v2 by moving its
+/// fields into a profile named `default`.
+fn migrate_v1(legacy: ConfigV1) -> Config {
+    let mut profiles = HashMap::new();
+    profiles.insert(
+        "default".to_string(),
+        Profile {
+            mode: legacy.mode,
+            plasmid_name: legacy.name,
+            output: legacy.output,
+            password_env: legacy.password_env,
+        },
+    );
+    Config {
+        version: CURRENT_VERSION.to_string(),
+        profiles,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_missing_file_is_empty_default() {
+        let config = Config::load(Path::new("/nonexistent/bi0cyph3r.toml")).unwrap();
+        assert_eq!(config.version, CURRENT_VERSION);
+        assert!(config.profiles.is_empty());
+    }
+
+    #[test]
+    fn test_load_v2_config() {
+        let dir = std::env::temp_dir().join(format!("bi0cyph3r-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        std::fs::write(
+            &path,
+            r#"
+version = "2"
+
+[profiles.default]
+mode = "secure"
+plasmid_name = "my_plasmid"
+output = "fasta"
+password_env = "BI0CYPHER_PASSWORD"
+"#,
+        )
+        .unwrap();
+
+        let config = Config::load(&path).unwrap();
+        let profile = config.profile("default").unwrap();
+        assert_eq!(profile.mode.as_deref(), Some("secure"));
+        assert_eq!(profile.plasmid_name.as_deref(), Some("my_plasmid"));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_migrate_v1_wraps_legacy_fields_into_default_profile() {
+        let dir = std::env::temp_dir().join(format!("bi0cyph3r-test-v1-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        std::fs::write(
+            &path,
+            r#"
+version = "1"
+mode = "nanopore"
+name = "legacy_plasmid"
+output = "txt"
+"#,
+        )
+        .unwrap();
+
+        let config = Config::load(&path).unwrap();
+        assert_eq!(config.version, CURRENT_VERSION);
+        let profile = config.profile("default").unwrap();
+        assert_eq!(profile.mode.as_deref(), Some("nanopore"));
+        assert_eq!(profile.plasmid_name.as_deref(), Some("legacy_plasmid"));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_profile_missing_key_material_is_rejected() {
+        let dir = std::env::temp_dir().join(format!("bi0cyph3r-test-keymat-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        std::fs::write(
+            &path,
+            r#"
+version = "2"
+
+[profiles.default]
+mode = "secure"
+"#,
+        )
+        .unwrap();
+
+        let result = Config::load(&path);
+        assert!(matches!(result, Err(ConfigError::MissingKeyMaterial { .. })));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_profile_not_found() {
+        let config = Config::default();
+        assert!(matches!(
+            config.profile("missing"),
+            Err(ConfigError::ProfileNotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,775 @@
+//! Bi0cyph3r CLI — Encode and decode messages as DNA from the command line
+//!
+//! Usage:
+//!   bi0cyph3r encode "Hello" [--mode basic|nanopore|secure] [--password PASS] [--profile NAME]
+//!   bi0cyph3r decode "ATCG..." [--mode basic|nanopore|secure] [--password PASS] [--profile NAME]
+//!   bi0cyph3r safety "ATCG..."
+//!   bi0cyph3r plasmid "Hello" [--mode basic|nanopore|secure] [--password PASS] [--name NAME] [--output fasta|txt|json|dot] [--profile NAME]
+//!   bi0cyph3r sign "ATCG..." --alg ed25519|p256 --key <private-key-file>
+//!   bi0cyph3r verify "ATCG..." <token> --key <public-key-file>
+//!
+//! `--profile NAME` loads defaults for `--mode`, `--name`, `--output` and the
+//! password's environment variable from `~/.config/bi0cyph3r.toml` (see
+//! `config` module); any flag given explicitly on the command line still
+//! takes precedence over the profile's default.
+//!
+//! `sign`/`verify` produce and check a detached signature token over a
+//! sequence's digest (see `biocypher_backend::sequence_token`) — useful for
+//! confirming a sequence wasn't altered in transit between `encode` and
+//! `decode`, independent of whatever transport carried it.
+//!
+//! `--mode splitkey` normally prints K1/K2 as raw base64 to stderr. Passing
+//! `--keyout FILE` alongside `--password` on `encode`/`plasmid` instead
+//! bundles both keys into a single password-protected container (see
+//! `biocypher_backend::dna::keystore`) written to `FILE`; `decode --keyin
+//! FILE --password PASS` unlocks it in place of `--k1`/`--k2`.
+//!
+//! `encode --prefix MOTIF [--max-tries N]` re-encodes the message (nanopore,
+//! secure, splitkey or openpgp mode only — each randomizes its nonce/salt on
+//! every call) up to `N` times (default 10000) until the resulting sequence
+//! starts with `MOTIF`, e.g. a restriction site or cloning overhang needed
+//! for downstream assembly.
+
+mod config;
+mod plasmid_map;
+
+use std::process::ExitCode;
+
+use biocypher_backend::dna::{
+    basic::DNACrypto,
+    keystore::KeyStore,
+    markers,
+    nanopore::NanoporeDNACrypto,
+    openpgp::OpenPgpDNACrypto,
+    secure::SecureDNACrypto,
+    split_key::SplitKeyDNACrypto,
+    traits::{DNACoder, SequenceStats},
+    EncodingMode,
+};
+use biocypher_backend::safety::DNASafetyScreener;
+use biocypher_backend::sequence_token::{self, KeyAlgorithm};
+use config::Config;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 2 {
+        print_usage(&args[0]);
+        return ExitCode::FAILURE;
+    }
+
+    let subcmd = args[1].to_lowercase();
+    match subcmd.as_str() {
+        "encode" => run_encode(&args[2..]),
+        "decode" => run_decode(&args[2..]),
+        "safety" | "screen" => run_safety(&args[2..]),
+        "plasmid" => run_plasmid(&args[2..]),
+        "sign" => run_sign(&args[2..]),
+        "verify" => run_verify(&args[2..]),
+        "help" | "-h" | "--help" => {
+            print_usage(&args[0]);
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("Unknown command: {}", subcmd);
+            print_usage(&args[0]);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Flags parsed from the command line before any profile defaults are
+/// merged in; `mode` stays `None` when `--mode` wasn't given, so a profile
+/// default can still apply (see [`resolve_mode_and_password`]).
+struct ModeArgs {
+    mode: Option<EncodingMode>,
+    password: Option<String>,
+    k1: Option<String>,
+    k2: Option<String>,
+    profile: Option<String>,
+    keyout: Option<String>,
+    keyin: Option<String>,
+    prefix: Option<String>,
+    max_tries: Option<u32>,
+}
+
+fn parse_mode(args: &[String]) -> ModeArgs {
+    let mut mode = None;
+    let mut password = None;
+    let mut k1 = None;
+    let mut k2 = None;
+    let mut profile = None;
+    let mut keyout = None;
+    let mut keyin = None;
+    let mut prefix = None;
+    let mut max_tries = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        if args[i] == "--mode" && i + 1 < args.len() {
+            mode = match args[i + 1].to_lowercase().as_str() {
+                "nanopore" => Some(EncodingMode::Nanopore),
+                "secure" => Some(EncodingMode::Secure),
+                "splitkey" => Some(EncodingMode::SplitKey),
+                "openpgp" => Some(EncodingMode::OpenPgp),
+                _ => Some(EncodingMode::Basic),
+            };
+            i += 2;
+        } else if (args[i] == "--password" || args[i] == "-p") && i + 1 < args.len() {
+            password = Some(args[i + 1].clone());
+            i += 2;
+        } else if args[i] == "--k1" && i + 1 < args.len() {
+            k1 = Some(args[i + 1].clone());
+            i += 2;
+        } else if args[i] == "--k2" && i + 1 < args.len() {
+            k2 = Some(args[i + 1].clone());
+            i += 2;
+        } else if args[i] == "--profile" && i + 1 < args.len() {
+            profile = Some(args[i + 1].clone());
+            i += 2;
+        } else if args[i] == "--keyout" && i + 1 < args.len() {
+            keyout = Some(args[i + 1].clone());
+            i += 2;
+        } else if args[i] == "--keyin" && i + 1 < args.len() {
+            keyin = Some(args[i + 1].clone());
+            i += 2;
+        } else if args[i] == "--prefix" && i + 1 < args.len() {
+            prefix = Some(args[i + 1].clone());
+            i += 2;
+        } else if args[i] == "--max-tries" && i + 1 < args.len() {
+            max_tries = args[i + 1].parse().ok();
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    ModeArgs { mode, password, k1, k2, profile, keyout, keyin, prefix, max_tries }
+}
+
+/// Load the named profile (if any) from the default config path and merge
+/// it under `cli`: an explicit CLI flag always wins over the profile's
+/// default, and an unset password falls back to the profile's
+/// `password_env` variable.
+fn resolve_mode_and_password(
+    cli: &ModeArgs,
+) -> Result<(EncodingMode, Option<String>, Option<String>, Option<String>), String> {
+    let profile = match &cli.profile {
+        Some(name) => {
+            let path = Config::default_path().ok_or("Cannot locate config file: $HOME is not set")?;
+            let config = Config::load(&path).map_err(|e| e.to_string())?;
+            Some(config.profile(name).map_err(|e| e.to_string())?.clone())
+        }
+        None => None,
+    };
+
+    let mode = cli
+        .mode
+        .or_else(|| profile.as_ref().and_then(|p| p.mode.as_deref()).and_then(|m| m.parse().ok()))
+        .unwrap_or(EncodingMode::Basic);
+
+    let password = cli.password.clone().or_else(|| {
+        profile
+            .as_ref()
+            .and_then(|p| p.password_env.as_ref())
+            .and_then(|var| std::env::var(var).ok())
+    });
+
+    Ok((mode, password, cli.k1.clone(), cli.k2.clone()))
+}
+
+/// Bundle a split-key mode's K1/K2 and sequence metadata into a single
+/// password-protected container (see [`biocypher_backend::dna::keystore`])
+/// so they can be handed off as one portable artifact instead of two loose
+/// base64 strings.
+fn write_split_key_container(
+    path: &str,
+    k1: &str,
+    k2: &str,
+    sequence_length: usize,
+    passphrase: &str,
+) -> Result<(), String> {
+    let bundle = serde_json::json!({
+        "mode": "splitkey",
+        "k1": k1,
+        "k2": k2,
+        "sequence_length": sequence_length,
+    });
+    let container = KeyStore::export_key(bundle.to_string().as_bytes(), passphrase).map_err(|e| e.to_string())?;
+    std::fs::write(path, container).map_err(|e| format!("failed to write key container {}: {}", path, e))
+}
+
+/// Unlock a container written by [`write_split_key_container`] and pull out
+/// its K1/K2 pair.
+fn read_split_key_container(path: &str, passphrase: &str) -> Result<(String, String), String> {
+    let container =
+        std::fs::read_to_string(path).map_err(|e| format!("failed to read key container {}: {}", path, e))?;
+    let material = KeyStore::import_key(container.trim(), passphrase).map_err(|e| e.to_string())?;
+    let bundle: serde_json::Value =
+        serde_json::from_slice(&material).map_err(|e| format!("invalid key container contents: {}", e))?;
+    let k1 = bundle["k1"].as_str().ok_or("key container missing k1")?.to_string();
+    let k2 = bundle["k2"].as_str().ok_or("key container missing k2")?.to_string();
+    Ok((k1, k2))
+}
+
+fn run_encode(args: &[String]) -> ExitCode {
+    if args.is_empty() {
+        eprintln!("Error: encode requires a message");
+        eprintln!("  {} encode \"Your message\"", std::env::args().next().unwrap_or_default());
+        return ExitCode::FAILURE;
+    }
+
+    let message = &args[0];
+    let cli = parse_mode(args);
+    let keyout = cli.keyout.clone();
+    let (mode, password, _k1, _k2) = match resolve_mode_and_password(&cli) {
+        Ok(resolved) => resolved,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if matches!(mode, EncodingMode::Secure) && password.is_none() {
+        eprintln!("Error: secure mode requires --password");
+        return ExitCode::FAILURE;
+    }
+
+    if keyout.is_some() && password.is_none() {
+        eprintln!("Error: --keyout requires --password to protect the key container");
+        return ExitCode::FAILURE;
+    }
+
+    if cli.prefix.is_some() && matches!(mode, EncodingMode::Basic) {
+        eprintln!("Error: --prefix needs a mode with per-encode randomness (nanopore, secure, splitkey, openpgp)");
+        return ExitCode::FAILURE;
+    }
+
+    let encode_once = || -> biocypher_backend::error::Result<(String, Option<String>, Option<String>)> {
+        match mode {
+            EncodingMode::Basic => DNACrypto::encode_message(message).map(|d| (d, None, None)),
+            EncodingMode::Nanopore => NanoporeDNACrypto::encode_message(message).map(|d| (d, None, None)),
+            EncodingMode::Secure => {
+                let pwd = password.as_ref().unwrap();
+                SecureDNACrypto::encode_with_password(message, pwd).map(|d| (d, None, None))
+            }
+            EncodingMode::SplitKey => {
+                SplitKeyDNACrypto::encode_with_split_keys(message).map(|(d, k1, k2)| (d, Some(k1), Some(k2)))
+            }
+            EncodingMode::OpenPgp => OpenPgpDNACrypto::encode_message(message).map(|d| (d, None, None)),
+        }
+    };
+
+    let result = match &cli.prefix {
+        None => encode_once(),
+        Some(prefix) => {
+            let motif = prefix.to_uppercase();
+            let max_tries = cli.max_tries.unwrap_or(10_000);
+            let mut attempts = 0u32;
+            loop {
+                attempts += 1;
+                let attempt = encode_once();
+                match &attempt {
+                    Ok((dna, _, _)) => {
+                        let core = dna.strip_prefix(markers::START_MARKER).unwrap_or(dna);
+                        if core.starts_with(&motif) {
+                            eprintln!("  Vanity prefix '{}' found after {} attempt(s)", prefix, attempts);
+                            break attempt;
+                        }
+                    }
+                    Err(_) => break attempt,
+                }
+                if attempts >= max_tries {
+                    eprintln!("Error: no sequence starting with '{}' found in {} attempts", prefix, max_tries);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+
+    match result {
+        Ok((dna, k1_opt, k2_opt)) => {
+            println!("{}", dna);
+            let stats = match mode {
+                EncodingMode::Basic => DNACrypto::get_sequence_stats(&dna),
+                EncodingMode::Nanopore => NanoporeDNACrypto::get_sequence_stats(&dna),
+                EncodingMode::Secure => SecureDNACrypto::get_sequence_stats(&dna),
+                EncodingMode::SplitKey => SplitKeyDNACrypto::get_sequence_stats(&dna),
+                EncodingMode::OpenPgp => OpenPgpDNACrypto::get_sequence_stats(&dna),
+            };
+            eprintln!(
+                "  [{} bases, GC: {:.1}%]",
+                stats.length, stats.gc_content
+            );
+            if let (Some(k1), Some(k2)) = (k1_opt, k2_opt) {
+                match keyout {
+                    Some(path) => {
+                        if let Err(e) =
+                            write_split_key_container(&path, &k1, &k2, dna.len(), password.as_ref().unwrap())
+                        {
+                            eprintln!("Error: {}", e);
+                            return ExitCode::FAILURE;
+                        }
+                        eprintln!("  Key container written to {}", path);
+                    }
+                    None => {
+                        eprintln!("  K1 (save securely): {}", k1);
+                        eprintln!("  K2 (escrow): {}", k2);
+                    }
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_decode(args: &[String]) -> ExitCode {
+    if args.is_empty() {
+        eprintln!("Error: decode requires a DNA sequence");
+        eprintln!("  {} decode \"ATCGATCG...\"", std::env::args().next().unwrap_or_default());
+        return ExitCode::FAILURE;
+    }
+
+    let sequence = &args[0];
+    let cli = parse_mode(args);
+    let keyin = cli.keyin.clone();
+    let (mode, password, mut k1, mut k2) = match resolve_mode_and_password(&cli) {
+        Ok(resolved) => resolved,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if matches!(mode, EncodingMode::Secure) && password.is_none() {
+        eprintln!("Error: secure mode requires --password");
+        return ExitCode::FAILURE;
+    }
+
+    if matches!(mode, EncodingMode::SplitKey) {
+        if let Some(path) = &keyin {
+            let Some(passphrase) = password.as_ref() else {
+                eprintln!("Error: --keyin requires --password to unlock the key container");
+                return ExitCode::FAILURE;
+            };
+            match read_split_key_container(path, passphrase) {
+                Ok((unlocked_k1, unlocked_k2)) => {
+                    k1 = Some(unlocked_k1);
+                    k2 = Some(unlocked_k2);
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        if k1.is_none() || k2.is_none() {
+            eprintln!("Error: splitkey mode requires --k1 and --k2, or --keyin FILE");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let result = match mode {
+        EncodingMode::Basic => DNACrypto::decode_sequence(sequence),
+        EncodingMode::Nanopore => NanoporeDNACrypto::decode_sequence(sequence),
+        EncodingMode::Secure => {
+            let pwd = password.as_ref().unwrap();
+            SecureDNACrypto::decode_with_password(sequence, pwd)
+        }
+        EncodingMode::SplitKey => {
+            let k1 = k1.as_ref().unwrap();
+            let k2 = k2.as_ref().unwrap();
+            SplitKeyDNACrypto::decode_with_split_keys(sequence, k1, k2)
+        }
+        EncodingMode::OpenPgp => OpenPgpDNACrypto::decode_sequence(sequence),
+    };
+
+    match result {
+        Ok(msg) => {
+            println!("{}", msg);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PlasmidOutput {
+    Fasta,
+    Txt,
+    Json,
+    Dot,
+}
+
+/// Flags parsed from the command line before profile defaults are merged
+/// in; see [`ModeArgs`] for why these stay `Option`.
+struct PlasmidArgs {
+    message: String,
+    mode: Option<EncodingMode>,
+    password: Option<String>,
+    name: Option<String>,
+    output: Option<PlasmidOutput>,
+    profile: Option<String>,
+    keyout: Option<String>,
+}
+
+fn parse_plasmid_args(args: &[String]) -> Option<PlasmidArgs> {
+    let mut message = None;
+    let mut mode = None;
+    let mut password = None;
+    let mut name = None;
+    let mut output = None;
+    let mut profile = None;
+    let mut keyout = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        if args[i] == "--mode" && i + 1 < args.len() {
+            mode = match args[i + 1].to_lowercase().as_str() {
+                "nanopore" => Some(EncodingMode::Nanopore),
+                "secure" => Some(EncodingMode::Secure),
+                "splitkey" => Some(EncodingMode::SplitKey),
+                "openpgp" => Some(EncodingMode::OpenPgp),
+                _ => Some(EncodingMode::Basic),
+            };
+            i += 2;
+        } else if (args[i] == "--password" || args[i] == "-p") && i + 1 < args.len() {
+            password = Some(args[i + 1].clone());
+            i += 2;
+        } else if args[i] == "--name" && i + 1 < args.len() {
+            name = Some(args[i + 1].clone());
+            i += 2;
+        } else if args[i] == "--output" && i + 1 < args.len() {
+            output = match args[i + 1].to_lowercase().as_str() {
+                "txt" | "text" => Some(PlasmidOutput::Txt),
+                "json" => Some(PlasmidOutput::Json),
+                "dot" | "gv" | "graphviz" => Some(PlasmidOutput::Dot),
+                _ => Some(PlasmidOutput::Fasta),
+            };
+            i += 2;
+        } else if args[i] == "--profile" && i + 1 < args.len() {
+            profile = Some(args[i + 1].clone());
+            i += 2;
+        } else if args[i] == "--keyout" && i + 1 < args.len() {
+            keyout = Some(args[i + 1].clone());
+            i += 2;
+        } else if !args[i].starts_with('-') {
+            message = Some(args[i].clone());
+            i += 1;
+        } else {
+            i += 1;
+        }
+    }
+    message.map(|message| PlasmidArgs { message, mode, password, name, output, profile, keyout })
+}
+
+/// Merge a profile's defaults (if `--profile` was given) under the flags
+/// parsed from the command line, same precedence as
+/// [`resolve_mode_and_password`].
+fn resolve_plasmid_args(
+    cli: &PlasmidArgs,
+) -> Result<(EncodingMode, Option<String>, String, PlasmidOutput), String> {
+    let profile = match &cli.profile {
+        Some(name) => {
+            let path = Config::default_path().ok_or("Cannot locate config file: $HOME is not set")?;
+            let config = Config::load(&path).map_err(|e| e.to_string())?;
+            Some(config.profile(name).map_err(|e| e.to_string())?.clone())
+        }
+        None => None,
+    };
+
+    let mode = cli
+        .mode
+        .or_else(|| profile.as_ref().and_then(|p| p.mode.as_deref()).and_then(|m| m.parse().ok()))
+        .unwrap_or(EncodingMode::Basic);
+
+    let password = cli.password.clone().or_else(|| {
+        profile
+            .as_ref()
+            .and_then(|p| p.password_env.as_ref())
+            .and_then(|var| std::env::var(var).ok())
+    });
+
+    let name = cli
+        .name
+        .clone()
+        .or_else(|| profile.as_ref().and_then(|p| p.plasmid_name.clone()))
+        .unwrap_or_else(|| "biocypher_plasmid".to_string());
+
+    let output = cli.output.or_else(|| {
+        profile.as_ref().and_then(|p| p.output.as_deref()).map(|o| match o.to_lowercase().as_str() {
+            "txt" | "text" => PlasmidOutput::Txt,
+            "json" => PlasmidOutput::Json,
+            "dot" | "gv" | "graphviz" => PlasmidOutput::Dot,
+            _ => PlasmidOutput::Fasta,
+        })
+    }).unwrap_or(PlasmidOutput::Fasta);
+
+    Ok((mode, password, name, output))
+}
+
+fn wrap_fasta(seq: &str, line_len: usize) -> String {
+    let mut out = String::with_capacity(seq.len() + seq.len() / line_len + 2);
+    for chunk in seq.as_bytes().chunks(line_len) {
+        out.push_str(std::str::from_utf8(chunk).unwrap_or(""));
+        out.push('\n');
+    }
+    out.trim_end().to_string()
+}
+
+fn run_plasmid(args: &[String]) -> ExitCode {
+    let Some(parsed) = parse_plasmid_args(args) else {
+        eprintln!("Error: plasmid requires a message");
+        eprintln!("  {} plasmid \"Your message\" [--name NAME] [--output fasta|txt|json|dot]", std::env::args().next().unwrap_or_default());
+        return ExitCode::FAILURE;
+    };
+    let message = parsed.message.clone();
+
+    let (mode, password, name, output) = match resolve_plasmid_args(&parsed) {
+        Ok(resolved) => resolved,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if matches!(mode, EncodingMode::Secure) && password.is_none() {
+        eprintln!("Error: secure mode requires --password");
+        return ExitCode::FAILURE;
+    }
+
+    if parsed.keyout.is_some() && password.is_none() {
+        eprintln!("Error: --keyout requires --password to protect the key container");
+        return ExitCode::FAILURE;
+    }
+
+    let result = match mode {
+        EncodingMode::Basic => DNACrypto::encode_message(&message).map(|s| (s, None, None)),
+        EncodingMode::Nanopore => NanoporeDNACrypto::encode_message(&message).map(|s| (s, None, None)),
+        EncodingMode::Secure => {
+            let pwd = password.as_ref().unwrap();
+            SecureDNACrypto::encode_with_password(&message, pwd).map(|s| (s, None, None))
+        }
+        EncodingMode::SplitKey => {
+            SplitKeyDNACrypto::encode_with_split_keys(&message).map(|(s, k1, k2)| (s, Some(k1), Some(k2)))
+        }
+        EncodingMode::OpenPgp => OpenPgpDNACrypto::encode_message(&message).map(|s| (s, None, None)),
+    };
+
+    match result {
+        Ok((sequence, k1_opt, k2_opt)) => {
+            let stats = match mode {
+                EncodingMode::Basic => DNACrypto::get_sequence_stats(&sequence),
+                EncodingMode::Nanopore => NanoporeDNACrypto::get_sequence_stats(&sequence),
+                EncodingMode::Secure => SecureDNACrypto::get_sequence_stats(&sequence),
+                EncodingMode::SplitKey => SplitKeyDNACrypto::get_sequence_stats(&sequence),
+                EncodingMode::OpenPgp => OpenPgpDNACrypto::get_sequence_stats(&sequence),
+            };
+            match output {
+                PlasmidOutput::Fasta => {
+                    println!(">{}\n{}", name, wrap_fasta(&sequence, 80));
+                }
+                PlasmidOutput::Txt => {
+                    println!("{}", sequence);
+                }
+                PlasmidOutput::Dot => {
+                    println!("{}", plasmid_map::render_dot(&name, &sequence, mode));
+                }
+                PlasmidOutput::Json => {
+                    let mut instructions = serde_json::json!({
+                        "name": name,
+                        "sequence": sequence,
+                        "mode": format!("{}", mode),
+                        "message_length": message.len(),
+                        "gc_content": stats.gc_content,
+                    });
+                    if let (Some(ref k1), Some(ref k2)) = (&k1_opt, &k2_opt) {
+                        if parsed.keyout.is_none() {
+                            instructions["k1_base64"] = serde_json::json!(k1);
+                            instructions["k2_base64"] = serde_json::json!(k2);
+                        }
+                    }
+                    println!("{}", serde_json::to_string_pretty(&instructions).unwrap_or_default());
+                }
+            }
+            if let (Some(k1), Some(k2)) = (k1_opt, k2_opt) {
+                match &parsed.keyout {
+                    Some(path) => {
+                        if let Err(e) =
+                            write_split_key_container(path, &k1, &k2, sequence.len(), password.as_ref().unwrap())
+                        {
+                            eprintln!("Error: {}", e);
+                            return ExitCode::FAILURE;
+                        }
+                        eprintln!("  Key container written to {}", path);
+                    }
+                    None => {
+                        eprintln!("  K1 (save securely): {}", k1);
+                        eprintln!("  K2 (escrow): {}", k2);
+                    }
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_safety(args: &[String]) -> ExitCode {
+    if args.is_empty() {
+        eprintln!("Error: safety requires a DNA sequence");
+        eprintln!("  {} safety \"ATCGATCG...\"", std::env::args().next().unwrap_or_default());
+        return ExitCode::FAILURE;
+    }
+
+    let sequence = &args[0];
+    let screener = DNASafetyScreener::new();
+    match screener.perform_comprehensive_screening(sequence) {
+        Ok(report) => {
+            println!("Status: {:?}", report.safety_status);
+            if !report.pathogen_analysis.matches.is_empty() {
+                println!("Pathogen matches: {:?}", report.pathogen_analysis.matches);
+            }
+            if !report.natural_occurrence.matches.is_empty() {
+                println!("Natural occurrence: {:?}", report.natural_occurrence.matches);
+            }
+            println!("GC content: {:.1}%", report.sequence_characteristics.gc_content);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parse `--alg` and `--key PATH` out of `args`, returning them alongside
+/// whatever positional arguments remain.
+fn parse_key_args(args: &[String]) -> (Vec<String>, Option<String>, Option<String>) {
+    let mut positional = Vec::new();
+    let mut alg = None;
+    let mut key_path = None;
+
+    let mut i = 0;
+    while i < args.len() {
+        if args[i] == "--alg" && i + 1 < args.len() {
+            alg = Some(args[i + 1].to_lowercase());
+            i += 2;
+        } else if args[i] == "--key" && i + 1 < args.len() {
+            key_path = Some(args[i + 1].clone());
+            i += 2;
+        } else {
+            positional.push(args[i].clone());
+            i += 1;
+        }
+    }
+    (positional, alg, key_path)
+}
+
+fn run_sign(args: &[String]) -> ExitCode {
+    let (positional, alg, key_path) = parse_key_args(args);
+    let (Some(sequence), Some(alg), Some(key_path)) = (positional.first(), alg, key_path) else {
+        eprintln!("Error: sign requires a sequence, --alg ed25519|p256 and --key PATH");
+        eprintln!(
+            "  {} sign \"ATCGATCG...\" --alg ed25519 --key private.key",
+            std::env::args().next().unwrap_or_default()
+        );
+        return ExitCode::FAILURE;
+    };
+
+    let algorithm = match alg.as_str() {
+        "ed25519" => KeyAlgorithm::Ed25519,
+        "p256" | "ecdsa-p256" | "es256" => KeyAlgorithm::EcdsaP256,
+        other => {
+            eprintln!("Error: unknown algorithm '{}' (expected ed25519 or p256)", other);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let private_key = match std::fs::read(&key_path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            eprintln!("Error: failed to read private key file {}: {}", key_path, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match sequence_token::sign(sequence, algorithm, &private_key) {
+        Ok(token) => {
+            println!("{}", token);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_verify(args: &[String]) -> ExitCode {
+    let (positional, _alg, key_path) = parse_key_args(args);
+    let (Some(sequence), Some(token), Some(key_path)) = (positional.first(), positional.get(1), key_path) else {
+        eprintln!("Error: verify requires a sequence, a token and --key PATH");
+        eprintln!(
+            "  {} verify \"ATCGATCG...\" <token> --key public.key",
+            std::env::args().next().unwrap_or_default()
+        );
+        return ExitCode::FAILURE;
+    };
+
+    let public_key = match std::fs::read(&key_path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            eprintln!("Error: failed to read public key file {}: {}", key_path, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match sequence_token::verify(sequence, token, &public_key) {
+        Ok(()) => {
+            println!("OK");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn print_usage(prog: &str) {
+    let name = std::path::Path::new(prog).file_stem().and_then(|s| s.to_str()).unwrap_or("bi0cyph3r");
+    eprintln!("Bi0cyph3r — DNA cryptography CLI");
+    eprintln!();
+    eprintln!("Usage:");
+    eprintln!("  {} encode <message> [--mode basic|nanopore|secure|splitkey|openpgp] [--password PASS] [--profile NAME] [--keyout FILE] [--prefix MOTIF] [--max-tries N]", name);
+    eprintln!("  {} decode <sequence> [--mode basic|nanopore|secure|splitkey|openpgp] [--password PASS] [--k1 K1] [--k2 K2] [--keyin FILE] [--profile NAME]", name);
+    eprintln!("  {} safety <sequence>", name);
+    eprintln!("  {} plasmid <message> [--mode basic|nanopore|secure|splitkey|openpgp] [--password PASS] [--name NAME] [--output fasta|txt|json|dot] [--profile NAME] [--keyout FILE]", name);
+    eprintln!("  {} sign <sequence> --alg ed25519|p256 --key <private-key-file>", name);
+    eprintln!("  {} verify <sequence> <token> --key <public-key-file>", name);
+    eprintln!();
+    eprintln!("--profile NAME loads defaults from ~/.config/bi0cyph3r.toml; explicit flags still win.");
+    eprintln!();
+    eprintln!("Examples:");
+    eprintln!("  {} encode \"Hello World\"", name);
+    eprintln!("  {} encode \"Secret\" --mode secure --password mypass", name);
+    eprintln!("  {} encode \"Secret\" --mode splitkey", name);
+    eprintln!("  {} decode \"TACATCTTTCG...\"", name);
+    eprintln!("  {} decode \"ATCG...\" --mode splitkey --k1 <base64> --k2 <base64>", name);
+    eprintln!("  {} encode \"Secret\" --mode splitkey --password escrow-pass --keyout keys.container", name);
+    eprintln!("  {} decode \"ATCG...\" --mode splitkey --password escrow-pass --keyin keys.container", name);
+    eprintln!("  {} encode \"Secret\" --mode splitkey --prefix GAATTC --max-tries 50000", name);
+    eprintln!("  {} safety \"ATCGATCGATCG\"", name);
+    eprintln!("  {} plasmid \"Hi\" --output fasta", name);
+    eprintln!("  {} sign \"ATCGATCG\" --alg ed25519 --key private.key", name);
+    eprintln!("  {} verify \"ATCGATCG\" <token> --key public.key", name);
+}
@@ -0,0 +1,164 @@
+//! Render the constructed plasmid as an annotated circular map in GraphViz
+//! DOT format, so `bi0cyph3r plasmid --output dot` can be piped straight
+//! into `dot`/`neato`/`circo` to visualize where the encoded payload sits
+//! relative to standard plasmid features — neither the FASTA nor JSON
+//! output conveys this.
+
+use biocypher_backend::dna::{markers, EncodingMode};
+
+/// A single labeled feature on the plasmid map, spanning `[start, end)`
+/// bases on the circular backbone.
+struct Feature {
+    label: String,
+    start: usize,
+    end: usize,
+}
+
+/// Restriction enzyme recognition sites scanned for in the final sequence —
+/// the small set of enzymes a cloning workflow checks first.
+const RESTRICTION_SITES: &[(&str, &str)] = &[
+    ("EcoRI", "GAATTC"),
+    ("BamHI", "GGATCC"),
+    ("HindIII", "AAGCTT"),
+    ("NotI", "GCGGCCGC"),
+    ("XhoI", "CTCGAG"),
+];
+
+/// Find every non-overlapping-start occurrence of each known restriction
+/// site in `sequence`.
+fn find_restriction_sites(sequence: &str) -> Vec<Feature> {
+    let upper = sequence.to_uppercase();
+    let mut sites = Vec::new();
+
+    for (name, motif) in RESTRICTION_SITES {
+        let mut offset = 0;
+        while let Some(pos) = upper[offset..].find(motif) {
+            let start = offset + pos;
+            sites.push(Feature {
+                label: name.to_string(),
+                start,
+                end: start + motif.len(),
+            });
+            offset = start + 1;
+        }
+    }
+
+    sites
+}
+
+/// Build the ordered list of features making up the plasmid map: a fixed
+/// origin-of-replication marker at position 0 (this plasmid has no real
+/// bacterial backbone), mode-dependent flanking primers (the nanopore
+/// start/stop markers, for modes that wrap their payload in them), the
+/// encoded-message insert between the primers, and any restriction sites
+/// detected in the final sequence.
+fn build_features(sequence: &str, mode: EncodingMode) -> Vec<Feature> {
+    let mut features = vec![Feature {
+        label: "ori".to_string(),
+        start: 0,
+        end: 0,
+    }];
+
+    let mut insert_start = 0;
+    let mut insert_end = sequence.len();
+
+    if sequence.starts_with(markers::START_MARKER) && sequence.ends_with(markers::STOP_MARKER) {
+        let start_len = markers::START_MARKER.len();
+        let stop_len = markers::STOP_MARKER.len();
+        insert_start = start_len;
+        insert_end = sequence.len().saturating_sub(stop_len).max(insert_start);
+
+        features.push(Feature {
+            label: format!("{} 5' primer", mode),
+            start: 0,
+            end: start_len,
+        });
+        features.push(Feature {
+            label: format!("{} 3' primer", mode),
+            start: insert_end,
+            end: sequence.len(),
+        });
+    }
+
+    features.push(Feature {
+        label: "encoded message".to_string(),
+        start: insert_start,
+        end: insert_end,
+    });
+
+    features.extend(find_restriction_sites(sequence));
+    features.sort_by_key(|f| f.start);
+    features
+}
+
+/// Escape a string for safe embedding in a DOT quoted identifier or label.
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Render `sequence` (already encoded in `mode`) as a GraphViz DOT digraph:
+/// one node per feature, laid out as a ring in backbone order, with each
+/// edge labeled by the base offset at which the feature it points to
+/// begins.
+pub fn render_dot(name: &str, sequence: &str, mode: EncodingMode) -> String {
+    let features = build_features(sequence, mode);
+
+    let mut dot = String::new();
+    dot.push_str(&format!("digraph \"{}\" {{\n", escape(name)));
+    dot.push_str("  layout=circo;\n");
+    dot.push_str(&format!(
+        "  label=\"{} ({} bp, {} mode)\";\n",
+        escape(name),
+        sequence.len(),
+        mode
+    ));
+
+    for (i, feature) in features.iter().enumerate() {
+        dot.push_str(&format!(
+            "  f{} [label=\"{}\\n{}-{}\"];\n",
+            i,
+            escape(&feature.label),
+            feature.start,
+            feature.end
+        ));
+    }
+
+    for i in 0..features.len() {
+        let next = (i + 1) % features.len();
+        dot.push_str(&format!(
+            "  f{} -> f{} [label=\"{}\"];\n",
+            i, next, features[next].start
+        ));
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_dot_wraps_ring_back_to_first_node() {
+        let dot = render_dot("test", "ATCGATCGATCGATCG", EncodingMode::Basic);
+        assert!(dot.starts_with("digraph \"test\" {"));
+        assert!(dot.contains("f0 [label=\"ori"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_render_dot_includes_flanking_primers_for_markered_modes() {
+        let sequence = format!("{}ATCGATCG{}", markers::START_MARKER, markers::STOP_MARKER);
+        let dot = render_dot("test", &sequence, EncodingMode::Nanopore);
+        assert!(dot.contains("5' primer"));
+        assert!(dot.contains("3' primer"));
+        assert!(dot.contains("encoded message"));
+    }
+
+    #[test]
+    fn test_render_dot_detects_restriction_sites() {
+        let dot = render_dot("test", "AAAGAATTCAAA", EncodingMode::Basic);
+        assert!(dot.contains("EcoRI"));
+    }
+}
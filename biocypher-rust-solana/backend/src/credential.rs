@@ -0,0 +1,238 @@
+//! W3C Verifiable Credential issuance and verification for attestations
+//!
+//! Wraps an attestation the same way `build_attest_transaction` does for a
+//! wallet to sign on-chain, but as a JWT-encoded Verifiable Credential
+//! instead: a compact JWS (`base64url(header).base64url(payload).base64url(signature)`)
+//! signed with the server's ed25519 key. A holder can present this to any
+//! verifier without that party needing Solana RPC access, which is the
+//! point — see [`crate::solana::client::build_attest_transaction`] for the
+//! on-chain counterpart this mirrors.
+
+use base64::{engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD}, Engine as _};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+use crate::dna::EncodingMode;
+use crate::error::{ApiError, BioCypherError, Result};
+use crate::models::{CredentialClaims, CredentialSubject, SafetyStatus, VerifiableCredential};
+
+const JWS_ALG: &str = "EdDSA";
+const JWS_TYP: &str = "JWT";
+const VC_CONTEXT: &str = "https://www.w3.org/2018/credentials/v1";
+const VC_TYPE_BASE: &str = "VerifiableCredential";
+const VC_TYPE_ATTESTATION: &str = "BiocypherAttestation";
+
+/// `did:key:` prefix, same convention as [`crate::auth::capability`]: a
+/// DID is simply this prefix plus the base64-encoded raw ed25519 public key.
+const DID_KEY_PREFIX: &str = "did:key:";
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JwsHeader<'a> {
+    alg: &'a str,
+    typ: &'a str,
+}
+
+/// Build and sign a compact JWS wrapping a Verifiable Credential asserting
+/// `operation`/`seq_hash`/`mode`/`status`. `pubkey` is the signer's raw
+/// ed25519 public key (used to derive the issuer DID); `sign` produces the
+/// ed25519 signature over the bytes it's given, using that same key.
+pub fn issue_credential(
+    pubkey: &[u8; 32],
+    sign: impl FnOnce(&[u8]) -> Result<[u8; 64]>,
+    operation: &str,
+    seq_hash: [u8; 32],
+    mode: Option<EncodingMode>,
+    status: Option<SafetyStatus>,
+    issued_at: i64,
+) -> Result<String> {
+    let issuer_did = encode_did_key(pubkey);
+
+    let claims = CredentialClaims {
+        iss: issuer_did.clone(),
+        sub: issuer_did,
+        nbf: issued_at,
+        vc: VerifiableCredential {
+            context: vec![VC_CONTEXT.to_string()],
+            credential_type: vec![VC_TYPE_BASE.to_string(), VC_TYPE_ATTESTATION.to_string()],
+            credential_subject: CredentialSubject {
+                operation: operation.to_string(),
+                seq_hash: crate::solana::merkle::to_hex(&seq_hash),
+                mode,
+                status,
+                issued_at,
+            },
+        },
+    };
+
+    let signing_input = jws_signing_input(&claims)?;
+    let signature = sign(signing_input.as_bytes())?;
+
+    Ok(format!("{}.{}", signing_input, URL_SAFE_NO_PAD.encode(signature)))
+}
+
+/// Verify a compact JWS produced by [`issue_credential`]: recompute the
+/// signing input, check the signature against the DID embedded in `iss`,
+/// and return the decoded claims.
+pub fn verify_credential(jws: &str) -> Result<CredentialClaims> {
+    let mut parts = jws.split('.');
+    let (Some(header_b64), Some(payload_b64), Some(signature_b64), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(ApiError::Unauthorized.into());
+    };
+
+    let header_json = URL_SAFE_NO_PAD
+        .decode(header_b64)
+        .map_err(|_| ApiError::Unauthorized)?;
+    let header: JwsHeader = serde_json::from_slice(&header_json).map_err(|_| ApiError::Unauthorized)?;
+    if header.alg != JWS_ALG || header.typ != JWS_TYP {
+        return Err(ApiError::Unauthorized.into());
+    }
+
+    let payload_json = URL_SAFE_NO_PAD
+        .decode(payload_b64)
+        .map_err(|_| ApiError::Unauthorized)?;
+    let claims: CredentialClaims = serde_json::from_slice(&payload_json).map_err(|_| ApiError::Unauthorized)?;
+
+    let signature_bytes = URL_SAFE_NO_PAD
+        .decode(signature_b64)
+        .map_err(|_| ApiError::Unauthorized)?;
+    let signature_arr: [u8; 64] = signature_bytes.try_into().map_err(|_| ApiError::Unauthorized)?;
+    let signature = Signature::from_bytes(&signature_arr);
+
+    let verifying_key = parse_did_key(&claims.iss)?;
+    let signing_input = format!("{}.{}", header_b64, payload_b64);
+    verifying_key
+        .verify(signing_input.as_bytes(), &signature)
+        .map_err(|_| ApiError::Unauthorized)?;
+
+    Ok(claims)
+}
+
+/// `base64url(header) . base64url(payload)`, the bytes the signature covers.
+fn jws_signing_input(claims: &CredentialClaims) -> Result<String> {
+    let header = JwsHeader { alg: JWS_ALG, typ: JWS_TYP };
+    let header_json = serde_json::to_vec(&header)
+        .map_err(|e| BioCypherError::Internal(format!("Failed to encode JWS header: {}", e)))?;
+    let payload_json = serde_json::to_vec(claims)
+        .map_err(|e| BioCypherError::Internal(format!("Failed to encode credential claims: {}", e)))?;
+
+    Ok(format!(
+        "{}.{}",
+        URL_SAFE_NO_PAD.encode(header_json),
+        URL_SAFE_NO_PAD.encode(payload_json)
+    ))
+}
+
+fn encode_did_key(pubkey: &[u8; 32]) -> String {
+    format!("{}{}", DID_KEY_PREFIX, STANDARD.encode(pubkey))
+}
+
+fn parse_did_key(did: &str) -> Result<VerifyingKey> {
+    let encoded = did.strip_prefix(DID_KEY_PREFIX).ok_or(ApiError::Unauthorized)?;
+    let bytes = STANDARD.decode(encoded).map_err(|_| ApiError::Unauthorized)?;
+    let arr: [u8; 32] = bytes.try_into().map_err(|_| ApiError::Unauthorized)?;
+    VerifyingKey::from_bytes(&arr).map_err(|_| ApiError::Unauthorized.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn test_signer() -> (SigningKey, [u8; 32]) {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let pubkey = key.verifying_key().to_bytes();
+        (key, pubkey)
+    }
+
+    #[test]
+    fn test_issue_and_verify_roundtrip() {
+        let (key, pubkey) = test_signer();
+        let jws = issue_credential(
+            &pubkey,
+            |msg| Ok(key.sign(msg).to_bytes()),
+            "encode",
+            [0x11u8; 32],
+            Some(EncodingMode::Secure),
+            None,
+            1_700_000_000,
+        )
+        .unwrap();
+
+        let claims = verify_credential(&jws).unwrap();
+        assert_eq!(claims.vc.credential_subject.operation, "encode");
+        assert_eq!(claims.vc.credential_subject.seq_hash, crate::solana::merkle::to_hex(&[0x11u8; 32]));
+        assert_eq!(claims.nbf, 1_700_000_000);
+        assert_eq!(claims.iss, encode_did_key(&pubkey));
+        assert_eq!(
+            claims.vc.credential_type,
+            vec!["VerifiableCredential".to_string(), "BiocypherAttestation".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_safety_operation_carries_status_not_mode() {
+        let (key, pubkey) = test_signer();
+        let jws = issue_credential(
+            &pubkey,
+            |msg| Ok(key.sign(msg).to_bytes()),
+            "safety",
+            [0x22u8; 32],
+            None,
+            Some(SafetyStatus::Caution),
+            1_700_000_001,
+        )
+        .unwrap();
+
+        let claims = verify_credential(&jws).unwrap();
+        assert!(claims.vc.credential_subject.mode.is_none());
+        assert_eq!(claims.vc.credential_subject.status, Some(SafetyStatus::Caution));
+    }
+
+    #[test]
+    fn test_tampered_payload_rejected() {
+        let (key, pubkey) = test_signer();
+        let jws = issue_credential(
+            &pubkey,
+            |msg| Ok(key.sign(msg).to_bytes()),
+            "decode",
+            [0x33u8; 32],
+            Some(EncodingMode::Basic),
+            None,
+            1_700_000_002,
+        )
+        .unwrap();
+
+        let mut parts: Vec<&str> = jws.split('.').collect();
+        parts[1] = "dGFtcGVyZWQ"; // "tampered", base64url, not valid JSON claims
+        let tampered = parts.join(".");
+
+        assert!(verify_credential(&tampered).is_err());
+    }
+
+    #[test]
+    fn test_wrong_issuer_key_rejected() {
+        let (key, _pubkey) = test_signer();
+        let other_pubkey = SigningKey::from_bytes(&[9u8; 32]).verifying_key().to_bytes();
+
+        // Sign with `key` but claim `other_pubkey` as the issuer.
+        let jws = issue_credential(
+            &other_pubkey,
+            |msg| Ok(key.sign(msg).to_bytes()),
+            "encode",
+            [0x44u8; 32],
+            Some(EncodingMode::Basic),
+            None,
+            1_700_000_003,
+        )
+        .unwrap();
+
+        assert!(verify_credential(&jws).is_err());
+    }
+
+    #[test]
+    fn test_malformed_jws_rejected() {
+        assert!(verify_credential("not-a-jws").is_err());
+        assert!(verify_credential("a.b.c.d").is_err());
+    }
+}
@@ -0,0 +1,179 @@
+//! Base58Check encoding for key shares
+//!
+//! An alternative, typo-resistant representation for the raw 32-byte K1/K2
+//! split-key shares (see [`crate::dna::split_key`]), which are otherwise
+//! base64 and easy to mistranscribe when copied by hand. Layout is the
+//! standard Bitcoin-style Base58Check: `version || payload || checksum`,
+//! where `checksum` is the first 4 bytes of `SHA256(SHA256(version ||
+//! payload))`, and the whole thing is Base58-encoded with the Bitcoin
+//! alphabet (no `0`, `O`, `I`, or `l`, so visually similar characters never
+//! collide). A single mistyped character almost always changes the
+//! checksum, so [`decode`] catches transcription errors instead of quietly
+//! reconstructing the wrong key.
+
+use sha2::{Digest, Sha256};
+
+use crate::error::{DNACryptoError, Result};
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const CHECKSUM_LEN: usize = 4;
+
+/// Version byte identifying a Base58Check-encoded split-key share.
+pub const KEY_SHARE_VERSION: u8 = 0x2b;
+
+/// Double-SHA256 the version-prefixed payload and keep the first 4 bytes.
+fn checksum(version: u8, payload: &[u8]) -> [u8; CHECKSUM_LEN] {
+    let mut first = Sha256::new();
+    first.update([version]);
+    first.update(payload);
+    let first_hash = first.finalize();
+
+    let second_hash = Sha256::digest(first_hash);
+    let mut out = [0u8; CHECKSUM_LEN];
+    out.copy_from_slice(&second_hash[..CHECKSUM_LEN]);
+    out
+}
+
+/// Encode `payload` as `version || payload || checksum`, Base58-encoded.
+pub fn encode(version: u8, payload: &[u8]) -> String {
+    let checksum = checksum(version, payload);
+
+    let mut buf = Vec::with_capacity(1 + payload.len() + CHECKSUM_LEN);
+    buf.push(version);
+    buf.extend_from_slice(payload);
+    buf.extend_from_slice(&checksum);
+
+    base58_encode(&buf)
+}
+
+/// Decode a string produced by [`encode`], verifying its checksum.
+///
+/// Returns `(version, payload)`. Fails with
+/// [`DNACryptoError::InvalidChecksum`] if the trailing checksum doesn't
+/// match the recomputed double-SHA256, which is what a single mistyped
+/// character almost always produces.
+pub fn decode(encoded: &str) -> Result<(u8, Vec<u8>)> {
+    let buf = base58_decode(encoded)?;
+    if buf.len() < 1 + CHECKSUM_LEN {
+        return Err(DNACryptoError::DecodingFailed("Base58Check string too short".to_string()).into());
+    }
+
+    let (versioned_payload, expected_checksum) = buf.split_at(buf.len() - CHECKSUM_LEN);
+    let (version, payload) = versioned_payload
+        .split_first()
+        .expect("checked length above");
+
+    let actual_checksum = checksum(*version, payload);
+    if actual_checksum != expected_checksum {
+        return Err(DNACryptoError::InvalidChecksum.into());
+    }
+
+    Ok((*version, payload.to_vec()))
+}
+
+/// Encode raw bytes to Base58 (no checksum).
+fn base58_encode(bytes: &[u8]) -> String {
+    let leading_zeros = bytes.iter().take_while(|&&b| b == 0).count();
+
+    // Repeated divmod-by-58 on a big-endian byte buffer, base256 -> base58.
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for &byte in bytes {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut out: Vec<u8> = std::iter::repeat(ALPHABET[0])
+        .take(leading_zeros)
+        .chain(digits.iter().rev().map(|&d| ALPHABET[d as usize]))
+        .collect();
+
+    if out.is_empty() {
+        out.push(ALPHABET[0]);
+    }
+
+    String::from_utf8(out.split_off(0)).expect("alphabet is ASCII")
+}
+
+/// Decode a Base58 string (no checksum) back to raw bytes.
+fn base58_decode(encoded: &str) -> Result<Vec<u8>> {
+    let leading_ones = encoded.chars().take_while(|&c| c == '1').count();
+
+    let mut bytes: Vec<u8> = Vec::with_capacity(encoded.len());
+    for c in encoded.chars() {
+        let digit = ALPHABET
+            .iter()
+            .position(|&a| a as char == c)
+            .ok_or_else(|| DNACryptoError::DecodingFailed(format!("Invalid Base58 character: {}", c)))?
+            as u32;
+
+        let mut carry = digit;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut out = vec![0u8; leading_ones];
+    out.extend(bytes.iter().rev());
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let payload = [0x42u8; 32];
+        let encoded = encode(KEY_SHARE_VERSION, &payload);
+        let (version, decoded) = decode(&encoded).unwrap();
+        assert_eq!(version, KEY_SHARE_VERSION);
+        assert_eq!(decoded, payload);
+    }
+
+    #[test]
+    fn test_leading_zero_bytes_preserved() {
+        let payload = [0u8; 32];
+        let encoded = encode(KEY_SHARE_VERSION, &payload);
+        let (_, decoded) = decode(&encoded).unwrap();
+        assert_eq!(decoded, payload);
+    }
+
+    #[test]
+    fn test_tampered_character_rejected() {
+        let payload = [0x7fu8; 32];
+        let mut encoded = encode(KEY_SHARE_VERSION, &payload).into_bytes();
+        let last = encoded.len() - 1;
+        encoded[last] = if encoded[last] == ALPHABET[0] { ALPHABET[1] } else { ALPHABET[0] };
+        let tampered = String::from_utf8(encoded).unwrap();
+
+        let result = decode(&tampered);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_invalid_character_rejected() {
+        let result = decode("0OIl");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_alphabet_excludes_ambiguous_characters() {
+        for c in [b'0', b'O', b'I', b'l'] {
+            assert!(!ALPHABET.contains(&c));
+        }
+    }
+}
@@ -3,8 +3,10 @@
 //! Simple binary-to-DNA mapping: 00=A, 01=T, 10=C, 11=G
 //! Ported from Python: biocypher/dna_crypto.py
 
-use crate::dna::traits::{DNACoder, SequenceStats, SequenceStatistics};
-use crate::error::{DNACryptoError, Result};
+use crate::dna::secure::{Compression, SecureDNACrypto};
+use crate::dna::traits::{DNACoder, DnaDecoder, DnaEncoder, SequenceStats, SequenceStatistics};
+use crate::error::{BioCypherError, DNACryptoError, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 
 /// Basic DNA cryptography encoder/decoder
 pub struct DNACrypto;
@@ -72,6 +74,28 @@ impl SequenceStats for DNACrypto {
     }
 }
 
+impl DnaEncoder for DNACrypto {
+    type Output = String;
+    type Error = BioCypherError;
+
+    fn encode(&self, input: &[u8]) -> std::result::Result<String, BioCypherError> {
+        let message = std::str::from_utf8(input)
+            .map_err(|e| BioCypherError::Validation(format!("message is not valid UTF-8: {e}")))?;
+        Self::encode_message(message)
+    }
+}
+
+impl DnaDecoder for DNACrypto {
+    type Output = String;
+    type Error = BioCypherError;
+
+    fn decode(&self, input: &[u8]) -> std::result::Result<String, BioCypherError> {
+        let sequence = std::str::from_utf8(input)
+            .map_err(|e| BioCypherError::Validation(format!("sequence is not valid UTF-8: {e}")))?;
+        Self::decode_sequence(sequence)
+    }
+}
+
 impl DNACrypto {
     /// DNA base encoding mapping
     const DNA_ENCODE: [(u8, &str); 4] = [
@@ -183,6 +207,96 @@ impl DNACrypto {
 
         Ok(text)
     }
+
+    /// Header length prepended by [`Self::encode_message_with_compression`]:
+    /// 1 compression-algorithm tag byte + 4-byte big-endian original length.
+    const COMPRESSION_HEADER_LEN: usize = 5;
+
+    /// Encode message to DNA, compressing the UTF-8 bytes first so
+    /// compressible payloads (JSON, text) synthesize to a shorter sequence.
+    /// A small header (algorithm tag + original length) is prepended before
+    /// the compressed bytes and the whole frame is base64-encoded, so it
+    /// stays within the printable-ASCII range [`Self::binary_to_text`]
+    /// requires on decode; [`Self::decode_sequence_with_compression`]
+    /// reverses this. Returns the DNA sequence plus (raw_bytes, compressed_bytes).
+    pub fn encode_message_with_compression(
+        message: &str,
+        compression: Compression,
+    ) -> Result<(String, usize, usize)> {
+        if message.is_empty() {
+            return Ok((String::new(), 0, 0));
+        }
+
+        let raw = message.as_bytes();
+        let compressed = SecureDNACrypto::compress_with_choice(raw, compression)?;
+
+        let mut framed = Vec::with_capacity(Self::COMPRESSION_HEADER_LEN + compressed.len());
+        framed.push(compression.to_tag());
+        framed.extend_from_slice(&(raw.len() as u32).to_be_bytes());
+        framed.extend_from_slice(&compressed);
+
+        let dna = Self::encode_message(&BASE64.encode(&framed))?;
+        Ok((dna, raw.len(), compressed.len()))
+    }
+
+    /// Commitment binding a plaintext message to the DNA/ciphertext it was
+    /// encoded into: `blake3(message_bytes || nonce_le)`. blake3 is chosen
+    /// for its speed and 256-bit security. A decoder that recomputes this
+    /// over its decoded message via [`Self::verify_commitment`] can detect
+    /// tampering or a mismatched ciphertext set without needing to
+    /// cross-check anything else about the encoding.
+    ///
+    /// The nonce must be serialized little-endian and the message bytes
+    /// must be in the exact order used at encode time; either mismatch
+    /// makes decode-side recomputation diverge from the original digest.
+    pub fn compute_commitment(message: &[u8], nonce: u128) -> [u8; 32] {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(message);
+        hasher.update(&nonce.to_le_bytes());
+        *hasher.finalize().as_bytes()
+    }
+
+    /// Recompute [`Self::compute_commitment`] over a decoded message and
+    /// compare it against the commitment recorded at encode time. The
+    /// comparison is constant-time so a mismatch doesn't leak which byte
+    /// of the digest differed.
+    pub fn verify_commitment(message: &[u8], nonce: u128, expected: &[u8; 32]) -> bool {
+        let actual = Self::compute_commitment(message, nonce);
+        let mut diff = 0u8;
+        for (a, b) in actual.iter().zip(expected.iter()) {
+            diff |= a ^ b;
+        }
+        diff == 0
+    }
+
+    /// Decode a sequence produced by [`Self::encode_message_with_compression`].
+    pub fn decode_sequence_with_compression(sequence: &str) -> Result<String> {
+        if sequence.is_empty() {
+            return Ok(String::new());
+        }
+
+        let encoded = Self::decode_sequence(sequence)?;
+        let framed = BASE64
+            .decode(encoded.as_bytes())
+            .map_err(|e| DNACryptoError::DecodingFailed(format!("Invalid base64: {}", e)))?;
+
+        if framed.len() < Self::COMPRESSION_HEADER_LEN {
+            return Err(DNACryptoError::DecodingFailed("Compression header missing".to_string()).into());
+        }
+        let (header, payload) = framed.split_at(Self::COMPRESSION_HEADER_LEN);
+        let compression = Compression::from_tag(header[0])?;
+        let original_len = u32::from_be_bytes(header[1..5].try_into().unwrap()) as usize;
+
+        let decompressed = SecureDNACrypto::decompress(compression, payload)?;
+        if decompressed.len() != original_len {
+            return Err(DNACryptoError::DecodingFailed(
+                "Decompressed length does not match the recorded original length".to_string(),
+            )
+            .into());
+        }
+
+        String::from_utf8(decompressed).map_err(|e| DNACryptoError::DecodingFailed(e.to_string()).into())
+    }
 }
 
 #[cfg(test)]
@@ -329,6 +443,68 @@ mod tests {
         assert_eq!(original, decoded);
     }
 
+    #[test]
+    fn test_compression_roundtrip_gzip() {
+        let original = "abababababababababababababababababababab".repeat(10);
+        let (dna, raw_bytes, compressed_bytes) =
+            DNACrypto::encode_message_with_compression(&original, Compression::Gzip).unwrap();
+        assert_eq!(raw_bytes, original.len());
+        assert!(compressed_bytes < raw_bytes);
+        let decoded = DNACrypto::decode_sequence_with_compression(&dna).unwrap();
+        assert_eq!(decoded, original);
+    }
+
+    #[test]
+    fn test_compression_roundtrip_zstd() {
+        let original = "abababababababababababababababababababab".repeat(10);
+        let (dna, _, _) =
+            DNACrypto::encode_message_with_compression(&original, Compression::Zstd).unwrap();
+        let decoded = DNACrypto::decode_sequence_with_compression(&dna).unwrap();
+        assert_eq!(decoded, original);
+    }
+
+    #[test]
+    fn test_compression_roundtrip_none() {
+        let original = "Hello, World!";
+        let (dna, raw_bytes, compressed_bytes) =
+            DNACrypto::encode_message_with_compression(original, Compression::None).unwrap();
+        assert_eq!(raw_bytes, compressed_bytes);
+        let decoded = DNACrypto::decode_sequence_with_compression(&dna).unwrap();
+        assert_eq!(decoded, original);
+    }
+
+    #[test]
+    fn test_compression_empty_message() {
+        let (dna, raw_bytes, compressed_bytes) =
+            DNACrypto::encode_message_with_compression("", Compression::Gzip).unwrap();
+        assert_eq!(dna, "");
+        assert_eq!(raw_bytes, 0);
+        assert_eq!(compressed_bytes, 0);
+        assert_eq!(DNACrypto::decode_sequence_with_compression("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_commitment_roundtrip() {
+        let message = b"Hello, World!";
+        let nonce = 0xdead_beef_u128;
+        let commitment = DNACrypto::compute_commitment(message, nonce);
+        assert!(DNACrypto::verify_commitment(message, nonce, &commitment));
+    }
+
+    #[test]
+    fn test_commitment_detects_tampered_message() {
+        let nonce = 42u128;
+        let commitment = DNACrypto::compute_commitment(b"original", nonce);
+        assert!(!DNACrypto::verify_commitment(b"tampered!", nonce, &commitment));
+    }
+
+    #[test]
+    fn test_commitment_detects_wrong_nonce() {
+        let message = b"same message";
+        let commitment = DNACrypto::compute_commitment(message, 1);
+        assert!(!DNACrypto::verify_commitment(message, 2, &commitment));
+    }
+
     #[test]
     fn test_all_printable_ascii() {
         let mut all_chars = String::new();
@@ -0,0 +1,199 @@
+//! Minimal DER tag-length-value encoder/decoder
+//!
+//! Implements just enough of DER (ASN.1 Distinguished Encoding Rules) to give
+//! envelope formats like the Secure mode's a stable, self-describing
+//! container: SEQUENCE, INTEGER and OCTET STRING, using the standard
+//! short-form (<0x80), one-byte (0x81) and two-byte (0x82) length encodings.
+//! This is not a general-purpose ASN.1 library; only the tags callers in
+//! this crate need are supported.
+
+use crate::error::{DNACryptoError, Result};
+
+pub const TAG_INTEGER: u8 = 0x02;
+pub const TAG_OCTET_STRING: u8 = 0x04;
+pub const TAG_SEQUENCE: u8 = 0x30;
+
+/// Encode a DER length per the standard short-form/long-form rules.
+fn encode_length(len: usize) -> Vec<u8> {
+    if len < 0x80 {
+        vec![len as u8]
+    } else if len <= 0xFF {
+        vec![0x81, len as u8]
+    } else if len <= 0xFFFF {
+        let bytes = (len as u16).to_be_bytes();
+        vec![0x82, bytes[0], bytes[1]]
+    } else {
+        let bytes = (len as u32).to_be_bytes();
+        vec![0x83, bytes[1], bytes[2], bytes[3]]
+    }
+}
+
+/// Encode a single tag-length-value element.
+pub fn encode_tlv(tag: u8, value: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(1 + 3 + value.len());
+    out.push(tag);
+    out.extend_from_slice(&encode_length(value.len()));
+    out.extend_from_slice(value);
+    out
+}
+
+/// Encode a non-negative integer as a minimal-length DER INTEGER.
+pub fn encode_integer(value: u64) -> Vec<u8> {
+    let mut bytes = value.to_be_bytes().to_vec();
+    while bytes.len() > 1 && bytes[0] == 0 && bytes[1] < 0x80 {
+        bytes.remove(0);
+    }
+    if bytes[0] & 0x80 != 0 {
+        // DER integers are signed; prepend 0x00 so a set high bit isn't read as negative.
+        bytes.insert(0, 0x00);
+    }
+    encode_tlv(TAG_INTEGER, &bytes)
+}
+
+/// Encode an OCTET STRING.
+pub fn encode_octet_string(value: &[u8]) -> Vec<u8> {
+    encode_tlv(TAG_OCTET_STRING, value)
+}
+
+/// Wrap already-encoded TLV elements in a SEQUENCE.
+pub fn encode_sequence(elements: &[Vec<u8>]) -> Vec<u8> {
+    let body: Vec<u8> = elements.concat();
+    encode_tlv(TAG_SEQUENCE, &body)
+}
+
+/// Reads TLV elements out of a DER buffer in order, validating tags and
+/// lengths and rejecting truncated or over-long inputs.
+pub struct TlvReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> TlvReader<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// Enter a SEQUENCE, returning a reader scoped to its contents.
+    pub fn read_sequence(&mut self) -> Result<TlvReader<'a>> {
+        let value = self.read_tlv(TAG_SEQUENCE)?;
+        Ok(TlvReader::new(value))
+    }
+
+    /// Read an INTEGER element and return it as a `u64`.
+    pub fn read_integer(&mut self) -> Result<u64> {
+        let value = self.read_tlv(TAG_INTEGER)?;
+        if value.is_empty() || value.len() > 8 {
+            return Err(DNACryptoError::DecryptionError("Invalid DER integer length".to_string()).into());
+        }
+        let mut padded = [0u8; 8];
+        padded[8 - value.len()..].copy_from_slice(value);
+        Ok(u64::from_be_bytes(padded))
+    }
+
+    /// Read an OCTET STRING element and return its raw bytes.
+    pub fn read_octet_string(&mut self) -> Result<Vec<u8>> {
+        Ok(self.read_tlv(TAG_OCTET_STRING)?.to_vec())
+    }
+
+    /// Read and validate one tag-length-value element, returning its value slice.
+    fn read_tlv(&mut self, expected_tag: u8) -> Result<&'a [u8]> {
+        if self.pos >= self.buf.len() {
+            return Err(DNACryptoError::DecryptionError("Truncated DER envelope".to_string()).into());
+        }
+        let tag = self.buf[self.pos];
+        if tag != expected_tag {
+            return Err(DNACryptoError::DecryptionError(format!(
+                "Unexpected DER tag: expected {:#04x}, found {:#04x}",
+                expected_tag, tag
+            ))
+            .into());
+        }
+        self.pos += 1;
+
+        let len = self.read_length()?;
+        if self.pos + len > self.buf.len() {
+            return Err(DNACryptoError::DecryptionError("Truncated DER envelope".to_string()).into());
+        }
+        let value = &self.buf[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(value)
+    }
+
+    /// Read a DER length, supporting short-form and the 1/2/3-byte long-form.
+    fn read_length(&mut self) -> Result<usize> {
+        if self.pos >= self.buf.len() {
+            return Err(DNACryptoError::DecryptionError("Truncated DER length".to_string()).into());
+        }
+        let first = self.buf[self.pos];
+        self.pos += 1;
+
+        if first < 0x80 {
+            return Ok(first as usize);
+        }
+
+        let num_bytes = (first & 0x7F) as usize;
+        if num_bytes == 0 || num_bytes > 4 {
+            return Err(DNACryptoError::DecryptionError("Unsupported DER length encoding".to_string()).into());
+        }
+        if self.pos + num_bytes > self.buf.len() {
+            return Err(DNACryptoError::DecryptionError("Truncated DER length".to_string()).into());
+        }
+        let mut len: usize = 0;
+        for &b in &self.buf[self.pos..self.pos + num_bytes] {
+            len = (len << 8) | b as usize;
+        }
+        self.pos += num_bytes;
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_roundtrip() {
+        for value in [0u64, 1, 127, 128, 255, 256, 65535, 65536] {
+            let encoded = encode_integer(value);
+            let mut reader = TlvReader::new(&encoded);
+            assert_eq!(reader.read_integer().unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn test_octet_string_roundtrip() {
+        let data = vec![0xAA; 300];
+        let encoded = encode_octet_string(&data);
+        let mut reader = TlvReader::new(&encoded);
+        assert_eq!(reader.read_octet_string().unwrap(), data);
+    }
+
+    #[test]
+    fn test_sequence_roundtrip() {
+        let seq = encode_sequence(&[
+            encode_integer(1),
+            encode_octet_string(b"salt"),
+            encode_octet_string(b"iv"),
+        ]);
+        let mut reader = TlvReader::new(&seq);
+        let mut inner = reader.read_sequence().unwrap();
+        assert_eq!(inner.read_integer().unwrap(), 1);
+        assert_eq!(inner.read_octet_string().unwrap(), b"salt".to_vec());
+        assert_eq!(inner.read_octet_string().unwrap(), b"iv".to_vec());
+    }
+
+    #[test]
+    fn test_truncated_input_rejected() {
+        let seq = encode_sequence(&[encode_octet_string(b"hello")]);
+        let truncated = &seq[..seq.len() - 2];
+        let mut reader = TlvReader::new(truncated);
+        assert!(reader.read_sequence().is_err());
+    }
+
+    #[test]
+    fn test_wrong_tag_rejected() {
+        let encoded = encode_integer(1);
+        let mut reader = TlvReader::new(&encoded);
+        assert!(reader.read_octet_string().is_err());
+    }
+}
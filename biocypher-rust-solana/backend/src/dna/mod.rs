@@ -3,18 +3,27 @@
 //! Handles encoding and decoding of messages using DNA bases with support for
 //! three modes: Basic, Nanopore, and Secure.
 
+pub mod base58check;
 pub mod basic;
+pub mod der;
+pub mod keystore;
 pub mod nanopore;
+pub mod openpgp;
 pub mod secure;
+pub mod shamir;
 pub mod split_key;
 pub mod traits;
 
 pub use basic::DNACrypto;
+pub use keystore::KeyStore;
 pub use nanopore::NanoporeDNACrypto;
+pub use openpgp::OpenPgpDNACrypto;
 pub use secure::SecureDNACrypto;
 pub use split_key::SplitKeyDNACrypto;
 pub use traits::*;
 
+use crate::error::BioCypherError;
+
 /// DNA base representation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DNABase {
@@ -66,6 +75,31 @@ impl DNABase {
             _ => None,
         }
     }
+
+    /// Watson-Crick complementary base (A<->T, C<->G)
+    pub fn complement(&self) -> Self {
+        match self {
+            DNABase::A => DNABase::T,
+            DNABase::T => DNABase::A,
+            DNABase::C => DNABase::G,
+            DNABase::G => DNABase::C,
+        }
+    }
+}
+
+/// Reverse complement of a DNA sequence, for screening the strand
+/// opposite the one supplied by the caller. Characters that aren't a
+/// recognized base (already filtered out by [`crate::safety::DNASafetyScreener`]
+/// upstream, but not guaranteed here) pass through unchanged.
+pub fn reverse_complement(sequence: &str) -> String {
+    sequence
+        .chars()
+        .rev()
+        .map(|c| match DNABase::from_char(c) {
+            Some(base) => base.complement().as_char(),
+            None => c,
+        })
+        .collect()
 }
 
 /// Encoding mode enumeration
@@ -76,6 +110,7 @@ pub enum EncodingMode {
     Nanopore,
     Secure,
     SplitKey,
+    OpenPgp,
 }
 
 impl std::fmt::Display for EncodingMode {
@@ -85,6 +120,35 @@ impl std::fmt::Display for EncodingMode {
             EncodingMode::Nanopore => write!(f, "nanopore"),
             EncodingMode::Secure => write!(f, "secure"),
             EncodingMode::SplitKey => write!(f, "splitkey"),
+            EncodingMode::OpenPgp => write!(f, "openpgp"),
+        }
+    }
+}
+
+impl EncodingMode {
+    /// Dispatch to a boxed [`DnaEncoder`] for this mode, so a caller can add
+    /// a new mode by implementing the trait instead of adding a match arm
+    /// here and at every call site. Modes needing extra input (Secure's
+    /// password, SplitKey's key shares) return a coder that errors
+    /// descriptively rather than panicking — see each mode's `DNACoder` impl.
+    pub fn encoder(&self) -> Box<dyn DnaEncoder<Output = String, Error = BioCypherError>> {
+        match self {
+            EncodingMode::Basic => Box::new(DNACrypto),
+            EncodingMode::Nanopore => Box::new(NanoporeDNACrypto),
+            EncodingMode::Secure => Box::new(SecureDNACrypto),
+            EncodingMode::SplitKey => Box::new(SplitKeyDNACrypto),
+            EncodingMode::OpenPgp => Box::new(OpenPgpDNACrypto),
+        }
+    }
+
+    /// Dispatch to a boxed [`DnaDecoder`] for this mode; see [`Self::encoder`].
+    pub fn decoder(&self) -> Box<dyn DnaDecoder<Output = String, Error = BioCypherError>> {
+        match self {
+            EncodingMode::Basic => Box::new(DNACrypto),
+            EncodingMode::Nanopore => Box::new(NanoporeDNACrypto),
+            EncodingMode::Secure => Box::new(SecureDNACrypto),
+            EncodingMode::SplitKey => Box::new(SplitKeyDNACrypto),
+            EncodingMode::OpenPgp => Box::new(OpenPgpDNACrypto),
         }
     }
 }
@@ -98,6 +162,7 @@ impl std::str::FromStr for EncodingMode {
             "nanopore" => Ok(EncodingMode::Nanopore),
             "secure" => Ok(EncodingMode::Secure),
             "splitkey" => Ok(EncodingMode::SplitKey),
+            "openpgp" => Ok(EncodingMode::OpenPgp),
             _ => Err(format!("Invalid encoding mode: {}", s)),
         }
     }
@@ -125,16 +190,49 @@ mod tests {
         assert_eq!(DNABase::from_binary_pair("00"), Some(DNABase::A));
     }
 
+    #[test]
+    fn test_dna_base_complement() {
+        assert_eq!(DNABase::A.complement(), DNABase::T);
+        assert_eq!(DNABase::T.complement(), DNABase::A);
+        assert_eq!(DNABase::C.complement(), DNABase::G);
+        assert_eq!(DNABase::G.complement(), DNABase::C);
+    }
+
+    #[test]
+    fn test_reverse_complement() {
+        assert_eq!(reverse_complement("ATCG"), "CGAT");
+        assert_eq!(reverse_complement("AAAA"), "TTTT");
+        assert_eq!(reverse_complement(""), "");
+    }
+
+    #[test]
+    fn test_encoder_decoder_dispatch_roundtrip() {
+        let encoder = EncodingMode::Basic.encoder();
+        let dna = encoder.encode(b"Hi").unwrap();
+
+        let decoder = EncodingMode::Basic.decoder();
+        let message = decoder.decode(dna.as_bytes()).unwrap();
+        assert_eq!(message, "Hi");
+    }
+
+    #[test]
+    fn test_encoder_dispatch_errors_for_modes_needing_extra_input() {
+        assert!(EncodingMode::Secure.encoder().encode(b"hi").is_err());
+        assert!(EncodingMode::SplitKey.encoder().encode(b"hi").is_err());
+    }
+
     #[test]
     fn test_encoding_mode() {
         assert_eq!(EncodingMode::Basic.to_string(), "basic");
         assert_eq!(EncodingMode::Nanopore.to_string(), "nanopore");
         assert_eq!(EncodingMode::Secure.to_string(), "secure");
         assert_eq!(EncodingMode::SplitKey.to_string(), "splitkey");
+        assert_eq!(EncodingMode::OpenPgp.to_string(), "openpgp");
 
         assert_eq!("basic".parse::<EncodingMode>(), Ok(EncodingMode::Basic));
         assert_eq!("nanopore".parse::<EncodingMode>(), Ok(EncodingMode::Nanopore));
         assert_eq!("secure".parse::<EncodingMode>(), Ok(EncodingMode::Secure));
         assert_eq!("splitkey".parse::<EncodingMode>(), Ok(EncodingMode::SplitKey));
+        assert_eq!("openpgp".parse::<EncodingMode>(), Ok(EncodingMode::OpenPgp));
     }
 }
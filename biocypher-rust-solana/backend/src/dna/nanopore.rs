@@ -4,8 +4,13 @@
 //! Ported from Python: biocypher/nanopore_dna_crypto.py
 
 use crate::dna::markers;
-use crate::dna::traits::{DNACoder, SequenceStats, SequenceStatistics};
-use crate::error::{DNACryptoError, Result};
+use crate::dna::secure::SecureDNACrypto;
+use crate::dna::traits::{DNACoder, DnaDecoder, DnaEncoder, SequenceStats, SequenceStatistics};
+use crate::error::{BioCypherError, DNACryptoError, Result};
+use argon2::Argon2;
+use chacha20poly1305::aead::Aead;
+use chacha20poly1305::{KeyInit, XChaCha20Poly1305, XNonce};
+use rand::RngCore;
 use regex::Regex;
 
 /// Nanopore-optimized DNA cryptography with error correction and homopolymer avoidance
@@ -29,6 +34,28 @@ impl SequenceStats for NanoporeDNACrypto {
     }
 }
 
+impl DnaEncoder for NanoporeDNACrypto {
+    type Output = String;
+    type Error = BioCypherError;
+
+    fn encode(&self, input: &[u8]) -> std::result::Result<String, BioCypherError> {
+        let message = std::str::from_utf8(input)
+            .map_err(|e| BioCypherError::Validation(format!("message is not valid UTF-8: {e}")))?;
+        Self::encode_message(message)
+    }
+}
+
+impl DnaDecoder for NanoporeDNACrypto {
+    type Output = String;
+    type Error = BioCypherError;
+
+    fn decode(&self, input: &[u8]) -> std::result::Result<String, BioCypherError> {
+        let sequence = std::str::from_utf8(input)
+            .map_err(|e| BioCypherError::Validation(format!("sequence is not valid UTF-8: {e}")))?;
+        Self::decode_sequence(sequence)
+    }
+}
+
 impl NanoporeDNACrypto {
     /// Triplet encoding table (avoids homopolymers)
     const NANOPORE_ENCODE: [(&'static str, &'static str); 8] = [
@@ -58,6 +85,141 @@ impl NanoporeDNACrypto {
     /// Error correction repeats
     const ERROR_CORRECTION_REPEATS: usize = 3;
 
+    /// Fixed-length prefixes for the secure-mode byte blob fed into the
+    /// triplet encoder: a 16-byte Argon2id salt, a 24-byte XChaCha20-Poly1305
+    /// nonce, then `ciphertext || 16-byte Poly1305 tag`.
+    const SECURE_SALT_SIZE: usize = 16;
+    const SECURE_NONCE_SIZE: usize = 24;
+    const SECURE_TAG_SIZE: usize = 16;
+    const SECURE_KEY_SIZE: usize = 32;
+
+    /// Encode with password-based authenticated encryption: the plaintext is
+    /// sealed with XChaCha20-Poly1305 (key derived via Argon2id from the
+    /// password and a fresh random salt) before the existing
+    /// homopolymer/GC/error-correction pipeline encodes the ciphertext blob
+    /// as DNA, so the pipeline only ever operates on ciphertext. Triple
+    /// redundancy is applied the same as [`Self::encode_message_with_options`]
+    /// so a handful of sequencing bit flips are corrected before the AEAD
+    /// tag is checked, rather than hard-failing decryption outright.
+    pub fn encode_message_secure(message: &str, password: &str) -> Result<String> {
+        if message.is_empty() {
+            return Ok(String::new());
+        }
+        if password.is_empty() {
+            return Err(DNACryptoError::PasswordRequired.into());
+        }
+        SecureDNACrypto::require_min_entropy(password, SecureDNACrypto::DEFAULT_MIN_ENTROPY_BITS)?;
+
+        let mut salt = [0u8; Self::SECURE_SALT_SIZE];
+        rand::thread_rng().fill_bytes(&mut salt);
+        let key = Self::derive_secure_key(password, &salt)?;
+
+        let mut nonce_bytes = [0u8; Self::SECURE_NONCE_SIZE];
+        rand::thread_rng().fill_bytes(&mut nonce_bytes);
+        let nonce = XNonce::from_slice(&nonce_bytes);
+        let cipher = XChaCha20Poly1305::new_from_slice(&key)
+            .map_err(|e| DNACryptoError::EncryptionError(e.to_string()))?;
+        let ciphertext = cipher
+            .encrypt(nonce, message.as_bytes())
+            .map_err(|e| DNACryptoError::EncryptionError(e.to_string()))?;
+
+        let mut blob = Vec::with_capacity(Self::SECURE_SALT_SIZE + Self::SECURE_NONCE_SIZE + ciphertext.len());
+        blob.extend_from_slice(&salt);
+        blob.extend_from_slice(&nonce_bytes);
+        blob.extend_from_slice(&ciphertext);
+
+        let binary = Self::bytes_to_binary(&blob);
+        let binary = Self::add_error_correction(&binary);
+        let mut dna = Self::binary_to_nanopore_dna(&binary)?;
+
+        if Self::has_homopolymers(&dna) || !Self::is_gc_balanced(&dna) {
+            let padding = Self::generate_nanopore_padding(&dna);
+            dna = format!(
+                "{}{}{}{}{}",
+                padding,
+                markers::PADDING_DELIMITER,
+                dna,
+                markers::PADDING_DELIMITER,
+                padding
+            );
+        }
+
+        Ok(format!(
+            "{}{}{}",
+            markers::START_MARKER,
+            dna,
+            markers::STOP_MARKER
+        ))
+    }
+
+    /// Decode a sequence produced by [`Self::encode_message_secure`]. A
+    /// wrong password or a tampered sequence surfaces as a Poly1305 tag
+    /// mismatch, reported as `DecryptionError`.
+    pub fn decode_sequence_secure(sequence: &str, password: &str) -> Result<String> {
+        if sequence.is_empty() {
+            return Ok(String::new());
+        }
+        if password.is_empty() {
+            return Err(DNACryptoError::PasswordRequired.into());
+        }
+
+        let mut seq = Self::remove_nanopore_markers(sequence);
+        seq = Self::remove_nanopore_padding(&seq);
+
+        let binary = Self::nanopore_dna_to_binary(&seq)?;
+        let binary = Self::correct_errors(&binary)?;
+        let blob = Self::binary_to_bytes(&binary);
+
+        let min_len = Self::SECURE_SALT_SIZE + Self::SECURE_NONCE_SIZE + Self::SECURE_TAG_SIZE;
+        if blob.len() < min_len {
+            return Err(DNACryptoError::DecryptionError("Secure nanopore blob too short".to_string()).into());
+        }
+
+        let (salt, rest) = blob.split_at(Self::SECURE_SALT_SIZE);
+        let (nonce_bytes, ciphertext) = rest.split_at(Self::SECURE_NONCE_SIZE);
+
+        let key = Self::derive_secure_key(password, salt)?;
+        let nonce = XNonce::from_slice(nonce_bytes);
+        let cipher = XChaCha20Poly1305::new_from_slice(&key)
+            .map_err(|e| DNACryptoError::DecryptionError(e.to_string()))?;
+        let plaintext = cipher.decrypt(nonce, ciphertext).map_err(|_| {
+            DNACryptoError::DecryptionError("Wrong password or tampered sequence".to_string())
+        })?;
+
+        String::from_utf8(plaintext).map_err(|e| DNACryptoError::DecryptionError(e.to_string()).into())
+    }
+
+    /// Derive a 256-bit XChaCha20-Poly1305 key from a password and salt via Argon2id.
+    fn derive_secure_key(password: &str, salt: &[u8]) -> Result<[u8; Self::SECURE_KEY_SIZE]> {
+        let mut key = [0u8; Self::SECURE_KEY_SIZE];
+        Argon2::default()
+            .hash_password_into(password.as_bytes(), salt, &mut key)
+            .map_err(|e| DNACryptoError::EncryptionError(format!("Argon2id derivation failed: {}", e)))?;
+        Ok(key)
+    }
+
+    /// Bytes to a bitstring, MSB first.
+    fn bytes_to_binary(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:08b}", b)).collect()
+    }
+
+    /// Inverse of [`Self::bytes_to_binary`]; any trailing partial byte left
+    /// over from triplet padding is dropped.
+    fn binary_to_bytes(binary: &str) -> Vec<u8> {
+        binary
+            .as_bytes()
+            .chunks(8)
+            .filter(|chunk| chunk.len() == 8)
+            .map(|chunk| {
+                let mut byte = 0u8;
+                for &b in chunk {
+                    byte = (byte << 1) | (b - b'0');
+                }
+                byte
+            })
+            .collect()
+    }
+
     /// Encode with optional error correction
     pub fn encode_message_with_options(
         message: &str,
@@ -369,4 +531,81 @@ mod tests {
         assert!(NanoporeDNACrypto::is_gc_balanced("ATCG")); // 50% GC
         assert!(NanoporeDNACrypto::is_gc_balanced("ATCGATCG")); // 50% GC
     }
+
+    #[test]
+    fn test_secure_mode_error_correction_survives_single_bit_flip() {
+        // encode_message_secure applies add_error_correction to the
+        // ciphertext blob the same way encode_message_with_options does for
+        // plaintext, so a flipped redundant bit is still recovered by
+        // correct_errors before the AEAD tag is ever checked.
+        let binary = "01101";
+        let redundant = NanoporeDNACrypto::add_error_correction(binary);
+        let mut chars: Vec<char> = redundant.chars().collect();
+        chars[1] = if chars[1] == '0' { '1' } else { '0' };
+        let flipped: String = chars.into_iter().collect();
+        let corrected = NanoporeDNACrypto::correct_errors(&flipped).unwrap();
+        assert_eq!(corrected, binary);
+    }
+
+    #[test]
+    fn test_secure_encode_decode_roundtrip() {
+        let original = "Secret nanopore message";
+        let password = "correct horse battery staple zebra";
+        let dna = NanoporeDNACrypto::encode_message_secure(original, password).unwrap();
+        assert!(dna.starts_with(markers::START_MARKER));
+        assert!(dna.ends_with(markers::STOP_MARKER));
+        let decoded = NanoporeDNACrypto::decode_sequence_secure(&dna, password).unwrap();
+        assert_eq!(original, decoded);
+    }
+
+    #[test]
+    fn test_secure_encode_decode_empty() {
+        let password = "correct horse battery staple zebra";
+        assert_eq!(
+            NanoporeDNACrypto::encode_message_secure("", password).unwrap(),
+            ""
+        );
+        assert_eq!(
+            NanoporeDNACrypto::decode_sequence_secure("", password).unwrap(),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_secure_wrong_password_fails() {
+        let dna = NanoporeDNACrypto::encode_message_secure(
+            "Secret",
+            "correct horse battery staple zebra",
+        )
+        .unwrap();
+        let result = NanoporeDNACrypto::decode_sequence_secure(&dna, "wrong horse battery staple zebra");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_secure_tampered_sequence_detected() {
+        let password = "correct horse battery staple zebra";
+        let dna = NanoporeDNACrypto::encode_message_secure("Secret", password).unwrap();
+        let mut tampered: Vec<char> = dna.chars().collect();
+        let mid = tampered.len() / 2;
+        tampered[mid] = if tampered[mid] == 'A' { 'T' } else { 'A' };
+        let tampered: String = tampered.into_iter().collect();
+        let result = NanoporeDNACrypto::decode_sequence_secure(&tampered, password);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_secure_weak_password_rejected() {
+        let result = NanoporeDNACrypto::encode_message_secure("Secret", "password123");
+        assert!(matches!(
+            result,
+            Err(crate::error::BioCypherError::DNACrypto(DNACryptoError::PasswordWeak(_)))
+        ));
+    }
+
+    #[test]
+    fn test_secure_password_required() {
+        let result = NanoporeDNACrypto::encode_message_secure("Secret", "");
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,384 @@
+//! OpenPGP Interop DNA Module
+//!
+//! Stores an existing ASCII-armored OpenPGP message as DNA without
+//! re-encrypting it: the crate's own cryptography never touches the
+//! packet bytes, so whatever keyring produced the message can decrypt it
+//! again once it's read back out of DNA. `encode_message` takes armor in,
+//! strips it down to `label || headers || packet bytes`, and maps that
+//! envelope onto DNA; `decode_sequence` rebuilds byte-identical armor
+//! (standard 64-column wrapping plus a recomputed CRC24 checksum, per
+//! RFC 4880 section 6) from the recovered envelope. Constructing a fresh
+//! PGP message from plaintext and a recipient key is out of scope here;
+//! produce the armored message with your existing OpenPGP tooling first.
+
+use crate::dna::markers;
+use crate::dna::traits::{DNACoder, DnaDecoder, DnaEncoder, SequenceStats, SequenceStatistics};
+use crate::error::{BioCypherError, DNACryptoError, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+
+/// OpenPGP interop DNA coder: ASCII-armored PGP blocks in, DNA out
+pub struct OpenPgpDNACrypto;
+
+impl DNACoder for OpenPgpDNACrypto {
+    /// Encode an ASCII-armored OpenPGP message to DNA
+    fn encode_message(message: &str) -> Result<String> {
+        if message.is_empty() {
+            return Ok(String::new());
+        }
+
+        let (label, headers, body) = Self::parse_armor(message)?;
+        let frame = Self::frame_envelope(&label, &headers, &body);
+        let dna = Self::bytes_to_dna(&frame);
+
+        Ok(format!(
+            "{}{}{}",
+            markers::START_MARKER,
+            dna,
+            markers::STOP_MARKER
+        ))
+    }
+
+    /// Decode a sequence produced by [`Self::encode_message`] back into
+    /// byte-identical ASCII-armored OpenPGP output
+    fn decode_sequence(sequence: &str) -> Result<String> {
+        if sequence.is_empty() {
+            return Ok(String::new());
+        }
+
+        let core = Self::remove_markers(sequence);
+        let frame = Self::dna_to_bytes(&core)?;
+        let (label, headers, body) = Self::unframe_envelope(&frame)?;
+
+        Ok(Self::build_armor(&label, &headers, &body))
+    }
+}
+
+impl SequenceStats for OpenPgpDNACrypto {
+    fn get_sequence_stats(sequence: &str) -> SequenceStatistics {
+        SequenceStatistics::new(sequence)
+    }
+}
+
+impl DnaEncoder for OpenPgpDNACrypto {
+    type Output = String;
+    type Error = BioCypherError;
+
+    fn encode(&self, input: &[u8]) -> std::result::Result<String, BioCypherError> {
+        let message = std::str::from_utf8(input)
+            .map_err(|e| BioCypherError::Validation(format!("message is not valid UTF-8: {e}")))?;
+        Self::encode_message(message)
+    }
+}
+
+impl DnaDecoder for OpenPgpDNACrypto {
+    type Output = String;
+    type Error = BioCypherError;
+
+    fn decode(&self, input: &[u8]) -> std::result::Result<String, BioCypherError> {
+        let sequence = std::str::from_utf8(input)
+            .map_err(|e| BioCypherError::Validation(format!("sequence is not valid UTF-8: {e}")))?;
+        Self::decode_sequence(sequence)
+    }
+}
+
+impl OpenPgpDNACrypto {
+    const ARMOR_BEGIN_PREFIX: &'static str = "-----BEGIN PGP ";
+    const ARMOR_END_PREFIX: &'static str = "-----END PGP ";
+    const ARMOR_SUFFIX: &'static str = "-----";
+    const ARMOR_LINE_WIDTH: usize = 64;
+
+    const CRC24_INIT: u32 = 0x00B7_04CE;
+    const CRC24_POLY: u32 = 0x0186_4CFB;
+
+    /// 2-bit/base mapping, same table as [`crate::dna::basic::DNACrypto`]
+    const DNA_ENCODE: [(u8, &'static str); 4] = [
+        (0b00, "A"),
+        (0b01, "T"),
+        (0b10, "C"),
+        (0b11, "G"),
+    ];
+
+    /// Strip the crate's own START/STOP markers (not part of the OpenPGP format)
+    fn remove_markers(sequence: &str) -> String {
+        let mut seq = sequence.to_string();
+        if seq.starts_with(markers::START_MARKER) {
+            seq = seq[markers::START_MARKER.len()..].to_string();
+        }
+        if seq.ends_with(markers::STOP_MARKER) {
+            seq = seq[..seq.len() - markers::STOP_MARKER.len()].to_string();
+        }
+        seq
+    }
+
+    /// Parse an ASCII-armored OpenPGP block into its label (e.g. `"PGP
+    /// MESSAGE"`), ordered armor headers (e.g. `Version: ...`), and decoded
+    /// packet bytes. Any checksum line is verified if present, then discarded
+    /// (it's recomputed fresh on the way back out).
+    fn parse_armor(armored: &str) -> Result<(String, Vec<(String, String)>, Vec<u8>)> {
+        let lines: Vec<&str> = armored.lines().collect();
+
+        let begin_idx = lines
+            .iter()
+            .position(|l| l.starts_with(Self::ARMOR_BEGIN_PREFIX) && l.ends_with(Self::ARMOR_SUFFIX))
+            .ok_or_else(|| DNACryptoError::DecodingFailed("Missing PGP armor header".to_string()))?;
+        let label = lines[begin_idx]
+            [Self::ARMOR_BEGIN_PREFIX.len()..lines[begin_idx].len() - Self::ARMOR_SUFFIX.len()]
+            .to_string();
+
+        let end_marker = format!("{}{}{}", Self::ARMOR_END_PREFIX, label, Self::ARMOR_SUFFIX);
+        let end_idx = lines[begin_idx + 1..]
+            .iter()
+            .position(|l| *l == end_marker)
+            .map(|i| begin_idx + 1 + i)
+            .ok_or_else(|| DNACryptoError::DecodingFailed("Missing PGP armor footer".to_string()))?;
+
+        let body_lines = &lines[begin_idx + 1..end_idx];
+
+        // Armor headers run until the first blank line
+        let blank_idx = body_lines
+            .iter()
+            .position(|l| l.is_empty())
+            .unwrap_or(body_lines.len());
+        let headers = body_lines[..blank_idx]
+            .iter()
+            .map(|l| {
+                l.split_once(": ")
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .ok_or_else(|| DNACryptoError::DecodingFailed(format!("Invalid armor header: {}", l)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Base64 payload lines, up to an optional `=XXXX` checksum line
+        let payload_lines = &body_lines[(blank_idx + 1).min(body_lines.len())..];
+        let checksum_idx = payload_lines.iter().position(|l| l.starts_with('='));
+        let base64_lines = match checksum_idx {
+            Some(i) => &payload_lines[..i],
+            None => payload_lines,
+        };
+
+        let body = BASE64
+            .decode(base64_lines.concat())
+            .map_err(|e| DNACryptoError::DecodingFailed(format!("Invalid armor base64: {}", e)))?;
+
+        if let Some(i) = checksum_idx {
+            let expected = payload_lines[i].trim_start_matches('=');
+            let expected_bytes = BASE64
+                .decode(expected)
+                .map_err(|e| DNACryptoError::DecodingFailed(format!("Invalid armor checksum: {}", e)))?;
+            if expected_bytes != Self::crc24(&body).to_be_bytes()[1..] {
+                return Err(DNACryptoError::DecodingFailed("Armor checksum mismatch".to_string()).into());
+            }
+        }
+
+        Ok((label, headers, body))
+    }
+
+    /// Rebuild a standard ASCII-armored block: `label`/`headers` as given,
+    /// `body` base64-wrapped at [`Self::ARMOR_LINE_WIDTH`] columns, followed
+    /// by a freshly computed CRC24 checksum line.
+    fn build_armor(label: &str, headers: &[(String, String)], body: &[u8]) -> String {
+        let mut out = format!("{}{}{}\n", Self::ARMOR_BEGIN_PREFIX, label, Self::ARMOR_SUFFIX);
+        for (key, value) in headers {
+            out.push_str(&format!("{}: {}\n", key, value));
+        }
+        out.push('\n');
+
+        let encoded = BASE64.encode(body);
+        for chunk in encoded.as_bytes().chunks(Self::ARMOR_LINE_WIDTH) {
+            out.push_str(std::str::from_utf8(chunk).expect("base64 output is ASCII"));
+            out.push('\n');
+        }
+
+        let checksum = Self::crc24(body).to_be_bytes();
+        out.push('=');
+        out.push_str(&BASE64.encode(&checksum[1..]));
+        out.push('\n');
+
+        out.push_str(&format!("{}{}{}", Self::ARMOR_END_PREFIX, label, Self::ARMOR_SUFFIX));
+        out
+    }
+
+    /// CRC24 checksum per RFC 4880 section 6.1
+    fn crc24(data: &[u8]) -> u32 {
+        let mut crc = Self::CRC24_INIT;
+        for &byte in data {
+            crc ^= (byte as u32) << 16;
+            for _ in 0..8 {
+                crc <<= 1;
+                if crc & 0x0100_0000 != 0 {
+                    crc ^= Self::CRC24_POLY;
+                }
+            }
+        }
+        crc & 0x00FF_FFFF
+    }
+
+    /// Frame `label || headers || body` into one byte blob so the DNA
+    /// round trip can reconstruct the full armor, not just the packet
+    /// bytes: `[label_len][label][header_count][(key_len,key,val_len,val)...][body_len u32 BE][body]`.
+    fn frame_envelope(label: &str, headers: &[(String, String)], body: &[u8]) -> Vec<u8> {
+        let mut frame = Vec::new();
+        frame.push(label.len() as u8);
+        frame.extend_from_slice(label.as_bytes());
+
+        frame.push(headers.len() as u8);
+        for (key, value) in headers {
+            frame.push(key.len() as u8);
+            frame.extend_from_slice(key.as_bytes());
+            frame.push(value.len() as u8);
+            frame.extend_from_slice(value.as_bytes());
+        }
+
+        frame.extend_from_slice(&(body.len() as u32).to_be_bytes());
+        frame.extend_from_slice(body);
+        frame
+    }
+
+    /// Inverse of [`Self::frame_envelope`].
+    fn unframe_envelope(frame: &[u8]) -> Result<(String, Vec<(String, String)>, Vec<u8>)> {
+        let too_short = || DNACryptoError::DecodingFailed("OpenPGP envelope truncated".to_string());
+
+        let mut pos = 0usize;
+        let label_len = *frame.get(pos).ok_or_else(too_short)? as usize;
+        pos += 1;
+        let label = String::from_utf8(frame.get(pos..pos + label_len).ok_or_else(too_short)?.to_vec())
+            .map_err(|e| DNACryptoError::DecodingFailed(e.to_string()))?;
+        pos += label_len;
+
+        let header_count = *frame.get(pos).ok_or_else(too_short)? as usize;
+        pos += 1;
+        let mut headers = Vec::with_capacity(header_count);
+        for _ in 0..header_count {
+            let key_len = *frame.get(pos).ok_or_else(too_short)? as usize;
+            pos += 1;
+            let key = String::from_utf8(frame.get(pos..pos + key_len).ok_or_else(too_short)?.to_vec())
+                .map_err(|e| DNACryptoError::DecodingFailed(e.to_string()))?;
+            pos += key_len;
+
+            let val_len = *frame.get(pos).ok_or_else(too_short)? as usize;
+            pos += 1;
+            let value = String::from_utf8(frame.get(pos..pos + val_len).ok_or_else(too_short)?.to_vec())
+                .map_err(|e| DNACryptoError::DecodingFailed(e.to_string()))?;
+            pos += val_len;
+
+            headers.push((key, value));
+        }
+
+        let body_len_bytes = frame.get(pos..pos + 4).ok_or_else(too_short)?;
+        let body_len = u32::from_be_bytes(body_len_bytes.try_into().expect("checked 4 bytes")) as usize;
+        pos += 4;
+        let body = frame.get(pos..pos + body_len).ok_or_else(too_short)?.to_vec();
+
+        Ok((label, headers, body))
+    }
+
+    /// 2-bit/base mapping over raw bytes (no printable-ASCII restriction,
+    /// unlike [`crate::dna::basic::DNACrypto::binary_to_text`], since
+    /// OpenPGP packets are arbitrary binary, not text).
+    fn bytes_to_dna(bytes: &[u8]) -> String {
+        let mut dna = String::with_capacity(bytes.len() * 4);
+        for &byte in bytes {
+            for shift in [6, 4, 2, 0] {
+                let bits = (byte >> shift) & 0b11;
+                let base = Self::DNA_ENCODE.iter().find(|(b, _)| *b == bits).unwrap().1;
+                dna.push_str(base);
+            }
+        }
+        dna
+    }
+
+    /// Inverse of [`Self::bytes_to_dna`].
+    fn dna_to_bytes(dna: &str) -> Result<Vec<u8>> {
+        let bits: Vec<u8> = dna
+            .chars()
+            .map(|c| match c.to_ascii_uppercase() {
+                'A' => Ok(0b00),
+                'T' => Ok(0b01),
+                'C' => Ok(0b10),
+                'G' => Ok(0b11),
+                other => Err(DNACryptoError::InvalidSequence(format!("Invalid base: {}", other))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if bits.len() % 4 != 0 {
+            return Err(DNACryptoError::InvalidSequence(
+                "DNA sequence length is not a multiple of 4 bases".to_string(),
+            )
+            .into());
+        }
+
+        Ok(bits
+            .chunks(4)
+            .map(|chunk| (chunk[0] << 6) | (chunk[1] << 4) | (chunk[2] << 2) | chunk[3])
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_armor() -> String {
+        let body = b"not real PGP packet bytes, just round-trip payload".to_vec();
+        OpenPgpDNACrypto::build_armor(
+            "PGP MESSAGE",
+            &[("Version".to_string(), "BioCypher-Test 1.0".to_string())],
+            &body,
+        )
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip_byte_identical() {
+        let armor = sample_armor();
+        let dna = OpenPgpDNACrypto::encode_message(&armor).unwrap();
+        assert!(dna.starts_with(markers::START_MARKER));
+        assert!(dna.ends_with(markers::STOP_MARKER));
+        let recovered = OpenPgpDNACrypto::decode_sequence(&dna).unwrap();
+        assert_eq!(armor, recovered);
+    }
+
+    #[test]
+    fn test_encode_decode_empty() {
+        assert_eq!(OpenPgpDNACrypto::encode_message("").unwrap(), "");
+        assert_eq!(OpenPgpDNACrypto::decode_sequence("").unwrap(), "");
+    }
+
+    #[test]
+    fn test_no_headers_roundtrip() {
+        let armor = OpenPgpDNACrypto::build_armor("PGP MESSAGE", &[], b"abc");
+        let dna = OpenPgpDNACrypto::encode_message(&armor).unwrap();
+        let recovered = OpenPgpDNACrypto::decode_sequence(&dna).unwrap();
+        assert_eq!(armor, recovered);
+    }
+
+    #[test]
+    fn test_multiline_body_roundtrip() {
+        let body = vec![0xABu8; 200];
+        let armor = OpenPgpDNACrypto::build_armor("PGP MESSAGE", &[], &body);
+        assert!(armor.lines().count() > 5);
+        let dna = OpenPgpDNACrypto::encode_message(&armor).unwrap();
+        let recovered = OpenPgpDNACrypto::decode_sequence(&dna).unwrap();
+        assert_eq!(armor, recovered);
+    }
+
+    #[test]
+    fn test_missing_armor_header_rejected() {
+        let result = OpenPgpDNACrypto::encode_message("not an armored block");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_tampered_checksum_rejected() {
+        let mut armor = sample_armor();
+        let checksum_line_start = armor.rfind("\n=").unwrap() + 1;
+        armor.replace_range(checksum_line_start + 1..checksum_line_start + 2, "9");
+        let result = OpenPgpDNACrypto::encode_message(&armor);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_crc24_known_vector() {
+        // RFC 4880 example: CRC24 of an empty input is the init constant folded through no rounds
+        assert_eq!(OpenPgpDNACrypto::crc24(b""), OpenPgpDNACrypto::CRC24_INIT & 0x00FF_FFFF);
+    }
+}
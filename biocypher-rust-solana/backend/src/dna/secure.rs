@@ -4,22 +4,197 @@
 //! Ported from Python: biocypher/secure_nanopore_dna_crypto.py
 
 use crate::dna::basic::DNACrypto;
+use crate::dna::der;
 use crate::dna::markers;
-use crate::dna::traits::{DNACoder, SequenceStats, SequenceStatistics};
-use crate::error::{DNACryptoError, Result};
+use crate::dna::traits::{DNACoder, DnaDecoder, DnaEncoder, SequenceStats, SequenceStatistics};
+use crate::error::{BioCypherError, DNACryptoError, Result};
 use aes::Aes256;
+use aes_gcm::aead::Aead;
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use argon2::{Algorithm, Argon2, Params as Argon2Params, Version as Argon2Version};
 use cbc::{Decryptor, Encryptor};
 use cbc::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit};
 use cbc::cipher::block_padding::Pkcs7;
+use chacha20poly1305::{ChaCha20Poly1305, Nonce as ChaChaNonce};
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use flate2::read::{GzDecoder, GzEncoder};
+use flate2::Compression as GzLevel;
+use hkdf::Hkdf;
 use pbkdf2::pbkdf2_hmac;
 use rand::RngCore;
-use sha2::Sha256;
+use sha2::{Sha256, Sha512};
+use solana_sdk::pubkey::Pubkey;
+use std::io::Read;
+use x25519_dalek::{EphemeralSecret, PublicKey as X25519PublicKey, StaticSecret};
 
 type Aes256CbcEnc = Encryptor<Aes256>;
 type Aes256CbcDec = Decryptor<Aes256>;
 
-/// Secure DNA cryptography with AES-256-CBC encryption
+/// Selects the symmetric cipher used by the Secure mode envelope.
+///
+/// `CbcLegacy` is kept so sequences encoded before GCM support still decode;
+/// new encodes always use `Gcm`, which authenticates the ciphertext, mode
+/// byte and markers so a flipped base or swapped marker is detected rather
+/// than silently corrupting the plaintext.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecureMode {
+    CbcLegacy,
+    Gcm,
+}
+
+impl SecureMode {
+    const CBC_TAG: u8 = 0;
+    const GCM_TAG: u8 = 1;
+
+    fn to_tag(self) -> u8 {
+        match self {
+            SecureMode::CbcLegacy => Self::CBC_TAG,
+            SecureMode::Gcm => Self::GCM_TAG,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Result<Self> {
+        match tag {
+            Self::CBC_TAG => Ok(SecureMode::CbcLegacy),
+            Self::GCM_TAG => Ok(SecureMode::Gcm),
+            _ => Err(DNACryptoError::DecryptionError(format!("Unknown secure mode tag: {}", tag)).into()),
+        }
+    }
+}
+
+/// Selects the AEAD cipher backing an authenticated ([`SecureMode::Gcm`])
+/// envelope. Both use a 32-byte key, a 12-byte nonce and produce a 16-byte
+/// authentication tag; `ChaCha20Poly1305` gives software-only deployments a
+/// fast constant-time alternative that doesn't depend on AES hardware
+/// acceleration. Irrelevant for [`SecureMode::CbcLegacy`], which always
+/// uses AES-256-CBC regardless of this choice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Cipher {
+    AesGcm,
+    ChaCha20Poly1305,
+}
+
+impl Cipher {
+    const AES_GCM_TAG: u8 = 0;
+    const CHACHA20POLY1305_TAG: u8 = 1;
+
+    fn to_tag(self) -> u8 {
+        match self {
+            Cipher::AesGcm => Self::AES_GCM_TAG,
+            Cipher::ChaCha20Poly1305 => Self::CHACHA20POLY1305_TAG,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Result<Self> {
+        match tag {
+            Self::AES_GCM_TAG => Ok(Cipher::AesGcm),
+            Self::CHACHA20POLY1305_TAG => Ok(Cipher::ChaCha20Poly1305),
+            _ => Err(DNACryptoError::DecryptionError(format!("Unknown cipher tag: {}", tag)).into()),
+        }
+    }
+}
+
+/// Selects the compression applied to the plaintext before it is encrypted.
+///
+/// Compressing first keeps the post-encryption ciphertext (and therefore the
+/// encoded DNA sequence) as short as possible for natural-language inputs,
+/// while the ciphertext itself still looks random. `None` is recorded when
+/// compression does not meaningfully shrink the input, so highly compressed
+/// or already-random payloads aren't penalized with extra overhead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Compression {
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl Compression {
+    const NONE_TAG: u8 = 0;
+    const GZIP_TAG: u8 = 1;
+    const ZSTD_TAG: u8 = 2;
+
+    /// Minimum shrinkage (as a fraction of the original size) required before
+    /// a compressed form is preferred over storing the plaintext as-is.
+    const MIN_RATIO_GAIN: f64 = 0.01;
+
+    pub(crate) fn to_tag(self) -> u8 {
+        match self {
+            Compression::None => Self::NONE_TAG,
+            Compression::Gzip => Self::GZIP_TAG,
+            Compression::Zstd => Self::ZSTD_TAG,
+        }
+    }
+
+    pub(crate) fn from_tag(tag: u8) -> Result<Self> {
+        match tag {
+            Self::NONE_TAG => Ok(Compression::None),
+            Self::GZIP_TAG => Ok(Compression::Gzip),
+            Self::ZSTD_TAG => Ok(Compression::Zstd),
+            _ => Err(DNACryptoError::DecryptionError(format!("Unknown compression tag: {}", tag)).into()),
+        }
+    }
+}
+
+/// Selects the password-based key derivation function used to turn a
+/// password into the AES key, and the cost parameters it was run with.
+///
+/// `Pbkdf2` is kept so sequences encoded before Argon2id support still
+/// decode; new encodes default to `Argon2id`, which is far more resistant to
+/// GPU/ASIC brute-force than a fixed PBKDF2 iteration count. The parameters
+/// are recorded in the envelope (not hardcoded) so raising the defaults
+/// later never makes already-encoded DNA undecodable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kdf {
+    Pbkdf2 { iterations: u32 },
+    Argon2id { memory_kib: u32, time_cost: u32, parallelism: u32 },
+}
+
+impl Kdf {
+    const PBKDF2_TAG: u8 = 0;
+    const ARGON2ID_TAG: u8 = 1;
+
+    /// Builder for the legacy PBKDF2-HMAC-SHA256 KDF.
+    pub fn pbkdf2(iterations: u32) -> Self {
+        Kdf::Pbkdf2 { iterations }
+    }
+
+    /// Builder for Argon2id, letting callers tune cost to their hardware.
+    pub fn argon2id(memory_kib: u32, time_cost: u32, parallelism: u32) -> Self {
+        Kdf::Argon2id { memory_kib, time_cost, parallelism }
+    }
+
+    pub(crate) fn tag(self) -> u8 {
+        match self {
+            Kdf::Pbkdf2 { .. } => Self::PBKDF2_TAG,
+            Kdf::Argon2id { .. } => Self::ARGON2ID_TAG,
+        }
+    }
+
+    /// Parameters as (param1, param2, param3); unused slots are 0.
+    pub(crate) fn params(self) -> (u64, u64, u64) {
+        match self {
+            Kdf::Pbkdf2 { iterations } => (iterations as u64, 0, 0),
+            Kdf::Argon2id { memory_kib, time_cost, parallelism } => {
+                (time_cost as u64, memory_kib as u64, parallelism as u64)
+            }
+        }
+    }
+
+    pub(crate) fn from_parts(tag: u8, param1: u64, param2: u64, param3: u64) -> Result<Self> {
+        match tag {
+            Self::PBKDF2_TAG => Ok(Kdf::Pbkdf2 { iterations: param1 as u32 }),
+            Self::ARGON2ID_TAG => Ok(Kdf::Argon2id {
+                time_cost: param1 as u32,
+                memory_kib: param2 as u32,
+                parallelism: param3 as u32,
+            }),
+            _ => Err(DNACryptoError::DecryptionError(format!("Unknown KDF tag: {}", tag)).into()),
+        }
+    }
+}
+
+/// Secure DNA cryptography with AES-256-CBC (legacy) and AES-256-GCM encryption
 pub struct SecureDNACrypto;
 
 impl DNACoder for SecureDNACrypto {
@@ -38,32 +213,221 @@ impl SequenceStats for SecureDNACrypto {
     }
 }
 
+impl DnaEncoder for SecureDNACrypto {
+    type Output = String;
+    type Error = BioCypherError;
+
+    /// Secure mode needs a password, which this generic interface has no
+    /// room for; delegates to `DNACoder::encode_message`, which already
+    /// errors with `DNACryptoError::PasswordRequired`.
+    fn encode(&self, input: &[u8]) -> std::result::Result<String, BioCypherError> {
+        let message = std::str::from_utf8(input)
+            .map_err(|e| BioCypherError::Validation(format!("message is not valid UTF-8: {e}")))?;
+        Self::encode_message(message)
+    }
+}
+
+impl DnaDecoder for SecureDNACrypto {
+    type Output = String;
+    type Error = BioCypherError;
+
+    fn decode(&self, input: &[u8]) -> std::result::Result<String, BioCypherError> {
+        let sequence = std::str::from_utf8(input)
+            .map_err(|e| BioCypherError::Validation(format!("sequence is not valid UTF-8: {e}")))?;
+        Self::decode_sequence(sequence)
+    }
+}
+
 impl SecureDNACrypto {
     /// Cryptographic constants
     pub const KEY_SIZE: usize = 32;
     pub const IV_SIZE: usize = 16;
     pub const SALT_SIZE: usize = 16;
+    pub const GCM_NONCE_SIZE: usize = 12;
+    pub const GCM_TAG_SIZE: usize = 16;
     pub const PBKDF2_ITERATIONS: u32 = 100_000;
 
-    /// Encode message with password (AES-256-CBC + Basic DNA encoding + markers)
+    /// Default secure mode used for new encodes (authenticated).
+    const DEFAULT_MODE: SecureMode = SecureMode::Gcm;
+
+    /// Default KDF for new encodes: Argon2id with OWASP's baseline cost
+    /// (19 MiB memory, 2 passes, single-threaded).
+    const DEFAULT_KDF: Kdf = Kdf::Argon2id { memory_kib: 19_456, time_cost: 2, parallelism: 1 };
+
+    /// Default AEAD cipher for new encodes.
+    const DEFAULT_CIPHER: Cipher = Cipher::AesGcm;
+
+    /// Envelope version written by sequences encoded before Argon2id/KDF
+    /// parameters existed; decoded as PBKDF2 at the legacy fixed iteration count.
+    const ENVELOPE_VERSION_LEGACY_PBKDF2_ONLY: u64 = 1;
+
+    /// Current envelope version: adds the KDF id and its parameters.
+    const ENVELOPE_VERSION: u64 = 2;
+
+    /// Encode message with password (AES-256-GCM + Basic DNA encoding + markers)
     pub fn encode_with_password(message: &str, password: &str) -> Result<String> {
+        Self::encode_with_password_and_options(message, password, Self::DEFAULT_KDF, Self::DEFAULT_CIPHER)
+    }
+
+    /// Encode message with password, using an explicitly chosen KDF and its
+    /// cost parameters (see [`Kdf::pbkdf2`] / [`Kdf::argon2id`]).
+    pub fn encode_with_password_and_kdf(message: &str, password: &str, kdf: Kdf) -> Result<String> {
+        Self::encode_with_password_and_options(message, password, kdf, Self::DEFAULT_CIPHER)
+    }
+
+    /// Encode message with password, using an explicitly chosen AEAD cipher
+    /// backend and the default KDF (see [`Self::encode_with_password_and_options`]
+    /// for full control over both).
+    pub fn encode_with_password_and_cipher(message: &str, password: &str, cipher: Cipher) -> Result<String> {
+        Self::encode_with_password_and_options(message, password, Self::DEFAULT_KDF, cipher)
+    }
+
+    /// Like [`Self::encode_with_password_and_compression`], using the
+    /// default KDF. Convenience entry point for callers (e.g. the encode
+    /// API) that only want to choose the cipher and compression.
+    pub fn encode_with_password_cipher_and_compression(
+        message: &str,
+        password: &str,
+        cipher: Cipher,
+        compression: Compression,
+    ) -> Result<(String, usize, usize)> {
+        Self::encode_with_password_and_compression(message, password, Self::DEFAULT_KDF, cipher, compression)
+    }
+
+    /// Encode message with password, forcing `compression` instead of
+    /// letting [`Self::compress_with_guard`] pick automatically, and
+    /// reporting the raw and compressed payload sizes so a caller can see
+    /// the achieved ratio. Enforces [`Self::DEFAULT_MIN_ENTROPY_BITS`]; use
+    /// [`Self::encode_with_password_compression_and_entropy_floor`] to pick
+    /// a different floor (or `0.0` to opt out).
+    pub fn encode_with_password_and_compression(
+        message: &str,
+        password: &str,
+        kdf: Kdf,
+        cipher: Cipher,
+        compression: Compression,
+    ) -> Result<(String, usize, usize)> {
+        Self::encode_with_password_compression_and_entropy_floor(
+            message,
+            password,
+            kdf,
+            cipher,
+            compression,
+            Self::DEFAULT_MIN_ENTROPY_BITS,
+        )
+    }
+
+    /// Like [`Self::encode_with_password_and_compression`], but checking the
+    /// password against `min_entropy_bits` instead of
+    /// [`Self::DEFAULT_MIN_ENTROPY_BITS`]. Pass `0.0` for deployments that
+    /// enforce their own password policy upstream and want to skip this
+    /// crate's heuristic entirely.
+    pub fn encode_with_password_compression_and_entropy_floor(
+        message: &str,
+        password: &str,
+        kdf: Kdf,
+        cipher: Cipher,
+        compression: Compression,
+        min_entropy_bits: f64,
+    ) -> Result<(String, usize, usize)> {
+        if message.is_empty() {
+            return Ok((String::new(), 0, 0));
+        }
+        if password.is_empty() {
+            return Err(DNACryptoError::PasswordRequired.into());
+        }
+        Self::require_min_entropy(password, min_entropy_bits)?;
+
+        let raw_bytes = message.len();
+        let payload = Self::compress_with_choice(message.as_bytes(), compression)?;
+        let compressed_bytes = payload.len();
+
+        let (encrypted_data, iv, salt) =
+            Self::encrypt_message(&payload, password, Self::DEFAULT_MODE, cipher, compression, kdf)?;
+        let crypto_string = Self::crypto_data_to_string(
+            Self::DEFAULT_MODE,
+            compression,
+            kdf,
+            cipher,
+            &encrypted_data,
+            &iv,
+            &salt,
+        )?;
+        let dna_sequence = DNACrypto::encode_message(&crypto_string)?;
+        let result = format!(
+            "{}{}{}",
+            markers::START_MARKER,
+            dna_sequence,
+            markers::STOP_MARKER
+        );
+
+        Ok((result, raw_bytes, compressed_bytes))
+    }
+
+    /// Encode message with password, using an explicitly chosen KDF and AEAD
+    /// cipher backend (see [`Cipher::AesGcm`] / [`Cipher::ChaCha20Poly1305`]).
+    /// The cipher choice is recorded in the envelope so decode auto-selects
+    /// the right backend without the caller having to pass it back in.
+    /// Enforces [`Self::DEFAULT_MIN_ENTROPY_BITS`]; use
+    /// [`Self::encode_with_password_options_and_entropy_floor`] to pick a
+    /// different floor (or `0.0` to opt out).
+    pub fn encode_with_password_and_options(
+        message: &str,
+        password: &str,
+        kdf: Kdf,
+        cipher: Cipher,
+    ) -> Result<String> {
+        Self::encode_with_password_options_and_entropy_floor(
+            message,
+            password,
+            kdf,
+            cipher,
+            Self::DEFAULT_MIN_ENTROPY_BITS,
+        )
+    }
+
+    /// Like [`Self::encode_with_password_and_options`], but checking the
+    /// password against `min_entropy_bits` instead of
+    /// [`Self::DEFAULT_MIN_ENTROPY_BITS`]. Pass `0.0` for deployments that
+    /// enforce their own password policy upstream and want to skip this
+    /// crate's heuristic entirely.
+    pub fn encode_with_password_options_and_entropy_floor(
+        message: &str,
+        password: &str,
+        kdf: Kdf,
+        cipher: Cipher,
+        min_entropy_bits: f64,
+    ) -> Result<String> {
         if message.is_empty() {
             return Ok(String::new());
         }
         if password.is_empty() {
             return Err(DNACryptoError::PasswordRequired.into());
         }
+        Self::require_min_entropy(password, min_entropy_bits)?;
+
+        // Step 1: Compress the plaintext if it actually shrinks the payload
+        let (compression, payload) = Self::compress_with_guard(message.as_bytes());
 
-        // Step 1: Encrypt with AES-256-CBC
-        let (encrypted_data, iv, salt) = Self::encrypt_message(message, password)?;
+        // Step 2: Encrypt with the default authenticated mode
+        let (encrypted_data, iv, salt) =
+            Self::encrypt_message(&payload, password, Self::DEFAULT_MODE, cipher, compression, kdf)?;
 
-        // Step 2: Serialize to base64 string (length-prefixed format)
-        let crypto_string = Self::crypto_data_to_string(&encrypted_data, &iv, &salt)?;
+        // Step 3: Serialize to base64 string (cipher tag + mode/compression/KDF tags + length-prefixed fields)
+        let crypto_string = Self::crypto_data_to_string(
+            Self::DEFAULT_MODE,
+            compression,
+            kdf,
+            cipher,
+            &encrypted_data,
+            &iv,
+            &salt,
+        )?;
 
-        // Step 3: Encode to DNA using Basic mode
+        // Step 4: Encode to DNA using Basic mode
         let dna_sequence = DNACrypto::encode_message(&crypto_string)?;
 
-        // Step 4: Add markers
+        // Step 5: Add markers
         let result = format!(
             "{}{}{}",
             markers::START_MARKER,
@@ -92,118 +456,379 @@ impl SecureDNACrypto {
             return Ok(String::new());
         }
 
-        // Step 3: Parse crypto data
-        let (encrypted_data, iv, salt) = Self::string_to_crypto_data(&crypto_string)?;
+        // Step 3: Parse crypto data (the KDF, cipher and their params are reproduced from the envelope)
+        let (mode, compression, kdf, cipher, encrypted_data, iv, salt) =
+            Self::string_to_crypto_data(&crypto_string)?;
 
         // Step 4: Decrypt
-        let plaintext = Self::decrypt_message(&encrypted_data, &iv, &salt, password)?;
+        let payload = Self::decrypt_message(mode, compression, kdf, cipher, &encrypted_data, &iv, &salt, password)?;
 
-        Ok(plaintext)
+        // Step 5: Decompress (if the envelope says the payload was compressed)
+        let plaintext_bytes = Self::decompress(compression, &payload)?;
+        String::from_utf8(plaintext_bytes)
+            .map_err(|e| DNACryptoError::DecryptionError(e.to_string()).into())
     }
 
-    /// Derive key from password using PBKDF2
-    fn derive_key(password: &str, salt: &[u8]) -> [u8; Self::KEY_SIZE] {
-        let mut key = [0u8; Self::KEY_SIZE];
-        pbkdf2_hmac::<Sha256>(
-            password.as_bytes(),
-            salt,
-            Self::PBKDF2_ITERATIONS,
-            &mut key,
-        );
-        key
+    /// Compress `data`, keeping the compressed form only if it shrinks the
+    /// payload by at least [`Compression::MIN_RATIO_GAIN`]; otherwise stores
+    /// the data uncompressed.
+    fn compress_with_guard(data: &[u8]) -> (Compression, Vec<u8>) {
+        let mut best = (Compression::None, data.to_vec());
+
+        if let Ok(gzip) = Self::gzip_compress(data) {
+            if Self::is_meaningfully_smaller(data.len(), gzip.len()) && gzip.len() < best.1.len() {
+                best = (Compression::Gzip, gzip);
+            }
+        }
+        if let Ok(zstd) = zstd::stream::encode_all(data, 0) {
+            if Self::is_meaningfully_smaller(data.len(), zstd.len()) && zstd.len() < best.1.len() {
+                best = (Compression::Zstd, zstd);
+            }
+        }
+
+        best
+    }
+
+    /// Compress `data` under an explicitly chosen algorithm, with no size
+    /// guard — unlike [`Self::compress_with_guard`], the caller's choice is
+    /// always honored even if it doesn't shrink the payload.
+    pub(crate) fn compress_with_choice(data: &[u8], compression: Compression) -> Result<Vec<u8>> {
+        match compression {
+            Compression::None => Ok(data.to_vec()),
+            Compression::Gzip => Self::gzip_compress(data),
+            Compression::Zstd => zstd::stream::encode_all(data, 0)
+                .map_err(|e| DNACryptoError::EncryptionError(format!("Zstd compression failed: {}", e)).into()),
+        }
+    }
+
+    fn is_meaningfully_smaller(original_len: usize, compressed_len: usize) -> bool {
+        if original_len == 0 {
+            return false;
+        }
+        let gain = 1.0 - (compressed_len as f64 / original_len as f64);
+        gain >= Compression::MIN_RATIO_GAIN
+    }
+
+    pub(crate) fn gzip_compress(data: &[u8]) -> Result<Vec<u8>> {
+        let mut encoder = GzEncoder::new(data, GzLevel::default());
+        let mut out = Vec::new();
+        encoder
+            .read_to_end(&mut out)
+            .map_err(|e| DNACryptoError::EncryptionError(format!("Gzip compression failed: {}", e)))?;
+        Ok(out)
+    }
+
+    /// Decompress `payload` according to the algorithm recorded in the envelope.
+    pub(crate) fn decompress(compression: Compression, payload: &[u8]) -> Result<Vec<u8>> {
+        match compression {
+            Compression::None => Ok(payload.to_vec()),
+            Compression::Gzip => {
+                let mut decoder = GzDecoder::new(payload);
+                let mut out = Vec::new();
+                decoder
+                    .read_to_end(&mut out)
+                    .map_err(|e| DNACryptoError::DecryptionError(format!("Gzip decompression failed: {}", e)))?;
+                Ok(out)
+            }
+            Compression::Zstd => zstd::stream::decode_all(payload)
+                .map_err(|e| DNACryptoError::DecryptionError(format!("Zstd decompression failed: {}", e)).into()),
+        }
+    }
+
+    /// Derive a key from password and salt under the given KDF.
+    fn derive_key(password: &str, salt: &[u8], kdf: Kdf) -> Result<[u8; Self::KEY_SIZE]> {
+        match kdf {
+            Kdf::Pbkdf2 { iterations } => {
+                let mut key = [0u8; Self::KEY_SIZE];
+                pbkdf2_hmac::<Sha256>(password.as_bytes(), salt, iterations, &mut key);
+                Ok(key)
+            }
+            Kdf::Argon2id { memory_kib, time_cost, parallelism } => {
+                let params = Argon2Params::new(memory_kib, time_cost, parallelism, Some(Self::KEY_SIZE))
+                    .map_err(|e| DNACryptoError::EncryptionError(format!("Invalid Argon2id parameters: {}", e)))?;
+                let argon2 = Argon2::new(Algorithm::Argon2id, Argon2Version::V0x13, params);
+                let mut key = [0u8; Self::KEY_SIZE];
+                argon2
+                    .hash_password_into(password.as_bytes(), salt, &mut key)
+                    .map_err(|e| DNACryptoError::EncryptionError(format!("Argon2id derivation failed: {}", e)))?;
+                Ok(key)
+            }
+        }
+    }
+
+    /// Additional authenticated data for an AEAD-sealed envelope: binds the
+    /// mode byte, compression byte and cipher byte, plus the markers, so a
+    /// downgraded mode, a swapped compression algorithm or cipher, or a
+    /// substituted START/STOP marker is detected.
+    fn aead_aad(mode: SecureMode, compression: Compression, cipher: Cipher) -> Vec<u8> {
+        let mut aad = vec![mode.to_tag(), compression.to_tag(), cipher.to_tag()];
+        aad.extend_from_slice(markers::START_MARKER.as_bytes());
+        aad.extend_from_slice(markers::STOP_MARKER.as_bytes());
+        aad
     }
 
-    /// Encrypt message, returns (ciphertext, iv, salt)
+    /// Encrypt a (possibly pre-compressed) payload, returns (ciphertext[+tag for GCM], iv/nonce, salt)
     fn encrypt_message(
-        plaintext: &str,
+        payload: &[u8],
         password: &str,
-    ) -> Result<(Vec<u8>, [u8; Self::IV_SIZE], [u8; Self::SALT_SIZE])> {
+        mode: SecureMode,
+        cipher: Cipher,
+        compression: Compression,
+        kdf: Kdf,
+    ) -> Result<(Vec<u8>, Vec<u8>, [u8; Self::SALT_SIZE])> {
         let mut salt = [0u8; Self::SALT_SIZE];
-        let mut iv = [0u8; Self::IV_SIZE];
         rand::thread_rng().fill_bytes(&mut salt);
-        rand::thread_rng().fill_bytes(&mut iv);
-
-        let key = Self::derive_key(password, &salt);
+        let key = Self::derive_key(password, &salt, kdf)?;
 
-        let plaintext_bytes = plaintext.as_bytes();
-        let cipher = Aes256CbcEnc::new_from_slices(&key, &iv)
-            .map_err(|e| DNACryptoError::EncryptionError(e.to_string()))?;
-        let ciphertext = cipher.encrypt_padded_vec_mut::<Pkcs7>(plaintext_bytes);
-
-        Ok((ciphertext, iv, salt))
+        match mode {
+            SecureMode::CbcLegacy => {
+                let mut iv = [0u8; Self::IV_SIZE];
+                rand::thread_rng().fill_bytes(&mut iv);
+                let cbc = Aes256CbcEnc::new_from_slices(&key, &iv)
+                    .map_err(|e| DNACryptoError::EncryptionError(e.to_string()))?;
+                let ciphertext = cbc.encrypt_padded_vec_mut::<Pkcs7>(payload);
+                Ok((ciphertext, iv.to_vec(), salt))
+            }
+            SecureMode::Gcm => {
+                let mut nonce_bytes = [0u8; Self::GCM_NONCE_SIZE];
+                rand::thread_rng().fill_bytes(&mut nonce_bytes);
+                let aad = Self::aead_aad(mode, compression, cipher);
+                let payload_with_aad = aes_gcm::aead::Payload { msg: payload, aad: &aad };
+                let ciphertext = match cipher {
+                    Cipher::AesGcm => {
+                        let nonce = Nonce::from_slice(&nonce_bytes);
+                        let aead = Aes256Gcm::new_from_slice(&key)
+                            .map_err(|e| DNACryptoError::EncryptionError(e.to_string()))?;
+                        aead.encrypt(nonce, payload_with_aad)
+                            .map_err(|e| DNACryptoError::EncryptionError(e.to_string()))?
+                    }
+                    Cipher::ChaCha20Poly1305 => {
+                        let nonce = ChaChaNonce::from_slice(&nonce_bytes);
+                        let aead = ChaCha20Poly1305::new_from_slice(&key)
+                            .map_err(|e| DNACryptoError::EncryptionError(e.to_string()))?;
+                        aead.encrypt(nonce, payload_with_aad)
+                            .map_err(|e| DNACryptoError::EncryptionError(e.to_string()))?
+                    }
+                };
+                Ok((ciphertext, nonce_bytes.to_vec(), salt))
+            }
+        }
     }
 
-    /// Decrypt message
+    /// Decrypt under the mode and cipher recorded in the envelope, returns
+    /// the (still possibly compressed) payload bytes
     fn decrypt_message(
+        mode: SecureMode,
+        compression: Compression,
+        kdf: Kdf,
+        cipher: Cipher,
         ciphertext: &[u8],
         iv: &[u8],
         salt: &[u8],
         password: &str,
-    ) -> Result<String> {
-        let key = Self::derive_key(password, salt);
+    ) -> Result<Vec<u8>> {
+        let key = Self::derive_key(password, salt, kdf)?;
 
-        let cipher = Aes256CbcDec::new_from_slices(&key, iv)
-            .map_err(|e| DNACryptoError::DecryptionError(e.to_string()))?;
-        let decrypted = cipher
-            .decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
-            .map_err(|e| DNACryptoError::DecryptionError(e.to_string()))?;
+        match mode {
+            SecureMode::CbcLegacy => {
+                let cbc = Aes256CbcDec::new_from_slices(&key, iv)
+                    .map_err(|e| DNACryptoError::DecryptionError(e.to_string()))?;
+                cbc.decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
+                    .map_err(|e| DNACryptoError::DecryptionError(e.to_string()).into())
+            }
+            SecureMode::Gcm => {
+                let aad = Self::aead_aad(mode, compression, cipher);
+                let payload_with_aad = aes_gcm::aead::Payload { msg: ciphertext, aad: &aad };
+                match cipher {
+                    Cipher::AesGcm => {
+                        let nonce = Nonce::from_slice(iv);
+                        let aead = Aes256Gcm::new_from_slice(&key)
+                            .map_err(|e| DNACryptoError::DecryptionError(e.to_string()))?;
+                        aead.decrypt(nonce, payload_with_aad)
+                            .map_err(|_| DNACryptoError::AuthenticationFailed.into())
+                    }
+                    Cipher::ChaCha20Poly1305 => {
+                        let nonce = ChaChaNonce::from_slice(iv);
+                        let aead = ChaCha20Poly1305::new_from_slice(&key)
+                            .map_err(|e| DNACryptoError::DecryptionError(e.to_string()))?;
+                        aead.decrypt(nonce, payload_with_aad)
+                            .map_err(|_| DNACryptoError::AuthenticationFailed.into())
+                    }
+                }
+            }
+        }
+    }
 
-        String::from_utf8(decrypted)
-            .map_err(|e| DNACryptoError::DecryptionError(e.to_string()).into())
+    /// Domain-separation AAD for [`Self::encrypt_with_key`]/[`Self::decrypt_with_key`],
+    /// binding the cipher tag so a split-key ciphertext can't be replayed
+    /// against the wrong AEAD backend.
+    fn split_key_aad(cipher: Cipher) -> Vec<u8> {
+        let mut aad = b"biocypher-split-key".to_vec();
+        aad.push(cipher.to_tag());
+        aad
+    }
+
+    /// Encrypt `plaintext` directly under a raw 32-byte key and 12-byte
+    /// nonce, with no password or KDF involved. Used by split-key and
+    /// Shamir-share modes, where the key is generated at random and handed
+    /// out in pieces rather than derived from a password.
+    pub(crate) fn encrypt_with_key(
+        plaintext: &[u8],
+        key: &[u8; Self::KEY_SIZE],
+        nonce_bytes: &[u8; Self::GCM_NONCE_SIZE],
+        cipher: Cipher,
+    ) -> Result<Vec<u8>> {
+        let aad = Self::split_key_aad(cipher);
+        let payload = aes_gcm::aead::Payload { msg: plaintext, aad: &aad };
+        match cipher {
+            Cipher::AesGcm => {
+                let nonce = Nonce::from_slice(nonce_bytes);
+                let aead = Aes256Gcm::new_from_slice(key)
+                    .map_err(|e| DNACryptoError::EncryptionError(e.to_string()))?;
+                aead.encrypt(nonce, payload)
+                    .map_err(|e| DNACryptoError::EncryptionError(e.to_string()).into())
+            }
+            Cipher::ChaCha20Poly1305 => {
+                let nonce = ChaChaNonce::from_slice(nonce_bytes);
+                let aead = ChaCha20Poly1305::new_from_slice(key)
+                    .map_err(|e| DNACryptoError::EncryptionError(e.to_string()))?;
+                aead.encrypt(nonce, payload)
+                    .map_err(|e| DNACryptoError::EncryptionError(e.to_string()).into())
+            }
+        }
+    }
+
+    /// Decrypt ciphertext produced by [`Self::encrypt_with_key`] under the
+    /// same raw key, nonce and cipher.
+    pub(crate) fn decrypt_with_key(
+        ciphertext: &[u8],
+        key: &[u8; Self::KEY_SIZE],
+        nonce_bytes: &[u8; Self::GCM_NONCE_SIZE],
+        cipher: Cipher,
+    ) -> Result<Vec<u8>> {
+        let aad = Self::split_key_aad(cipher);
+        let payload = aes_gcm::aead::Payload { msg: ciphertext, aad: &aad };
+        match cipher {
+            Cipher::AesGcm => {
+                let nonce = Nonce::from_slice(nonce_bytes);
+                let aead = Aes256Gcm::new_from_slice(key)
+                    .map_err(|e| DNACryptoError::DecryptionError(e.to_string()))?;
+                aead.decrypt(nonce, payload)
+                    .map_err(|_| DNACryptoError::AuthenticationFailed.into())
+            }
+            Cipher::ChaCha20Poly1305 => {
+                let nonce = ChaChaNonce::from_slice(nonce_bytes);
+                let aead = ChaCha20Poly1305::new_from_slice(key)
+                    .map_err(|e| DNACryptoError::DecryptionError(e.to_string()))?;
+                aead.decrypt(nonce, payload)
+                    .map_err(|_| DNACryptoError::AuthenticationFailed.into())
+            }
+        }
     }
 
-    /// Serialize crypto data to base64 string
+    /// Serialize crypto data to a base64-encoded blob: a one-byte cipher tag
+    /// (outside the DER envelope, so decode can pick the AEAD backend before
+    /// parsing anything else) followed by the DER/TLV envelope: SEQUENCE {
+    /// INTEGER version, INTEGER mode, INTEGER compression, INTEGER kdf id,
+    /// INTEGER kdf param 1/2/3, OCTET STRING salt, OCTET STRING iv/nonce,
+    /// OCTET STRING auth tag, OCTET STRING ciphertext }. The GCM/ChaCha20
+    /// auth tag (appended by the AEAD cipher to the end of its output) is
+    /// split into its own field; CBC mode has no tag, so that field is an
+    /// empty OCTET STRING.
     fn crypto_data_to_string(
+        mode: SecureMode,
+        compression: Compression,
+        kdf: Kdf,
+        cipher: Cipher,
         encrypted_data: &[u8],
         iv: &[u8],
         salt: &[u8],
     ) -> Result<String> {
-        let mut combined = Vec::new();
-        combined.extend_from_slice(&(salt.len() as u16).to_be_bytes());
-        combined.extend_from_slice(salt);
-        combined.extend_from_slice(&(iv.len() as u16).to_be_bytes());
-        combined.extend_from_slice(iv);
-        combined.extend_from_slice(&(encrypted_data.len() as u32).to_be_bytes());
-        combined.extend_from_slice(encrypted_data);
-        Ok(BASE64.encode(&combined))
-    }
-
-    /// Parse base64 string to crypto data
-    fn string_to_crypto_data(crypto_string: &str) -> Result<(Vec<u8>, Vec<u8>, Vec<u8>)> {
-        let combined = BASE64
+        let (ciphertext, auth_tag): (&[u8], &[u8]) = match mode {
+            SecureMode::Gcm => {
+                if encrypted_data.len() < Self::GCM_TAG_SIZE {
+                    return Err(DNACryptoError::EncryptionError(
+                        "GCM ciphertext shorter than auth tag".to_string(),
+                    )
+                    .into());
+                }
+                encrypted_data.split_at(encrypted_data.len() - Self::GCM_TAG_SIZE)
+            }
+            SecureMode::CbcLegacy => (encrypted_data, &[]),
+        };
+
+        let (kdf_param1, kdf_param2, kdf_param3) = kdf.params();
+        let der_bytes = der::encode_sequence(&[
+            der::encode_integer(Self::ENVELOPE_VERSION),
+            der::encode_integer(mode.to_tag() as u64),
+            der::encode_integer(compression.to_tag() as u64),
+            der::encode_integer(kdf.tag() as u64),
+            der::encode_integer(kdf_param1),
+            der::encode_integer(kdf_param2),
+            der::encode_integer(kdf_param3),
+            der::encode_octet_string(salt),
+            der::encode_octet_string(iv),
+            der::encode_octet_string(auth_tag),
+            der::encode_octet_string(ciphertext),
+        ]);
+
+        let mut blob = Vec::with_capacity(1 + der_bytes.len());
+        blob.push(cipher.to_tag());
+        blob.extend_from_slice(&der_bytes);
+
+        Ok(BASE64.encode(&blob))
+    }
+
+    /// Parse a base64-encoded blob produced by [`Self::crypto_data_to_string`],
+    /// returning the cipher, mode, compression and KDF (with its original
+    /// parameters) it was sealed under, and the ciphertext with its auth tag
+    /// (if any) reassembled in encryption order. Envelopes written before the
+    /// KDF fields existed (version 1) are read back as PBKDF2 at the legacy
+    /// fixed iteration count, so they remain decodable after the defaults
+    /// changed.
+    fn string_to_crypto_data(
+        crypto_string: &str,
+    ) -> Result<(SecureMode, Compression, Kdf, Cipher, Vec<u8>, Vec<u8>, Vec<u8>)> {
+        let blob = BASE64
             .decode(crypto_string.as_bytes())
             .map_err(|e| DNACryptoError::DecryptionError(format!("Invalid base64: {}", e)))?;
 
-        if combined.len() < 2 {
-            return Err(DNACryptoError::DecryptionError("Invalid crypto data".to_string()).into());
-        }
+        let (cipher_tag, der_bytes) = blob
+            .split_first()
+            .ok_or_else(|| DNACryptoError::DecryptionError("Empty crypto blob".to_string()))?;
+        let cipher = Cipher::from_tag(*cipher_tag)?;
 
-        let salt_len = u16::from_be_bytes([combined[0], combined[1]]) as usize;
-        if combined.len() < 2 + salt_len {
-            return Err(DNACryptoError::DecryptionError("Invalid salt length".to_string()).into());
-        }
-        let salt = combined[2..2 + salt_len].to_vec();
+        let mut envelope = der::TlvReader::new(der_bytes).read_sequence()?;
 
-        let iv_start = 2 + salt_len;
-        if combined.len() < iv_start + 2 {
-            return Err(DNACryptoError::DecryptionError("Invalid IV length".to_string()).into());
-        }
-        let iv_len = u16::from_be_bytes([combined[iv_start], combined[iv_start + 1]]) as usize;
-        let iv = combined[iv_start + 2..iv_start + 2 + iv_len].to_vec();
+        let version = envelope.read_integer()?;
+        let mode = SecureMode::from_tag(envelope.read_integer()? as u8)?;
+        let compression = Compression::from_tag(envelope.read_integer()? as u8)?;
 
-        let data_start = iv_start + 2 + iv_len;
-        if combined.len() < data_start + 4 {
-            return Err(DNACryptoError::DecryptionError("Invalid data length".to_string()).into());
-        }
-        let data_len = u32::from_be_bytes([
-            combined[data_start],
-            combined[data_start + 1],
-            combined[data_start + 2],
-            combined[data_start + 3],
-        ]) as usize;
-        let encrypted_data = combined[data_start + 4..data_start + 4 + data_len].to_vec();
+        let kdf = match version {
+            Self::ENVELOPE_VERSION_LEGACY_PBKDF2_ONLY => {
+                Kdf::Pbkdf2 { iterations: Self::PBKDF2_ITERATIONS }
+            }
+            Self::ENVELOPE_VERSION => {
+                let kdf_tag = envelope.read_integer()? as u8;
+                let param1 = envelope.read_integer()?;
+                let param2 = envelope.read_integer()?;
+                let param3 = envelope.read_integer()?;
+                Kdf::from_parts(kdf_tag, param1, param2, param3)?
+            }
+            other => {
+                return Err(
+                    DNACryptoError::DecryptionError(format!("Unsupported envelope version: {}", other)).into(),
+                )
+            }
+        };
 
-        Ok((encrypted_data, iv, salt))
+        let salt = envelope.read_octet_string()?;
+        let iv = envelope.read_octet_string()?;
+        let auth_tag = envelope.read_octet_string()?;
+        let mut encrypted_data = envelope.read_octet_string()?;
+        encrypted_data.extend_from_slice(&auth_tag);
+
+        Ok((mode, compression, kdf, cipher, encrypted_data, iv, salt))
     }
 
     /// Remove start/stop markers
@@ -236,6 +861,259 @@ impl SecureDNACrypto {
         let valid = issues.is_empty();
         (valid, issues)
     }
+
+    /// Default floor for [`Self::require_min_entropy`], following current
+    /// best practice for the security level a PBKDF2-derived key should have.
+    pub const DEFAULT_MIN_ENTROPY_BITS: f64 = 128.0;
+
+    /// A small sample of the most common leaked passwords, checked as a
+    /// substring match so minor variations ("Password1!") are still caught.
+    const COMMON_PASSWORDS: &'static [&'static str] = &[
+        "password", "123456", "12345678", "qwerty", "letmein", "admin",
+        "welcome", "monkey", "dragon", "iloveyou", "trustno1", "abc123",
+    ];
+
+    /// Estimate the entropy of `password` in bits, along with any issues
+    /// found that reduced the estimate.
+    ///
+    /// Entropy is estimated as `length * log2(effective charset size)`, with
+    /// penalties applied for repeated-character runs, short sequential runs
+    /// (e.g. "abc", "123") and matches against a small common-password list.
+    /// This is a heuristic, not a formal guarantee, but it rejects
+    /// weak-but-varied passwords (like "Aa1!Aa1!") that pure character-class
+    /// checks let through, while accepting long, unpunctuated passphrases.
+    pub fn estimate_password_entropy(password: &str) -> (f64, Vec<String>) {
+        let mut issues = Vec::new();
+
+        if password.is_empty() {
+            issues.push("Password is empty".to_string());
+            return (0.0, issues);
+        }
+
+        let lower = password.to_lowercase();
+        for common in Self::COMMON_PASSWORDS {
+            if lower.contains(common) {
+                issues.push(format!("Password contains a common password: {}", common));
+                return (10.0, issues);
+            }
+        }
+
+        let mut charset_size: f64 = 0.0;
+        if password.chars().any(|c| c.is_lowercase()) {
+            charset_size += 26.0;
+        }
+        if password.chars().any(|c| c.is_uppercase()) {
+            charset_size += 26.0;
+        }
+        if password.chars().any(|c| c.is_ascii_digit()) {
+            charset_size += 10.0;
+        }
+        if password.chars().any(|c| !c.is_alphanumeric()) {
+            charset_size += 32.0;
+        }
+        if charset_size == 0.0 {
+            charset_size = 1.0;
+        }
+
+        let mut bits = password.chars().count() as f64 * charset_size.log2();
+
+        let repeat_penalty = Self::repeated_run_penalty(password);
+        if repeat_penalty > 0.0 {
+            issues.push("Password contains repeated-character runs".to_string());
+            bits -= repeat_penalty;
+        }
+
+        let sequence_penalty = Self::sequential_run_penalty(password);
+        if sequence_penalty > 0.0 {
+            issues.push("Password contains sequential runs (e.g. abc, 123)".to_string());
+            bits -= sequence_penalty;
+        }
+
+        (bits.max(0.0), issues)
+    }
+
+    /// Bits to subtract for runs of 3+ identical characters (e.g. "aaa").
+    fn repeated_run_penalty(password: &str) -> f64 {
+        let chars: Vec<char> = password.chars().collect();
+        let mut penalty = 0.0;
+        let mut run_len = 1;
+        for i in 1..chars.len() {
+            if chars[i] == chars[i - 1] {
+                run_len += 1;
+                if run_len >= 3 {
+                    penalty += 4.0;
+                }
+            } else {
+                run_len = 1;
+            }
+        }
+        penalty
+    }
+
+    /// Bits to subtract for runs of 3+ sequential characters, ascending or
+    /// descending (e.g. "abc", "cba", "123", "321").
+    fn sequential_run_penalty(password: &str) -> f64 {
+        let chars: Vec<u32> = password.chars().map(|c| c as u32).collect();
+        let mut penalty = 0.0;
+        let mut run_len = 1;
+        for i in 1..chars.len() {
+            let diff = chars[i] as i64 - chars[i - 1] as i64;
+            if diff == 1 || diff == -1 {
+                run_len += 1;
+                if run_len >= 3 {
+                    penalty += 4.0;
+                }
+            } else {
+                run_len = 1;
+            }
+        }
+        penalty
+    }
+
+    /// Guard that rejects deriving a key from a password estimated below
+    /// `min_bits` of entropy, instead of silently accepting any non-empty
+    /// string. Deployments with a stricter policy than
+    /// [`Self::DEFAULT_MIN_ENTROPY_BITS`] can pass their own floor.
+    pub fn require_min_entropy(password: &str, min_bits: f64) -> Result<()> {
+        let (bits, issues) = Self::estimate_password_entropy(password);
+        if bits < min_bits {
+            return Err(DNACryptoError::PasswordWeak(format!(
+                "Password has ~{:.1} bits of entropy, below the required {:.1}: {}",
+                bits,
+                min_bits,
+                issues.join("; ")
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    /// HKDF info string binding the ECIES key derivation to this scheme.
+    const ECIES_INFO: &'static [u8] = b"biocypher-ecies-aes256gcm";
+
+    /// Encode message to DNA, encrypted so only `recipient` can decode it.
+    ///
+    /// Uses ECIES: an ephemeral X25519 key is Diffie-Hellman'd against the
+    /// recipient's ed25519 Solana public key (converted to its X25519
+    /// birational equivalent), and the shared secret is expanded via
+    /// HKDF-SHA256 into an AES-256-GCM key. No password or pre-shared secret
+    /// is required; only the holder of `recipient`'s secret key can decrypt.
+    pub fn encode_to_recipient(message: &str, recipient: &Pubkey) -> Result<String> {
+        if message.is_empty() {
+            return Ok(String::new());
+        }
+
+        let recipient_x25519 = Self::ed25519_pubkey_to_x25519(recipient)?;
+
+        let ephemeral_secret = EphemeralSecret::random_from_rng(rand::thread_rng());
+        let ephemeral_public = X25519PublicKey::from(&ephemeral_secret);
+        let shared_secret = ephemeral_secret.diffie_hellman(&recipient_x25519);
+
+        let mut nonce_bytes = [0u8; Self::GCM_NONCE_SIZE];
+        rand::thread_rng().fill_bytes(&mut nonce_bytes);
+        let key = Self::ecies_derive_key(shared_secret.as_bytes(), ephemeral_public.as_bytes(), recipient_x25519.as_bytes());
+
+        let nonce = Nonce::from_slice(&nonce_bytes);
+        let cipher = Aes256Gcm::new_from_slice(&key)
+            .map_err(|e| DNACryptoError::EncryptionError(e.to_string()))?;
+        let ciphertext = cipher
+            .encrypt(nonce, message.as_bytes())
+            .map_err(|e| DNACryptoError::EncryptionError(e.to_string()))?;
+
+        // Envelope: ephemeral_public(32) || nonce(12) || ciphertext(+tag)
+        let mut combined = Vec::with_capacity(32 + Self::GCM_NONCE_SIZE + ciphertext.len());
+        combined.extend_from_slice(ephemeral_public.as_bytes());
+        combined.extend_from_slice(&nonce_bytes);
+        combined.extend_from_slice(&ciphertext);
+        let crypto_string = BASE64.encode(&combined);
+
+        let dna_sequence = DNACrypto::encode_message(&crypto_string)?;
+        Ok(format!(
+            "{}{}{}",
+            markers::START_MARKER,
+            dna_sequence,
+            markers::STOP_MARKER
+        ))
+    }
+
+    /// Decode a sequence produced by [`Self::encode_to_recipient`] using the
+    /// recipient's 32-byte ed25519 secret key seed.
+    pub fn decode_as_recipient(sequence: &str, recipient_secret: &[u8]) -> Result<String> {
+        if sequence.is_empty() {
+            return Ok(String::new());
+        }
+
+        let core = Self::remove_markers(sequence);
+        let crypto_string = DNACrypto::decode_sequence(&core)?;
+        if crypto_string.is_empty() {
+            return Ok(String::new());
+        }
+
+        let combined = BASE64
+            .decode(crypto_string.as_bytes())
+            .map_err(|e| DNACryptoError::DecryptionError(format!("Invalid base64: {}", e)))?;
+        if combined.len() < 32 + Self::GCM_NONCE_SIZE {
+            return Err(DNACryptoError::DecryptionError("Invalid ECIES envelope".to_string()).into());
+        }
+
+        let ephemeral_public_bytes: [u8; 32] = combined[..32]
+            .try_into()
+            .map_err(|_| DNACryptoError::DecryptionError("Invalid ephemeral public key".to_string()))?;
+        let ephemeral_public = X25519PublicKey::from(ephemeral_public_bytes);
+        let nonce_bytes = &combined[32..32 + Self::GCM_NONCE_SIZE];
+        let ciphertext = &combined[32 + Self::GCM_NONCE_SIZE..];
+
+        let recipient_secret = Self::ed25519_secret_to_x25519(recipient_secret)?;
+        let recipient_public = X25519PublicKey::from(&recipient_secret);
+        let shared_secret = recipient_secret.diffie_hellman(&ephemeral_public);
+
+        let key = Self::ecies_derive_key(shared_secret.as_bytes(), &ephemeral_public_bytes, recipient_public.as_bytes());
+        let nonce = Nonce::from_slice(nonce_bytes);
+        let cipher = Aes256Gcm::new_from_slice(&key)
+            .map_err(|e| DNACryptoError::DecryptionError(e.to_string()))?;
+        let plaintext = cipher
+            .decrypt(nonce, ciphertext)
+            .map_err(|_| DNACryptoError::AuthenticationFailed)?;
+
+        String::from_utf8(plaintext).map_err(|e| DNACryptoError::DecryptionError(e.to_string()).into())
+    }
+
+    /// Expand an X25519 shared secret into an AES-256-GCM key via HKDF-SHA256.
+    fn ecies_derive_key(shared_secret: &[u8], ephemeral_public: &[u8], recipient_public: &[u8]) -> [u8; Self::KEY_SIZE] {
+        let mut salt = Vec::with_capacity(64);
+        salt.extend_from_slice(ephemeral_public);
+        salt.extend_from_slice(recipient_public);
+
+        let hk = Hkdf::<Sha256>::new(Some(&salt), shared_secret);
+        let mut key = [0u8; Self::KEY_SIZE];
+        hk.expand(Self::ECIES_INFO, &mut key)
+            .expect("HKDF output length is valid for SHA-256");
+        key
+    }
+
+    /// Convert a Solana (ed25519) public key to its birationally equivalent
+    /// X25519 Montgomery public key, per RFC 8032's Edwards/Montgomery map.
+    fn ed25519_pubkey_to_x25519(pubkey: &Pubkey) -> Result<X25519PublicKey> {
+        let compressed = CompressedEdwardsY::from_slice(pubkey.as_ref())
+            .map_err(|_| DNACryptoError::DecryptionError("Invalid ed25519 public key".to_string()))?;
+        let point = compressed
+            .decompress()
+            .ok_or_else(|| DNACryptoError::DecryptionError("Public key is not a valid curve point".to_string()))?;
+        Ok(X25519PublicKey::from(point.to_montgomery().to_bytes()))
+    }
+
+    /// Derive the X25519 static secret corresponding to an ed25519 secret key
+    /// seed, by clamping SHA-512(seed)[..32] per RFC 8032 convention.
+    fn ed25519_secret_to_x25519(seed: &[u8]) -> Result<StaticSecret> {
+        if seed.len() != 32 {
+            return Err(DNACryptoError::DecryptionError("ed25519 seed must be 32 bytes".to_string()).into());
+        }
+        use sha2::Digest;
+        let hash = Sha512::digest(seed);
+        let mut scalar = [0u8; 32];
+        scalar.copy_from_slice(&hash[..32]);
+        Ok(StaticSecret::from(scalar))
+    }
 }
 
 #[cfg(test)]
@@ -275,17 +1153,245 @@ mod tests {
 
     #[test]
     fn test_wrong_password_fails() {
-        let dna = SecureDNACrypto::encode_with_password("secret", "password123").unwrap();
-        let result = SecureDNACrypto::decode_with_password(&dna, "wrongpassword");
+        let dna = SecureDNACrypto::encode_with_password("secret", "TestPass123!").unwrap();
+        let result = SecureDNACrypto::decode_with_password(&dna, "WrongPass456!");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_wrong_password_is_authentication_failed() {
+        let dna = SecureDNACrypto::encode_with_password("secret", "TestPass123!").unwrap();
+        let err = SecureDNACrypto::decode_with_password(&dna, "WrongPass456!").unwrap_err();
+        assert!(matches!(err, crate::error::BioCypherError::DNACrypto(DNACryptoError::AuthenticationFailed)));
+    }
+
+    #[test]
+    fn test_tampered_sequence_detected() {
+        let original = "Secret message";
+        let password = "TestPass123!";
+        let dna = SecureDNACrypto::encode_with_password(original, password).unwrap();
+        let mut tampered: Vec<char> = dna.chars().collect();
+        let mid = tampered.len() / 2;
+        tampered[mid] = if tampered[mid] == 'A' { 'T' } else { 'A' };
+        let tampered: String = tampered.into_iter().collect();
+        let result = SecureDNACrypto::decode_with_password(&tampered, password);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_legacy_cbc_still_decodes() {
+        // Builds a version-1 envelope by hand (no KDF fields at all) to stand
+        // in for DNA sequences encoded before Argon2id support existed.
+        let password = "TestPass123!";
+        let legacy_kdf = Kdf::Pbkdf2 { iterations: SecureDNACrypto::PBKDF2_ITERATIONS };
+        let (encrypted_data, iv, salt) = SecureDNACrypto::encrypt_message(
+            "legacy message".as_bytes(),
+            password,
+            SecureMode::CbcLegacy,
+            Cipher::AesGcm,
+            Compression::None,
+            legacy_kdf,
+        )
+        .unwrap();
+        let der_bytes = der::encode_sequence(&[
+            der::encode_integer(SecureDNACrypto::ENVELOPE_VERSION_LEGACY_PBKDF2_ONLY),
+            der::encode_integer(SecureMode::CbcLegacy.to_tag() as u64),
+            der::encode_integer(Compression::None.to_tag() as u64),
+            der::encode_octet_string(&salt),
+            der::encode_octet_string(&iv),
+            der::encode_octet_string(&[]),
+            der::encode_octet_string(&encrypted_data),
+        ]);
+        let mut blob = vec![Cipher::AesGcm.to_tag()];
+        blob.extend_from_slice(&der_bytes);
+        let crypto_string = BASE64.encode(&blob);
+        let dna_sequence = DNACrypto::encode_message(&crypto_string).unwrap();
+        let dna = format!("{}{}{}", markers::START_MARKER, dna_sequence, markers::STOP_MARKER);
+        let decoded = SecureDNACrypto::decode_with_password(&dna, password).unwrap();
+        assert_eq!(decoded, "legacy message");
+    }
+
+    #[test]
+    fn test_gzip_compression_roundtrip_for_repetitive_text() {
+        let password = "TestPass123!";
+        let original = "abababababababababababababababababababababababababababab".repeat(20);
+        let dna = SecureDNACrypto::encode_with_password(&original, password).unwrap();
+        let decoded = SecureDNACrypto::decode_with_password(&dna, password).unwrap();
+        assert_eq!(decoded, original);
+    }
+
+    #[test]
+    fn test_compression_skipped_for_incompressible_short_input() {
+        let (compression, payload) = SecureDNACrypto::compress_with_guard(b"Hi");
+        assert_eq!(compression, Compression::None);
+        assert_eq!(payload, b"Hi");
+    }
+
+    #[test]
+    fn test_malformed_envelope_rejected() {
+        let result = SecureDNACrypto::string_to_crypto_data(&BASE64.encode(b"not a der envelope"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_envelope_roundtrip_preserves_fields() {
+        let kdf = Kdf::argon2id(19_456, 2, 1);
+        let (encrypted_data, iv, salt) = SecureDNACrypto::encrypt_message(
+            b"payload bytes",
+            "TestPass123!",
+            SecureMode::Gcm,
+            Cipher::ChaCha20Poly1305,
+            Compression::Gzip,
+            kdf,
+        )
+        .unwrap();
+        let crypto_string = SecureDNACrypto::crypto_data_to_string(
+            SecureMode::Gcm,
+            Compression::Gzip,
+            kdf,
+            Cipher::ChaCha20Poly1305,
+            &encrypted_data,
+            &iv,
+            &salt,
+        )
+        .unwrap();
+        let (mode, compression, round_kdf, round_cipher, round_tripped, round_iv, round_salt) =
+            SecureDNACrypto::string_to_crypto_data(&crypto_string).unwrap();
+        assert_eq!(mode, SecureMode::Gcm);
+        assert_eq!(compression, Compression::Gzip);
+        assert_eq!(round_kdf, kdf);
+        assert_eq!(round_cipher, Cipher::ChaCha20Poly1305);
+        assert_eq!(round_tripped, encrypted_data);
+        assert_eq!(round_iv, iv);
+        assert_eq!(round_salt, salt.to_vec());
+    }
+
+    #[test]
+    fn test_chacha20poly1305_roundtrip() {
+        let original = "message sealed under chacha20-poly1305";
+        let password = "TestPass123!";
+        let kdf = Kdf::argon2id(19_456, 2, 1);
+        let dna = SecureDNACrypto::encode_with_password_and_options(
+            original,
+            password,
+            kdf,
+            Cipher::ChaCha20Poly1305,
+        )
+        .unwrap();
+        let decoded = SecureDNACrypto::decode_with_password(&dna, password).unwrap();
+        assert_eq!(decoded, original);
+    }
+
+    #[test]
+    fn test_chacha20poly1305_tampered_sequence_detected() {
+        let original = "message sealed under chacha20-poly1305";
+        let password = "TestPass123!";
+        let dna = SecureDNACrypto::encode_with_password_and_options(
+            original,
+            password,
+            SecureDNACrypto::DEFAULT_KDF,
+            Cipher::ChaCha20Poly1305,
+        )
+        .unwrap();
+        let mut tampered: Vec<char> = dna.chars().collect();
+        let mid = tampered.len() / 2;
+        tampered[mid] = if tampered[mid] == 'A' { 'T' } else { 'A' };
+        let tampered: String = tampered.into_iter().collect();
+        let result = SecureDNACrypto::decode_with_password(&tampered, password);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_argon2id_roundtrip() {
+        let original = "message sealed under argon2id";
+        let password = "TestPass123!";
+        let kdf = Kdf::argon2id(19_456, 2, 1);
+        let dna = SecureDNACrypto::encode_with_password_and_kdf(original, password, kdf).unwrap();
+        let decoded = SecureDNACrypto::decode_with_password(&dna, password).unwrap();
+        assert_eq!(decoded, original);
+    }
+
+    #[test]
+    fn test_pbkdf2_kdf_choice_still_decodes() {
+        let original = "message sealed under pbkdf2";
+        let password = "TestPass123!";
+        let kdf = Kdf::pbkdf2(SecureDNACrypto::PBKDF2_ITERATIONS);
+        let dna = SecureDNACrypto::encode_with_password_and_kdf(original, password, kdf).unwrap();
+        let decoded = SecureDNACrypto::decode_with_password(&dna, password).unwrap();
+        assert_eq!(decoded, original);
+    }
+
     #[test]
     fn test_password_required() {
         let result = SecureDNACrypto::encode_with_password("msg", "");
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_entropy_rejects_common_password() {
+        let (bits, issues) = SecureDNACrypto::estimate_password_entropy("Password123!");
+        assert!(bits < SecureDNACrypto::DEFAULT_MIN_ENTROPY_BITS);
+        assert!(!issues.is_empty());
+    }
+
+    #[test]
+    fn test_entropy_rejects_short_varied_password() {
+        let (bits, _) = SecureDNACrypto::estimate_password_entropy("Aa1!Aa1!");
+        assert!(bits < SecureDNACrypto::DEFAULT_MIN_ENTROPY_BITS);
+    }
+
+    #[test]
+    fn test_entropy_accepts_long_passphrase() {
+        let (bits, _) = SecureDNACrypto::estimate_password_entropy("correct horse battery staple zebra");
+        assert!(bits >= SecureDNACrypto::DEFAULT_MIN_ENTROPY_BITS);
+    }
+
+    #[test]
+    fn test_entropy_penalizes_repeated_and_sequential_runs() {
+        let (repeated, issues) = SecureDNACrypto::estimate_password_entropy("aaaaaaaa");
+        let (varied, _) = SecureDNACrypto::estimate_password_entropy("k7Qz9!pX");
+        assert!(repeated < varied);
+        assert!(issues.iter().any(|i| i.contains("repeated")));
+
+        let (sequential, issues) = SecureDNACrypto::estimate_password_entropy("abcdefgh");
+        assert!(issues.iter().any(|i| i.contains("sequential")));
+        assert!(sequential < varied);
+    }
+
+    #[test]
+    fn test_require_min_entropy() {
+        assert!(SecureDNACrypto::require_min_entropy("password", 128.0).is_err());
+        assert!(SecureDNACrypto::require_min_entropy(
+            "correct horse battery staple zebra",
+            128.0
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_encode_with_password_rejects_weak_password() {
+        let err = SecureDNACrypto::encode_with_password("secret", "password123").unwrap_err();
+        assert!(matches!(
+            err,
+            crate::error::BioCypherError::DNACrypto(DNACryptoError::PasswordWeak(_))
+        ));
+    }
+
+    #[test]
+    fn test_encode_with_password_and_entropy_floor_allows_opt_out() {
+        let dna =
+            SecureDNACrypto::encode_with_password_options_and_entropy_floor(
+                "secret",
+                "password123",
+                SecureDNACrypto::DEFAULT_KDF,
+                SecureDNACrypto::DEFAULT_CIPHER,
+                0.0,
+            )
+            .unwrap();
+        let decoded = SecureDNACrypto::decode_with_password(&dna, "password123").unwrap();
+        assert_eq!(decoded, "secret");
+    }
+
     #[test]
     fn test_validate_password_strength() {
         let (valid, _) = SecureDNACrypto::validate_password_strength("TestPass123!");
@@ -295,4 +1401,45 @@ mod tests {
         assert!(!invalid);
         assert!(!issues.is_empty());
     }
+
+    #[test]
+    fn test_ecies_encode_decode_roundtrip() {
+        let recipient_secret = [7u8; 32];
+        let recipient_x25519 = SecureDNACrypto::ed25519_secret_to_x25519(&recipient_secret).unwrap();
+        let recipient_public = X25519PublicKey::from(&recipient_x25519);
+        let recipient = Pubkey::new_from_array(recipient_public.to_bytes());
+
+        let original = "Message for recipient only";
+        let dna = SecureDNACrypto::encode_to_recipient(original, &recipient).unwrap();
+        assert!(dna.starts_with(markers::START_MARKER));
+        assert!(dna.ends_with(markers::STOP_MARKER));
+
+        let decoded = SecureDNACrypto::decode_as_recipient(&dna, &recipient_secret).unwrap();
+        assert_eq!(original, decoded);
+    }
+
+    #[test]
+    fn test_ecies_wrong_recipient_secret_fails() {
+        let recipient_secret = [7u8; 32];
+        let recipient_x25519 = SecureDNACrypto::ed25519_secret_to_x25519(&recipient_secret).unwrap();
+        let recipient_public = X25519PublicKey::from(&recipient_x25519);
+        let recipient = Pubkey::new_from_array(recipient_public.to_bytes());
+
+        let dna = SecureDNACrypto::encode_to_recipient("secret", &recipient).unwrap();
+
+        let wrong_secret = [9u8; 32];
+        let result = SecureDNACrypto::decode_as_recipient(&dna, &wrong_secret);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_ecies_encode_decode_empty() {
+        let recipient_secret = [7u8; 32];
+        let recipient_x25519 = SecureDNACrypto::ed25519_secret_to_x25519(&recipient_secret).unwrap();
+        let recipient_public = X25519PublicKey::from(&recipient_x25519);
+        let recipient = Pubkey::new_from_array(recipient_public.to_bytes());
+
+        assert_eq!(SecureDNACrypto::encode_to_recipient("", &recipient).unwrap(), "");
+        assert_eq!(SecureDNACrypto::decode_as_recipient("", &recipient_secret).unwrap(), "");
+    }
 }
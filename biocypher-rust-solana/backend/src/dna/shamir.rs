@@ -0,0 +1,276 @@
+//! Shamir's Secret Sharing over GF(2^8)
+//!
+//! Splits a byte array into `shares` shares such that any `threshold` of
+//! them reconstruct it via Lagrange interpolation at x=0, while any
+//! `threshold - 1` reveal nothing about it. Each byte of the secret gets
+//! its own random degree-`(threshold - 1)` polynomial with that byte as
+//! its constant term; a share is the polynomial evaluated at a distinct
+//! non-zero x-coordinate for every byte, so x=0 (the secret) is never
+//! handed out. Field arithmetic uses generator 3 with reduction polynomial
+//! 0x11b (the same field AES uses), via precomputed log/exp tables.
+
+use std::collections::HashSet;
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use rand::Rng;
+
+use crate::error::{DNACryptoError, Result};
+
+const GF_REDUCTION: u16 = 0x11b;
+
+/// Double `x` in GF(2^8), reducing modulo [`GF_REDUCTION`] on overflow.
+fn gf_double(x: u8) -> u8 {
+    let doubled = (x as u16) << 1;
+    if doubled & 0x100 != 0 {
+        (doubled ^ GF_REDUCTION) as u8
+    } else {
+        doubled as u8
+    }
+}
+
+struct GfTables {
+    exp: [u8; 256],
+    log: [u8; 256],
+}
+
+impl GfTables {
+    /// Build log/exp tables by walking the multiplicative group generated
+    /// by 3 (`3*x == double(x) xor x`, since 3 == 2 xor 1 and
+    /// multiplication distributes over xor in this field).
+    fn build() -> Self {
+        let mut exp = [0u8; 256];
+        let mut log = [0u8; 256];
+        let mut x: u8 = 1;
+        for i in 0..255usize {
+            exp[i] = x;
+            log[x as usize] = i as u8;
+            x = gf_double(x) ^ x;
+        }
+        exp[255] = exp[0];
+        Self { exp, log }
+    }
+
+    fn mul(&self, a: u8, b: u8) -> u8 {
+        if a == 0 || b == 0 {
+            return 0;
+        }
+        let sum = self.log[a as usize] as u16 + self.log[b as usize] as u16;
+        self.exp[(sum % 255) as usize]
+    }
+
+    fn div(&self, a: u8, b: u8) -> u8 {
+        if a == 0 {
+            return 0;
+        }
+        let diff = (self.log[a as usize] as i16 - self.log[b as usize] as i16).rem_euclid(255);
+        self.exp[diff as usize]
+    }
+}
+
+/// One share of a split secret: a 1-indexed x-coordinate plus one
+/// evaluated byte per secret byte.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Share {
+    pub index: u8,
+    pub values: Vec<u8>,
+}
+
+/// Base64-encode a share as its index byte followed by its value column.
+pub fn share_to_base64(share: &Share) -> String {
+    let mut buf = Vec::with_capacity(1 + share.values.len());
+    buf.push(share.index);
+    buf.extend_from_slice(&share.values);
+    BASE64.encode(buf)
+}
+
+/// Parse a share previously serialized by [`share_to_base64`].
+pub fn share_from_base64(encoded: &str) -> Result<Share> {
+    let bytes = BASE64
+        .decode(encoded)
+        .map_err(|e| DNACryptoError::DecryptionError(format!("Invalid share base64: {}", e)))?;
+    let (index, values) = bytes
+        .split_first()
+        .ok_or_else(|| DNACryptoError::DecryptionError("Empty share".to_string()))?;
+    Ok(Share {
+        index: *index,
+        values: values.to_vec(),
+    })
+}
+
+/// Split `secret` into `shares` Shamir shares, any `threshold` of which
+/// reconstruct it.
+///
+/// # Panics
+/// Panics if `threshold` is 0, `shares` is less than `threshold`, or
+/// `shares` exceeds 255 (x-coordinates must be distinct non-zero bytes).
+pub fn split(secret: &[u8], threshold: u8, shares: u8) -> Vec<Share> {
+    assert!(threshold >= 1, "threshold must be at least 1");
+    assert!(shares >= threshold, "shares must be at least threshold");
+
+    let tables = GfTables::build();
+    let mut rng = rand::thread_rng();
+
+    let coefficients: Vec<Vec<u8>> = secret
+        .iter()
+        .map(|&byte| {
+            let mut coeffs = vec![0u8; threshold as usize];
+            coeffs[0] = byte;
+            for c in coeffs.iter_mut().skip(1) {
+                *c = rng.gen();
+            }
+            coeffs
+        })
+        .collect();
+
+    (1..=shares)
+        .map(|x| Share {
+            index: x,
+            values: coefficients
+                .iter()
+                .map(|coeffs| eval_polynomial(&tables, coeffs, x))
+                .collect(),
+        })
+        .collect()
+}
+
+/// Horner's method, highest-degree coefficient first.
+fn eval_polynomial(tables: &GfTables, coeffs: &[u8], x: u8) -> u8 {
+    coeffs.iter().rev().fold(0u8, |acc, &c| tables.mul(acc, x) ^ c)
+}
+
+/// Reconstruct a secret from `threshold`-or-more shares via Lagrange
+/// interpolation at x=0. Only the first `threshold` shares are used.
+///
+/// Rejects fewer than `threshold` shares, a share with index 0 (reserved
+/// for the secret), duplicate indices, or shares of mismatched length.
+pub fn reconstruct(shares: &[Share], threshold: u8) -> Result<Vec<u8>> {
+    if shares.len() < threshold as usize {
+        return Err(DNACryptoError::DecryptionError(format!(
+            "Need at least {} shares to reconstruct the secret, got {}",
+            threshold,
+            shares.len()
+        ))
+        .into());
+    }
+
+    let used = &shares[..threshold as usize];
+
+    let mut seen = HashSet::new();
+    for share in used {
+        if share.index == 0 {
+            return Err(DNACryptoError::DecryptionError(
+                "Share index 0 is reserved for the secret and cannot be a valid share".to_string(),
+            )
+            .into());
+        }
+        if !seen.insert(share.index) {
+            return Err(DNACryptoError::DecryptionError(format!(
+                "Duplicate share index {}",
+                share.index
+            ))
+            .into());
+        }
+    }
+
+    let secret_len = used[0].values.len();
+    if used.iter().any(|s| s.values.len() != secret_len) {
+        return Err(DNACryptoError::DecryptionError(
+            "Shares disagree on secret length".to_string(),
+        )
+        .into());
+    }
+
+    let tables = GfTables::build();
+    let secret = (0..secret_len)
+        .map(|byte_idx| lagrange_at_zero(&tables, used, byte_idx))
+        .collect();
+    Ok(secret)
+}
+
+/// Evaluate the Lagrange interpolation of `shares`' byte at `byte_idx` at
+/// x=0. In GF(2^n), `0 - x_j == x_j` and subtraction is xor, so this is
+/// `sum_i values[i] * prod_{j != i} x_j / (x_i xor x_j)`.
+fn lagrange_at_zero(tables: &GfTables, shares: &[Share], byte_idx: usize) -> u8 {
+    let mut result = 0u8;
+    for (i, share_i) in shares.iter().enumerate() {
+        let mut numerator = 1u8;
+        let mut denominator = 1u8;
+        for (j, share_j) in shares.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            numerator = tables.mul(numerator, share_j.index);
+            denominator = tables.mul(denominator, share_i.index ^ share_j.index);
+        }
+        let coefficient = tables.div(numerator, denominator);
+        result ^= tables.mul(share_i.values[byte_idx], coefficient);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_threshold_shares_reconstruct_secret() {
+        let secret = b"0123456789abcdef0123456789abcdef".to_vec();
+        let shares = split(&secret, 3, 5);
+        let reconstructed = reconstruct(&shares[1..4], 3).unwrap();
+        assert_eq!(reconstructed, secret);
+    }
+
+    #[test]
+    fn test_any_threshold_subset_reconstructs() {
+        let secret = vec![0xAAu8; 32];
+        let shares = split(&secret, 3, 5);
+        for combo in [[0, 1, 2], [0, 2, 4], [1, 3, 4], [2, 3, 4]] {
+            let subset: Vec<Share> = combo.iter().map(|&i| shares[i].clone()).collect();
+            assert_eq!(reconstruct(&subset, 3).unwrap(), secret);
+        }
+    }
+
+    #[test]
+    fn test_gf_tables_round_trip_all_nonzero_elements() {
+        let tables = GfTables::build();
+        for a in 1..=255u8 {
+            assert_eq!(tables.div(tables.mul(a, 7), 7), a);
+        }
+    }
+
+    #[test]
+    fn test_fewer_than_threshold_shares_rejected() {
+        let secret = vec![1u8; 32];
+        let shares = split(&secret, 3, 5);
+        let result = reconstruct(&shares[..2], 3);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_duplicate_indices_rejected() {
+        let secret = vec![1u8; 32];
+        let shares = split(&secret, 2, 5);
+        let duplicated = vec![shares[0].clone(), shares[0].clone()];
+        let result = reconstruct(&duplicated, 2);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_share_serialization_roundtrip() {
+        let secret = vec![5u8; 32];
+        let shares = split(&secret, 2, 4);
+        let encoded: Vec<String> = shares.iter().map(share_to_base64).collect();
+        let decoded: Vec<Share> = encoded.iter().map(|s| share_from_base64(s).unwrap()).collect();
+        assert_eq!(reconstruct(&decoded, 2).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_threshold_of_one_is_plain_replication() {
+        let secret = vec![42u8; 32];
+        let shares = split(&secret, 1, 3);
+        for share in &shares {
+            assert_eq!(share.values, secret);
+        }
+        assert_eq!(reconstruct(&shares[..1], 1).unwrap(), secret);
+    }
+}
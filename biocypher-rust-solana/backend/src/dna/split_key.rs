@@ -4,13 +4,71 @@
 //! Provider A (DNA manufacturer) synthesizes ciphertext-as-DNA; Provider B holds K2.
 //! Neither can decrypt alone.
 
+use crate::dna::base58check;
 use crate::dna::basic::DNACrypto;
 use crate::dna::markers;
-use crate::dna::secure::SecureDNACrypto;
-use crate::dna::traits::{DNACoder, SequenceStats, SequenceStatistics};
-use crate::error::{DNACryptoError, Result};
+use crate::dna::secure::{Cipher, Compression, Kdf, SecureDNACrypto, SecureMode};
+use crate::dna::shamir;
+use crate::dna::traits::{DNACoder, DnaDecoder, DnaEncoder, SequenceStats, SequenceStatistics};
+use crate::error::{BioCypherError, DNACryptoError, Result};
+use argon2::{Algorithm, Argon2, Params as Argon2Params, Version as Argon2Version};
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use pbkdf2::pbkdf2_hmac;
 use rand::RngCore;
+use sha2::Sha256;
+
+/// Domain-separation salt for passphrase-derived ("brain") split keys: fixed
+/// so the same passphrase always regenerates the same K1/K2 pair without
+/// ever storing a salt, the same way [`SecureDNACrypto::split_key_aad`]
+/// fixes a domain-separation constant rather than deriving one from caller
+/// input.
+const BRAIN_KEY_SALT: &[u8; 16] = b"biocypher-braink";
+
+/// KDF used to derive brain keys unless a caller picks another one via
+/// [`SplitKeyDNACrypto::encode_with_passphrase_and_kdf`]: Argon2id at the
+/// same OWASP baseline cost the rest of the crate defaults to.
+const BRAIN_KEY_DEFAULT_KDF: Kdf = Kdf::Argon2id { memory_kib: 19_456, time_cost: 2, parallelism: 1 };
+
+/// Text representation used for a K1/K2 split-key share.
+///
+/// `Base64` is compact but gives no feedback on a mistyped character;
+/// `Base58Check` trades a few extra characters for a checksum (see
+/// [`base58check`]), so a transcription error is caught at decode time
+/// instead of silently reconstructing the wrong key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyShareFormat {
+    Base64,
+    Base58Check,
+}
+
+impl KeyShareFormat {
+    fn encode_share(self, key: &[u8]) -> String {
+        match self {
+            KeyShareFormat::Base64 => BASE64.encode(key),
+            KeyShareFormat::Base58Check => base58check::encode(base58check::KEY_SHARE_VERSION, key),
+        }
+    }
+
+    fn decode_share(self, encoded: &str) -> Result<Vec<u8>> {
+        match self {
+            KeyShareFormat::Base64 => BASE64
+                .decode(encoded.as_bytes())
+                .map_err(|e| DNACryptoError::DecryptionError(format!("Invalid key share base64: {}", e)).into()),
+            KeyShareFormat::Base58Check => {
+                let (version, payload) = base58check::decode(encoded)?;
+                if version != base58check::KEY_SHARE_VERSION {
+                    return Err(DNACryptoError::DecryptionError(format!(
+                        "Unexpected key share version byte: {}",
+                        version
+                    ))
+                    .into());
+                }
+                Ok(payload)
+            }
+        }
+    }
+}
 
 /// Split Key DNA cryptography
 pub struct SplitKeyDNACrypto;
@@ -31,30 +89,103 @@ impl SequenceStats for SplitKeyDNACrypto {
     }
 }
 
+impl DnaEncoder for SplitKeyDNACrypto {
+    type Output = String;
+    type Error = BioCypherError;
+
+    /// SplitKey mode needs two key shares, which this generic interface has
+    /// no room for; delegates to `DNACoder::encode_message`, which already
+    /// errors with `DNACryptoError::SplitKeyRequired`.
+    fn encode(&self, input: &[u8]) -> std::result::Result<String, BioCypherError> {
+        let message = std::str::from_utf8(input)
+            .map_err(|e| BioCypherError::Validation(format!("message is not valid UTF-8: {e}")))?;
+        Self::encode_message(message)
+    }
+}
+
+impl DnaDecoder for SplitKeyDNACrypto {
+    type Output = String;
+    type Error = BioCypherError;
+
+    fn decode(&self, input: &[u8]) -> std::result::Result<String, BioCypherError> {
+        let sequence = std::str::from_utf8(input)
+            .map_err(|e| BioCypherError::Validation(format!("sequence is not valid UTF-8: {e}")))?;
+        Self::decode_sequence(sequence)
+    }
+}
+
 impl SplitKeyDNACrypto {
-    /// Encode message with split keys. Returns (dna_sequence, k1_base64, k2_base64).
+    /// Encode message with split keys, using AES-256-GCM. Returns
+    /// (dna_sequence, k1_base64, k2_base64).
     pub fn encode_with_split_keys(message: &str) -> Result<(String, String, String)> {
+        Self::encode_with_split_keys_and_cipher(message, Cipher::AesGcm)
+    }
+
+    /// Encode message with split keys, using an explicitly chosen AEAD
+    /// cipher backend (see [`Cipher::AesGcm`] / [`Cipher::ChaCha20Poly1305`]).
+    /// Returns (dna_sequence, k1_base64, k2_base64). The cipher choice is
+    /// recorded in the envelope so decode auto-selects the right backend.
+    pub fn encode_with_split_keys_and_cipher(
+        message: &str,
+        cipher: Cipher,
+    ) -> Result<(String, String, String)> {
+        Self::encode_with_split_keys_and_options(message, cipher, Compression::None)
+            .map(|(seq, k1, k2, _, _)| (seq, k1, k2))
+    }
+
+    /// Like [`Self::encode_with_split_keys_and_cipher`], additionally
+    /// compressing the message before encryption. Returns (dna_sequence,
+    /// k1_base64, k2_base64, raw_bytes, compressed_bytes).
+    pub fn encode_with_split_keys_and_options(
+        message: &str,
+        cipher: Cipher,
+        compression: Compression,
+    ) -> Result<(String, String, String, usize, usize)> {
+        Self::encode_with_split_keys_and_format(message, cipher, compression, KeyShareFormat::Base64)
+    }
+
+    /// Like [`Self::encode_with_split_keys_and_options`], additionally
+    /// choosing the text representation of the returned K1/K2 shares (see
+    /// [`KeyShareFormat`]). Returns (dna_sequence, k1, k2, raw_bytes,
+    /// compressed_bytes) with k1/k2 encoded in `format`.
+    pub fn encode_with_split_keys_and_format(
+        message: &str,
+        cipher: Cipher,
+        compression: Compression,
+        format: KeyShareFormat,
+    ) -> Result<(String, String, String, usize, usize)> {
         if message.is_empty() {
-            return Ok((String::new(), String::new(), String::new()));
+            return Ok((String::new(), String::new(), String::new(), 0, 0));
         }
 
         // Generate random K
         let mut k = [0u8; SecureDNACrypto::KEY_SIZE];
         rand::thread_rng().fill_bytes(&mut k);
 
-        // Generate random IV and salt
-        let mut iv = [0u8; SecureDNACrypto::IV_SIZE];
+        // Generate random nonce and salt
+        let mut nonce = [0u8; SecureDNACrypto::GCM_NONCE_SIZE];
         let mut salt = [0u8; SecureDNACrypto::SALT_SIZE];
-        rand::thread_rng().fill_bytes(&mut iv);
+        rand::thread_rng().fill_bytes(&mut nonce);
         rand::thread_rng().fill_bytes(&mut salt);
 
-        // Encrypt with K
-        let ciphertext =
-            SecureDNACrypto::encrypt_with_key(message.as_bytes(), &k, &iv)?;
+        // Compress, then encrypt with K
+        let raw_bytes = message.len();
+        let payload = SecureDNACrypto::compress_with_choice(message.as_bytes(), compression)?;
+        let compressed_bytes = payload.len();
+        let ciphertext = SecureDNACrypto::encrypt_with_key(&payload, &k, &nonce, cipher)?;
 
-        // Serialize to base64 (same format as Secure mode)
-        let crypto_string =
-            SecureDNACrypto::crypto_data_to_string(&ciphertext, &iv, &salt)?;
+        // Serialize to base64 (same format as Secure mode). Split-key mode
+        // never derives K from a password, so the KDF field is a fixed
+        // placeholder recorded only to satisfy the shared envelope layout.
+        let crypto_string = SecureDNACrypto::crypto_data_to_string(
+            SecureMode::Gcm,
+            compression,
+            Kdf::pbkdf2(SecureDNACrypto::PBKDF2_ITERATIONS),
+            cipher,
+            &ciphertext,
+            &nonce,
+            &salt,
+        )?;
 
         // Encode to DNA
         let dna_sequence = DNACrypto::encode_message(&crypto_string)?;
@@ -78,27 +209,138 @@ impl SplitKeyDNACrypto {
 
         Ok((
             result,
-            BASE64.encode(&k1),
-            BASE64.encode(&k2),
+            format.encode_share(&k1),
+            format.encode_share(&k2),
+            raw_bytes,
+            compressed_bytes,
         ))
     }
 
-    /// Decode sequence with split keys K1 and K2.
+    /// Derive a deterministic K1/K2 pair from `passphrase` alone (a "brain"
+    /// derivation): the same passphrase under the same [`Kdf`] always
+    /// reproduces the same keys, so nothing needs to be stored between
+    /// encode and decode. Splits a 64-byte KDF-derived buffer into (k1, k2)
+    /// the same way [`crate::dna::keystore::KeyStore`]'s `derive_keys`
+    /// splits its wrap/MAC key pair from one derived buffer.
+    fn derive_brain_keys(
+        passphrase: &str,
+        kdf: Kdf,
+    ) -> Result<([u8; SecureDNACrypto::KEY_SIZE], [u8; SecureDNACrypto::KEY_SIZE])> {
+        let mut derived = [0u8; 64];
+        match kdf {
+            Kdf::Pbkdf2 { iterations } => {
+                pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), BRAIN_KEY_SALT, iterations, &mut derived);
+            }
+            Kdf::Argon2id { memory_kib, time_cost, parallelism } => {
+                let params = Argon2Params::new(memory_kib, time_cost, parallelism, Some(derived.len()))
+                    .map_err(|e| DNACryptoError::EncryptionError(format!("Invalid Argon2id parameters: {}", e)))?;
+                let argon2 = Argon2::new(Algorithm::Argon2id, Argon2Version::V0x13, params);
+                argon2
+                    .hash_password_into(passphrase.as_bytes(), BRAIN_KEY_SALT, &mut derived)
+                    .map_err(|e| DNACryptoError::EncryptionError(format!("Argon2id derivation failed: {}", e)))?;
+            }
+        }
+        let mut k1 = [0u8; SecureDNACrypto::KEY_SIZE];
+        let mut k2 = [0u8; SecureDNACrypto::KEY_SIZE];
+        k1.copy_from_slice(&derived[..32]);
+        k2.copy_from_slice(&derived[32..]);
+        Ok((k1, k2))
+    }
+
+    /// Encode message with K1/K2 derived deterministically from `passphrase`
+    /// instead of generated at random, using [`BRAIN_KEY_DEFAULT_KDF`]. The
+    /// same passphrase regenerates the identical K1/K2 pair later, so the
+    /// keys never need to be written down — only the passphrase does. See
+    /// [`Self::decode_with_passphrase`] to decode.
+    pub fn encode_with_passphrase(message: &str, passphrase: &str) -> Result<(String, String, String)> {
+        Self::encode_with_passphrase_and_kdf(message, passphrase, BRAIN_KEY_DEFAULT_KDF)
+    }
+
+    /// Like [`Self::encode_with_passphrase`], deriving K1/K2 under an
+    /// explicitly chosen [`Kdf`] instead of the default.
+    pub fn encode_with_passphrase_and_kdf(
+        message: &str,
+        passphrase: &str,
+        kdf: Kdf,
+    ) -> Result<(String, String, String)> {
+        if message.is_empty() {
+            return Ok((String::new(), String::new(), String::new()));
+        }
+
+        let (k1, k2) = Self::derive_brain_keys(passphrase, kdf)?;
+        let mut k = [0u8; SecureDNACrypto::KEY_SIZE];
+        for i in 0..SecureDNACrypto::KEY_SIZE {
+            k[i] = k1[i] ^ k2[i];
+        }
+
+        let mut nonce = [0u8; SecureDNACrypto::GCM_NONCE_SIZE];
+        let mut salt = [0u8; SecureDNACrypto::SALT_SIZE];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        rand::thread_rng().fill_bytes(&mut salt);
+
+        let payload = SecureDNACrypto::compress_with_choice(message.as_bytes(), Compression::None)?;
+        let ciphertext = SecureDNACrypto::encrypt_with_key(&payload, &k, &nonce, Cipher::AesGcm)?;
+
+        let crypto_string = SecureDNACrypto::crypto_data_to_string(
+            SecureMode::Gcm,
+            Compression::None,
+            Kdf::pbkdf2(SecureDNACrypto::PBKDF2_ITERATIONS),
+            Cipher::AesGcm,
+            &ciphertext,
+            &nonce,
+            &salt,
+        )?;
+
+        let dna_sequence = DNACrypto::encode_message(&crypto_string)?;
+        let result = format!("{}{}{}", markers::START_MARKER, dna_sequence, markers::STOP_MARKER);
+
+        Ok((result, BASE64.encode(k1), BASE64.encode(k2)))
+    }
+
+    /// Decode a sequence produced by [`Self::encode_with_passphrase`] by
+    /// re-deriving K1/K2 from `passphrase` under [`BRAIN_KEY_DEFAULT_KDF`]
+    /// rather than requiring them to be passed in separately.
+    pub fn decode_with_passphrase(sequence: &str, passphrase: &str) -> Result<String> {
+        Self::decode_with_passphrase_and_kdf(sequence, passphrase, BRAIN_KEY_DEFAULT_KDF)
+    }
+
+    /// Like [`Self::decode_with_passphrase`], re-deriving K1/K2 under an
+    /// explicitly chosen [`Kdf`] — must match whatever
+    /// [`Self::encode_with_passphrase_and_kdf`] was called with.
+    pub fn decode_with_passphrase_and_kdf(sequence: &str, passphrase: &str, kdf: Kdf) -> Result<String> {
+        if sequence.is_empty() {
+            return Ok(String::new());
+        }
+        let (k1, k2) = Self::derive_brain_keys(passphrase, kdf)?;
+        Self::decode_with_split_keys(sequence, &BASE64.encode(k1), &BASE64.encode(k2))
+    }
+
+    /// Decode sequence with split keys K1 and K2, given as base64 (see
+    /// [`Self::decode_with_split_keys_and_format`] for Base58Check). The
+    /// cipher and compression backend are read back from the envelope, not
+    /// passed in.
     pub fn decode_with_split_keys(
         sequence: &str,
         k1_base64: &str,
         k2_base64: &str,
+    ) -> Result<String> {
+        Self::decode_with_split_keys_and_format(sequence, k1_base64, k2_base64, KeyShareFormat::Base64)
+    }
+
+    /// Like [`Self::decode_with_split_keys`], with `k1`/`k2` given in the
+    /// caller-chosen [`KeyShareFormat`] rather than assumed to be base64.
+    pub fn decode_with_split_keys_and_format(
+        sequence: &str,
+        k1: &str,
+        k2: &str,
+        format: KeyShareFormat,
     ) -> Result<String> {
         if sequence.is_empty() {
             return Ok(String::new());
         }
 
-        let k1 = BASE64
-            .decode(k1_base64.as_bytes())
-            .map_err(|e| DNACryptoError::DecryptionError(format!("Invalid K1 base64: {}", e)))?;
-        let k2 = BASE64
-            .decode(k2_base64.as_bytes())
-            .map_err(|e| DNACryptoError::DecryptionError(format!("Invalid K2 base64: {}", e)))?;
+        let k1 = format.decode_share(k1)?;
+        let k2 = format.decode_share(k2)?;
 
         if k1.len() != SecureDNACrypto::KEY_SIZE || k2.len() != SecureDNACrypto::KEY_SIZE {
             return Err(DNACryptoError::DecryptionError(
@@ -123,14 +365,150 @@ impl SplitKeyDNACrypto {
         }
 
         // Parse crypto data
-        let (encrypted_data, iv, _salt) = SecureDNACrypto::string_to_crypto_data(&crypto_string)?;
+        let (_mode, compression, _kdf, cipher, encrypted_data, nonce, _salt) =
+            SecureDNACrypto::string_to_crypto_data(&crypto_string)?;
+
+        // Decrypt with K, then decompress
+        let nonce_arr: [u8; SecureDNACrypto::GCM_NONCE_SIZE] = nonce
+            .try_into()
+            .map_err(|_| DNACryptoError::DecryptionError("Invalid nonce length".to_string()))?;
+
+        let payload = SecureDNACrypto::decrypt_with_key(&encrypted_data, &k, &nonce_arr, cipher)?;
+        let decompressed = SecureDNACrypto::decompress(compression, &payload)?;
+        String::from_utf8(decompressed).map_err(|e| DNACryptoError::DecryptionError(e.to_string()).into())
+    }
+
+    /// Encode message with the encryption key split into `shares` Shamir
+    /// shares, any `threshold` of which reconstruct it. Unlike
+    /// [`Self::encode_with_split_keys`]'s fixed 2-of-2 XOR split, this
+    /// supports arbitrary t-of-n escrow (e.g. 3-of-5 custodians). Uses
+    /// AES-256-GCM; see [`Self::encode_with_shamir_shares_and_cipher`] to
+    /// choose ChaCha20-Poly1305 instead.
+    pub fn encode_with_shamir_shares(
+        message: &str,
+        threshold: u8,
+        shares: u8,
+    ) -> Result<(String, Vec<String>)> {
+        Self::encode_with_shamir_shares_and_cipher(message, threshold, shares, Cipher::AesGcm)
+    }
+
+    /// Like [`Self::encode_with_shamir_shares`], with an explicitly chosen
+    /// AEAD cipher backend. The cipher choice is recorded in the envelope so
+    /// decode auto-selects the right backend.
+    pub fn encode_with_shamir_shares_and_cipher(
+        message: &str,
+        threshold: u8,
+        shares: u8,
+        cipher: Cipher,
+    ) -> Result<(String, Vec<String>)> {
+        Self::encode_with_shamir_shares_and_options(message, threshold, shares, cipher, Compression::None)
+            .map(|(seq, key_shares, _, _)| (seq, key_shares))
+    }
+
+    /// Like [`Self::encode_with_shamir_shares_and_cipher`], additionally
+    /// compressing the message before encryption. Returns (dna_sequence,
+    /// key_shares, raw_bytes, compressed_bytes).
+    pub fn encode_with_shamir_shares_and_options(
+        message: &str,
+        threshold: u8,
+        shares: u8,
+        cipher: Cipher,
+        compression: Compression,
+    ) -> Result<(String, Vec<String>, usize, usize)> {
+        if message.is_empty() {
+            return Ok((String::new(), Vec::new(), 0, 0));
+        }
+
+        // Generate random K
+        let mut k = [0u8; SecureDNACrypto::KEY_SIZE];
+        rand::thread_rng().fill_bytes(&mut k);
+
+        // Generate random nonce and salt
+        let mut nonce = [0u8; SecureDNACrypto::GCM_NONCE_SIZE];
+        let mut salt = [0u8; SecureDNACrypto::SALT_SIZE];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        rand::thread_rng().fill_bytes(&mut salt);
+
+        // Compress, then encrypt with K
+        let raw_bytes = message.len();
+        let payload = SecureDNACrypto::compress_with_choice(message.as_bytes(), compression)?;
+        let compressed_bytes = payload.len();
+        let ciphertext = SecureDNACrypto::encrypt_with_key(&payload, &k, &nonce, cipher)?;
+
+        // Serialize to base64 (same format as Secure mode). Split-key mode
+        // never derives K from a password, so the KDF field is a fixed
+        // placeholder recorded only to satisfy the shared envelope layout.
+        let crypto_string = SecureDNACrypto::crypto_data_to_string(
+            SecureMode::Gcm,
+            compression,
+            Kdf::pbkdf2(SecureDNACrypto::PBKDF2_ITERATIONS),
+            cipher,
+            &ciphertext,
+            &nonce,
+            &salt,
+        )?;
+
+        // Encode to DNA
+        let dna_sequence = DNACrypto::encode_message(&crypto_string)?;
+
+        // Add markers
+        let result = format!(
+            "{}{}{}",
+            markers::START_MARKER,
+            dna_sequence,
+            markers::STOP_MARKER
+        );
+
+        let key_shares = shamir::split(&k, threshold, shares)
+            .iter()
+            .map(shamir::share_to_base64)
+            .collect();
+
+        Ok((result, key_shares, raw_bytes, compressed_bytes))
+    }
+
+    /// Decode a sequence produced by [`Self::encode_with_shamir_shares`]
+    /// given any `threshold`-or-more of its base64-encoded key shares. The
+    /// cipher backend is read back from the envelope, not passed in.
+    pub fn decode_with_shamir_shares(
+        sequence: &str,
+        threshold: u8,
+        key_shares_base64: &[String],
+    ) -> Result<String> {
+        if sequence.is_empty() {
+            return Ok(String::new());
+        }
+
+        let parsed_shares = key_shares_base64
+            .iter()
+            .map(|s| shamir::share_from_base64(s))
+            .collect::<Result<Vec<_>>>()?;
+        let k = shamir::reconstruct(&parsed_shares, threshold)?;
+        let k: [u8; SecureDNACrypto::KEY_SIZE] = k.try_into().map_err(|_| {
+            DNACryptoError::DecryptionError("Reconstructed key has wrong length".to_string())
+        })?;
+
+        // Remove markers
+        let core = SecureDNACrypto::remove_markers(sequence);
+
+        // Decode DNA to base64
+        let crypto_string = DNACrypto::decode_sequence(&core)?;
+        if crypto_string.is_empty() {
+            return Ok(String::new());
+        }
+
+        // Parse crypto data
+        let (_mode, compression, _kdf, cipher, encrypted_data, nonce, _salt) =
+            SecureDNACrypto::string_to_crypto_data(&crypto_string)?;
 
-        // Decrypt with K
-        let iv_arr: [u8; SecureDNACrypto::IV_SIZE] = iv
+        // Decrypt with K, then decompress
+        let nonce_arr: [u8; SecureDNACrypto::GCM_NONCE_SIZE] = nonce
             .try_into()
-            .map_err(|_| DNACryptoError::DecryptionError("Invalid IV length".to_string()))?;
+            .map_err(|_| DNACryptoError::DecryptionError("Invalid nonce length".to_string()))?;
 
-        SecureDNACrypto::decrypt_with_key(&encrypted_data, &k, &iv_arr)
+        let payload = SecureDNACrypto::decrypt_with_key(&encrypted_data, &k, &nonce_arr, cipher)?;
+        let decompressed = SecureDNACrypto::decompress(compression, &payload)?;
+        String::from_utf8(decompressed).map_err(|e| DNACryptoError::DecryptionError(e.to_string()).into())
     }
 }
 
@@ -166,4 +544,112 @@ mod tests {
         let result = SplitKeyDNACrypto::decode_with_split_keys(&dna, &k1, &wrong_k2);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_base58check_key_share_roundtrip() {
+        let original = "Secret message for split key";
+        let (dna, k1, k2, _, _) = SplitKeyDNACrypto::encode_with_split_keys_and_format(
+            original,
+            Cipher::AesGcm,
+            Compression::None,
+            KeyShareFormat::Base58Check,
+        )
+        .unwrap();
+        let decoded = SplitKeyDNACrypto::decode_with_split_keys_and_format(&dna, &k1, &k2, KeyShareFormat::Base58Check)
+            .unwrap();
+        assert_eq!(original, decoded);
+    }
+
+    #[test]
+    fn test_base58check_mistyped_key_share_rejected() {
+        let original = "Secret message for split key";
+        let (dna, k1, k2, _, _) = SplitKeyDNACrypto::encode_with_split_keys_and_format(
+            original,
+            Cipher::AesGcm,
+            Compression::None,
+            KeyShareFormat::Base58Check,
+        )
+        .unwrap();
+        let mut mistyped: Vec<char> = k1.chars().collect();
+        let last = mistyped.len() - 1;
+        mistyped[last] = if mistyped[last] == '1' { '2' } else { '1' };
+        let mistyped: String = mistyped.into_iter().collect();
+
+        let result = SplitKeyDNACrypto::decode_with_split_keys_and_format(&dna, &mistyped, &k2, KeyShareFormat::Base58Check);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_shamir_shares_roundtrip_with_threshold_subset() {
+        let original = "escrowed among custodians";
+        let (dna, shares) = SplitKeyDNACrypto::encode_with_shamir_shares(original, 3, 5).unwrap();
+        let decoded =
+            SplitKeyDNACrypto::decode_with_shamir_shares(&dna, 3, &shares[1..4]).unwrap();
+        assert_eq!(original, decoded);
+    }
+
+    #[test]
+    fn test_shamir_shares_empty_message() {
+        let (dna, shares) = SplitKeyDNACrypto::encode_with_shamir_shares("", 3, 5).unwrap();
+        assert_eq!(dna, "");
+        assert!(shares.is_empty());
+        let decoded = SplitKeyDNACrypto::decode_with_shamir_shares("", 3, &[]).unwrap();
+        assert_eq!(decoded, "");
+    }
+
+    #[test]
+    fn test_shamir_shares_below_threshold_fails() {
+        let original = "too few custodians";
+        let (dna, shares) = SplitKeyDNACrypto::encode_with_shamir_shares(original, 3, 5).unwrap();
+        let result = SplitKeyDNACrypto::decode_with_shamir_shares(&dna, 3, &shares[..2]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_passphrase_roundtrip() {
+        let original = "Secret message recoverable from a passphrase alone";
+        let (dna, _k1, _k2) = SplitKeyDNACrypto::encode_with_passphrase(original, "correct horse battery staple").unwrap();
+        let decoded = SplitKeyDNACrypto::decode_with_passphrase(&dna, "correct horse battery staple").unwrap();
+        assert_eq!(original, decoded);
+    }
+
+    #[test]
+    fn test_passphrase_same_phrase_yields_same_keys() {
+        let (_, k1_a, k2_a) = SplitKeyDNACrypto::encode_with_passphrase("first message", "shared phrase").unwrap();
+        let (_, k1_b, k2_b) = SplitKeyDNACrypto::encode_with_passphrase("second message", "shared phrase").unwrap();
+        assert_eq!(k1_a, k1_b);
+        assert_eq!(k2_a, k2_b);
+    }
+
+    #[test]
+    fn test_passphrase_wrong_phrase_fails() {
+        let original = "Secret message recoverable from a passphrase alone";
+        let (dna, _k1, _k2) = SplitKeyDNACrypto::encode_with_passphrase(original, "right phrase").unwrap();
+        let result = SplitKeyDNACrypto::decode_with_passphrase(&dna, "wrong phrase");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_split_keys_with_chacha20poly1305_roundtrip() {
+        let original = "escrowed under chacha20-poly1305";
+        let (dna, k1, k2) =
+            SplitKeyDNACrypto::encode_with_split_keys_and_cipher(original, Cipher::ChaCha20Poly1305)
+                .unwrap();
+        let decoded = SplitKeyDNACrypto::decode_with_split_keys(&dna, &k1, &k2).unwrap();
+        assert_eq!(original, decoded);
+    }
+
+    #[test]
+    fn test_shamir_shares_with_chacha20poly1305_roundtrip() {
+        let original = "escrowed among custodians, chacha20-poly1305";
+        let (dna, shares) = SplitKeyDNACrypto::encode_with_shamir_shares_and_cipher(
+            original,
+            3,
+            5,
+            Cipher::ChaCha20Poly1305,
+        )
+        .unwrap();
+        let decoded = SplitKeyDNACrypto::decode_with_shamir_shares(&dna, 3, &shares[1..4]).unwrap();
+        assert_eq!(original, decoded);
+    }
 }
@@ -11,6 +11,48 @@ pub trait DNACoder {
     fn decode_sequence(sequence: &str) -> Result<String>;
 }
 
+/// Generic message-to-DNA encoder, parameterized over its own output and
+/// error types so a caller can dispatch across modes (via
+/// [`crate::dna::EncodingMode::encoder`]) without matching on every
+/// concrete coder. Implemented by the same unit structs that implement
+/// [`DNACoder`]; modes that need extra input (Secure's password, SplitKey's
+/// key shares) just delegate to their `DNACoder::encode_message`, which
+/// already errors descriptively when called without one.
+pub trait DnaEncoder {
+    type Output;
+    type Error;
+
+    /// Encode raw message bytes.
+    fn encode(&self, input: &[u8]) -> std::result::Result<Self::Output, Self::Error>;
+}
+
+/// Symmetric counterpart to [`DnaEncoder`].
+pub trait DnaDecoder {
+    type Output;
+    type Error;
+
+    /// Decode a DNA sequence (as bytes) back to a message.
+    fn decode(&self, input: &[u8]) -> std::result::Result<Self::Output, Self::Error>;
+}
+
+/// Detached signing/verification over a sequence's digest, independent of
+/// how the sequence itself was encoded. Mirrors [`DNACoder`]'s
+/// associated-function shape (no `self`) so a caller can dispatch by key
+/// algorithm the same way [`crate::dna::EncodingMode::encoder`] dispatches
+/// by mode. Implemented by [`crate::sequence_token::Ed25519Token`] and
+/// [`crate::sequence_token::EcdsaP256Token`].
+pub trait SequenceSigner {
+    /// Sign `sequence`'s digest with `private_key`, returning a compact
+    /// `header.payload.signature` token.
+    fn sign_sequence(sequence: &str, private_key: &[u8]) -> Result<String>;
+
+    /// Verify `token` was produced by [`SequenceSigner::sign_sequence`] for
+    /// `sequence` using the key paired with `public_key`. Errors if the
+    /// token is malformed, its algorithm doesn't match this impl, or the
+    /// signature or payload digest don't match.
+    fn verify_sequence(sequence: &str, token: &str, public_key: &[u8]) -> Result<()>;
+}
+
 /// Sequence statistics trait
 pub trait SequenceStats {
     /// Get statistics about a DNA sequence
@@ -71,6 +71,12 @@ pub enum DNACryptoError {
 
     #[error("Password too weak: {0}")]
     PasswordWeak(String),
+
+    #[error("Authentication failed: ciphertext or markers were tampered with")]
+    AuthenticationFailed,
+
+    #[error("Invalid checksum: key share was mistyped or corrupted")]
+    InvalidChecksum,
 }
 
 /// Safety screener specific errors
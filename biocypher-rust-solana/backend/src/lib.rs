@@ -4,8 +4,12 @@
 
 pub mod api;
 pub mod arcium;
+pub mod auth;
+pub mod credential;
 pub mod dna;
 pub mod error;
 pub mod models;
+pub mod policy;
 pub mod safety;
+pub mod sequence_token;
 pub mod solana;
@@ -7,7 +7,15 @@ use actix_web::{middleware, web, App, HttpServer, HttpResponse, Responder};
 use tracing::{info, level_filters::LevelFilter};
 use tracing_subscriber;
 
-use biocypher_backend::{api, arcium};
+use std::sync::Arc;
+
+use biocypher_backend::{
+    api,
+    arcium::{self, MxeOrderStore},
+    auth::{CapabilityGuard, TrustedRootDid},
+    policy::PolicyStore,
+    solana::{AttestProofStore, SafetyBatcher, SolanaClient},
+};
 
 /// Health check endpoint
 async fn health_check() -> impl Responder {
@@ -28,7 +36,14 @@ async fn root() -> impl Responder {
             "encode": "/api/encode",
             "decode": "/api/decode",
             "safety_screen": "/api/safety-screen",
-            "arcium_info": "/api/arcium-info"
+            "arcium_info": "/api/arcium-info",
+            "encode_private": "/api/encode-private",
+            "mxe_order_status": "/api/mxe/orders/{id}",
+            "attest_credential": "/api/attest/credential",
+            "attest_credential_verify": "/api/attest/credential/verify",
+            "attest_batch": "/api/attest/batch",
+            "attest_proof": "/api/attest/proof/{root}/{index}",
+            "admin_policy_reload": "/api/admin/policy/reload"
         }
     }))
 }
@@ -49,7 +64,49 @@ async fn main() -> std::io::Result<()> {
 
     info!("🚀 Server listening on http://{}", bind_address);
 
-    HttpServer::new(|| {
+    // Root DID that capability tokens guarding biosecurity-sensitive routes
+    // must ultimately be delegated from.
+    let trusted_root_did = std::env::var("BIOCYPHER_TRUSTED_ROOT_DID")
+        .expect("BIOCYPHER_TRUSTED_ROOT_DID must be set to the trusted capability-token issuer DID");
+
+    // Shared with handlers (e.g. `/api/decode`) that verify a delegation
+    // chain against a capability only known once the request body is
+    // parsed, so it can't be fixed at route-registration time via
+    // `CapabilityGuard::new` the way `screen`/`admin` are.
+    let trusted_root_did_data = web::Data::new(TrustedRootDid(Arc::from(trusted_root_did.as_str())));
+
+    // Shared across workers so on-chain safety attestations batch across
+    // requests instead of one transaction per sequence.
+    let safety_batcher = web::Data::new(SafetyBatcher::new());
+
+    // Shared across workers so the background poller below and every
+    // `/api/encode-private` / `/api/mxe/orders/{id}` request see the same
+    // in-flight orders.
+    let mxe_orders = web::Data::new(MxeOrderStore::new());
+
+    // Declarative attestation policy (see `biocypher.toml`); defaults to
+    // allow-all when no config file is present.
+    let attestation_policy = web::Data::new(PolicyStore::load_default());
+
+    // Inclusion proofs for every item of each committed attestation batch,
+    // keyed by the batch's Merkle root.
+    let attest_proofs = web::Data::new(AttestProofStore::new());
+
+    // Internal poller: periodically advances every open MXE order by
+    // querying the chain for its queued-computation or callback state.
+    {
+        let mxe_orders = mxe_orders.clone();
+        actix_web::rt::spawn(async move {
+            let mut interval = actix_web::rt::time::interval(std::time::Duration::from_secs(2));
+            loop {
+                interval.tick().await;
+                let client = SolanaClient::from_env();
+                mxe_orders.advance_all(client.as_ref()).await;
+            }
+        });
+    }
+
+    HttpServer::new(move || {
         let cors = Cors::default()
             .allow_any_origin()
             .allow_any_method()
@@ -57,6 +114,11 @@ async fn main() -> std::io::Result<()> {
             .max_age(3600);
 
         App::new()
+            .app_data(safety_batcher.clone())
+            .app_data(mxe_orders.clone())
+            .app_data(attestation_policy.clone())
+            .app_data(attest_proofs.clone())
+            .app_data(trusted_root_did_data.clone())
             // Middleware
             .wrap(cors)
             .wrap(middleware::Logger::default())
@@ -73,8 +135,23 @@ async fn main() -> std::io::Result<()> {
             .route("/health", web::get().to(health_check))
             .route("/api/encode", web::post().to(api::encode::encode_message))
             .route("/api/decode", web::post().to(api::decode::decode_message))
-            .route("/api/safety-screen", web::post().to(api::safety::safety_screen))
+            .service(
+                web::resource("/api/safety-screen")
+                    .wrap(CapabilityGuard::new(trusted_root_did.clone(), "screen"))
+                    .route(web::post().to(api::safety::safety_screen)),
+            )
             .route("/api/arcium-info", web::get().to(arcium::arcium_info))
+            .route("/api/encode-private", web::post().to(api::mxe::queue_private_encode))
+            .route("/api/mxe/orders/{id}", web::get().to(api::mxe::get_order_status))
+            .route("/api/attest/credential", web::post().to(api::credential::issue_attestation_credential))
+            .route("/api/attest/credential/verify", web::post().to(api::credential::verify_attestation_credential))
+            .route("/api/attest/batch", web::post().to(api::attest_batch::batch_attest))
+            .route("/api/attest/proof/{root}/{index}", web::get().to(api::attest_batch::get_attest_proof))
+            .service(
+                web::resource("/api/admin/policy/reload")
+                    .wrap(CapabilityGuard::new(trusted_root_did.clone(), "admin"))
+                    .route(web::post().to(api::policy::reload_policy)),
+            )
             .service(
                 actix_files::Files::new("/app", concat!(env!("CARGO_MANIFEST_DIR"), "/../static"))
                     .index_file("index.html"),
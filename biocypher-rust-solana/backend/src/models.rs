@@ -3,7 +3,10 @@
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
+use crate::dna::secure::{Cipher, Compression};
+use crate::dna::split_key::KeyShareFormat;
 use crate::dna::EncodingMode;
+use crate::safety::ResistanceProfile;
 
 /// Encode request model
 #[derive(Debug, Clone, Deserialize, Validate)]
@@ -20,6 +23,32 @@ pub struct EncodeRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
 
+    /// AEAD cipher backend for Secure/SplitKey modes. Defaults to
+    /// `AesGcm` if omitted; the choice is recorded in the envelope, so
+    /// decode never needs this field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cipher: Option<Cipher>,
+
+    /// Compression to apply before the binary-to-DNA step. Defaults to
+    /// `None` if omitted; the choice is recorded alongside the sequence
+    /// (in the envelope for Secure/SplitKey, in a small framed header for
+    /// Basic mode), so decode never needs this field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compression: Option<Compression>,
+
+    /// Text representation for the returned `k1_base64`/`k2_base64` fields
+    /// in SplitKey mode. Defaults to `Base64` if omitted; pick
+    /// `Base58Check` for keys a human will copy by hand, since it catches
+    /// a mistyped character instead of silently reconstructing the wrong
+    /// key. The field names stay `k1_base64`/`k2_base64` regardless of the
+    /// format actually used, matching the decode request's fields.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_share_format: Option<KeyShareFormat>,
+
+    /// Escrow callback URL for SplitKey mode (Phase 2)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub escrow_url: Option<String>,
+
     /// Store on blockchain (Phase 2)
     #[serde(default, skip_serializing_if = "is_false")]
     pub store_on_chain: bool,
@@ -48,6 +77,20 @@ pub struct EncodeResponse {
 
     /// Sequence statistics
     pub stats: SequenceStats,
+
+    /// User's half of the split key (SplitKey mode only), in the request's
+    /// `key_share_format`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub k1_base64: Option<String>,
+
+    /// Escrow's half of the split key (SplitKey mode only), in the
+    /// request's `key_share_format`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub k2_base64: Option<String>,
+
+    /// Identifier for the escrow handoff of `k2_base64` (SplitKey mode only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transmission_id: Option<String>,
 }
 
 /// Decode request model
@@ -65,6 +108,21 @@ pub struct DecodeRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub password: Option<String>,
 
+    /// User's half of the split key (required if mode == SplitKey), in
+    /// `key_share_format`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub k1_base64: Option<String>,
+
+    /// Escrow's half of the split key (required if mode == SplitKey), in
+    /// `key_share_format`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub k2_base64: Option<String>,
+
+    /// Text representation of `k1_base64`/`k2_base64`. Defaults to
+    /// `Base64` if omitted; must match what the encode request used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_share_format: Option<KeyShareFormat>,
+
     /// Decode on blockchain (Phase 2)
     #[serde(default, skip_serializing_if = "is_false")]
     pub decode_on_chain: bool,
@@ -120,13 +178,41 @@ pub struct SafetyScreenResponse {
     /// Recommendations
     pub recommendations: Vec<String>,
 
-    /// Transaction signature (Phase 2)
+    /// Signature of the transaction that recorded this result's batch
+    /// Merkle root on-chain (Phase 2)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_signature: Option<String>,
+
+    /// Merkle inclusion proof tying this result to the on-chain batch root
+    /// named by `transaction_signature` (Phase 2)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merkle_proof: Option<MerkleInclusionProof>,
 }
 
-/// Safety status enumeration
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Merkle inclusion proof for a batched on-chain safety attestation.
+///
+/// Hex-encoded so it round-trips through JSON. A third party can
+/// recompute `root` from `leaf` and the `siblings`/`sibling_is_left` pairs
+/// via `solana::merkle::verify_inclusion`, confirming this result was
+/// committed on-chain without trusting whoever issued the proof.
+#[derive(Debug, Clone, Serialize)]
+pub struct MerkleInclusionProof {
+    /// Hex-encoded leaf hash, derived from the sequence hash and safety status.
+    pub leaf: String,
+
+    /// Hex-encoded Merkle root committed on-chain for this batch.
+    pub root: String,
+
+    /// Hex-encoded sibling hash at each level, ordered leaf to root.
+    pub siblings: Vec<String>,
+
+    /// Whether the sibling at the matching index is the left child.
+    pub sibling_is_left: Vec<bool>,
+}
+
+/// Safety status enumeration, ordered by increasing severity so the worst
+/// status across a batch can be found with [`Iterator::max`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum SafetyStatus {
     Safe,
@@ -175,12 +261,29 @@ pub struct PathogenMatch {
     /// Matching signature
     pub signature: String,
 
-    /// Position in sequence
+    /// Position in sequence, in forward-strand coordinates
     pub position: usize,
+
+    /// Hamming distance (substitutions only) between the signature and the
+    /// matched region; zero for an exact match.
+    pub distance: usize,
+
+    /// Strand the match was found on: `'+'` for forward, `'-'` for the
+    /// reverse complement.
+    pub strand: char,
+
+    /// Risk level carried by the matched signature's database entry.
+    pub risk_level: RiskLevel,
+
+    /// Gene, antibiotic class and external codes this signature resolves to
+    /// if it's a known antibiotic-resistance gene; `None` otherwise.
+    pub resistance: Option<ResistanceProfile>,
 }
 
-/// Risk level enumeration
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Risk level enumeration. Variants are declared in ascending severity so
+/// `PartialOrd`/`Ord` can be used directly to find the highest risk among a
+/// set of matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum RiskLevel {
     Low,
@@ -214,8 +317,16 @@ pub struct NaturalMatch {
     /// Matching signature
     pub signature: String,
 
-    /// Position in sequence
+    /// Position in sequence, in forward-strand coordinates
     pub position: usize,
+
+    /// Hamming distance (substitutions only) between the signature and the
+    /// matched region; zero for an exact match.
+    pub distance: usize,
+
+    /// Strand the match was found on: `'+'` for forward, `'-'` for the
+    /// reverse complement.
+    pub strand: char,
 }
 
 /// Sequence characteristics
@@ -256,14 +367,18 @@ pub struct HomopolymerRun {
 /// Open reading frame
 #[derive(Debug, Clone, Serialize)]
 pub struct OpenReadingFrame {
-    /// Start position
+    /// Start position, in forward-strand coordinates
     pub start: usize,
 
-    /// End position
+    /// End position, in forward-strand coordinates
     pub end: usize,
 
-    /// Reading frame
+    /// Reading frame (0-2)
     pub frame: usize,
+
+    /// Strand the ORF was found on: `'+'` for forward, `'-'` for the
+    /// reverse complement.
+    pub strand: char,
 }
 
 /// Repetitive element
@@ -290,6 +405,15 @@ pub struct SequenceStats {
 
     /// GC content percentage
     pub gc_content: f64,
+
+    /// Message bytes before compression (only present when compression
+    /// was applied; lets callers compute the achieved ratio)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_bytes: Option<usize>,
+
+    /// Message bytes after compression
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compressed_bytes: Option<usize>,
 }
 
 /// Base count statistics
@@ -301,6 +425,214 @@ pub struct BaseCounts {
     pub g: usize,
 }
 
+/// W3C Verifiable Credential wrapping an attestation, as issued by
+/// `/api/attest/credential`. This is the off-chain-verifiable counterpart
+/// to the on-chain attestation transactions built by
+/// [`crate::solana::build_attest_transaction`]: the same `operation` and
+/// `seq_hash` a wallet would otherwise sign into a Solana transaction are
+/// asserted here instead, signed with the server's ed25519 key, and
+/// presented as a compact JWS.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifiableCredential {
+    /// JSON-LD context
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+
+    /// Credential types, always `["VerifiableCredential", "BiocypherAttestation"]`
+    #[serde(rename = "type")]
+    pub credential_type: Vec<String>,
+
+    /// The attestation being asserted
+    pub credential_subject: CredentialSubject,
+}
+
+/// Claims asserted by a [`VerifiableCredential`] about one attestation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialSubject {
+    /// `"encode"`, `"decode"`, or `"safety"`
+    pub operation: String,
+
+    /// Hex-encoded `hash_sequence` output for the attested sequence
+    pub seq_hash: String,
+
+    /// Encoding mode (encode/decode operations only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<EncodingMode>,
+
+    /// Safety status (safety operations only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<SafetyStatus>,
+
+    /// Unix timestamp (seconds) the credential was issued
+    pub issued_at: i64,
+}
+
+/// Standard JWT claims wrapping a [`VerifiableCredential`], the payload of
+/// the compact JWS returned by `/api/attest/credential`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialClaims {
+    /// Issuer DID, derived from the signing ed25519 public key
+    pub iss: String,
+
+    /// Subject DID (same as `iss`: the server attests to its own observation)
+    pub sub: String,
+
+    /// Not-valid-before, Unix timestamp (seconds)
+    pub nbf: i64,
+
+    /// The wrapped credential
+    pub vc: VerifiableCredential,
+}
+
+/// Response for `/api/attest/credential`: a compact JWS the caller can
+/// present to any verifier, e.g. back to `/api/attest/credential/verify`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CredentialResponse {
+    /// `base64url(header).base64url(payload).base64url(signature)`
+    pub jws: String,
+}
+
+/// Request to verify a credential JWS previously issued by
+/// `/api/attest/credential`.
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct VerifyCredentialRequest {
+    #[validate(length(min = 1))]
+    pub jws: String,
+}
+
+/// Response for credential verification.
+#[derive(Debug, Clone, Serialize)]
+pub struct VerifyCredentialResponse {
+    /// Whether the signature is valid and the JWS is well-formed
+    pub valid: bool,
+
+    /// Decoded claims, present only if `valid`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claims: Option<CredentialClaims>,
+}
+
+/// Request to queue a confidential DNA encoding computation on the Arcium
+/// MXE. The message must already be encrypted client-side (see
+/// `biocypher-mxe/encrypted-ixs`); the server only ever handles
+/// ciphertext.
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct EncodePrivateRequest {
+    /// Base64-encoded ciphertext, as 32-byte Arcium ciphertext words.
+    #[validate(length(min = 1))]
+    pub ciphertext: String,
+
+    /// Base64-encoded x25519 public key (32 bytes) the ciphertext was
+    /// encrypted under, used by the MXE to derive the shared secret for
+    /// its encrypted output.
+    #[validate(length(min = 1))]
+    pub client_pubkey: String,
+
+    /// Base64-encoded nonce (16 bytes) paired with `client_pubkey`.
+    #[validate(length(min = 1))]
+    pub nonce: String,
+}
+
+/// Response for `POST /api/encode-private` and `GET /api/mxe/orders/{id}`:
+/// the current state of an [`crate::arcium::orders::MxeOrder`].
+#[derive(Debug, Clone, Serialize)]
+pub struct MxeOrderResponse {
+    pub id: String,
+    pub status: crate::arcium::orders::MxeOrderStatus,
+    pub status_url: String,
+
+    /// Base64-encoded encrypted DNA result, present once `status` is `VALID`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_ciphertext: Option<String>,
+
+    /// Reason the order became `INVALID`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// Present while not terminal: hint for how long to wait before
+    /// polling again.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after_secs: Option<u64>,
+}
+
+/// One item in a `POST /api/attest/batch` request — the same fields as
+/// [`crate::api::build_attest::BuildAttestRequest`] minus `payer`, since a
+/// batch's root (not each item) is what a payer signs for on-chain.
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct AttestBatchItem {
+    #[validate(length(min = 1))]
+    pub operation: String,
+
+    #[validate(length(min = 1))]
+    pub sequence: String,
+
+    #[serde(default)]
+    pub mode: Option<EncodingMode>,
+
+    #[serde(default)]
+    pub status: Option<SafetyStatus>,
+}
+
+/// Request for `POST /api/attest/batch`.
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct AttestBatchRequest {
+    #[validate(length(min = 1, message = "At least one item is required"))]
+    pub items: Vec<AttestBatchItem>,
+}
+
+/// Hex-encoded sibling hash and left/right bit for one level of a Merkle
+/// inclusion proof.
+#[derive(Debug, Clone, Serialize)]
+pub struct AttestProofStep {
+    pub sibling: String,
+    pub sibling_is_left: bool,
+}
+
+/// One item's result within an [`AttestBatchResponse`].
+#[derive(Debug, Clone, Serialize)]
+pub struct AttestBatchItemResult {
+    pub leaf_index: usize,
+    pub leaf: String,
+    pub proof: Vec<AttestProofStep>,
+}
+
+/// Response for `POST /api/attest/batch`.
+#[derive(Debug, Clone, Serialize)]
+pub struct AttestBatchResponse {
+    pub root: String,
+    pub batch_size: usize,
+    pub tree_height: usize,
+    pub items: Vec<AttestBatchItemResult>,
+}
+
+/// Aggregate result across many sequence records from a batch screening run
+/// (e.g. a FASTA/FASTQ synthesis order).
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchScreeningSummary {
+    /// Number of records screened
+    pub total: usize,
+
+    /// Records that came back `Safe`
+    pub safe_count: usize,
+
+    /// Records that came back `Caution`
+    pub caution_count: usize,
+
+    /// Records that came back `Unsafe`
+    pub unsafe_count: usize,
+
+    /// Worst status observed across the batch (`Safe` if it was empty)
+    pub worst_status: SafetyStatus,
+}
+
+/// Response for `GET /api/attest/proof/{root}/{index}`.
+#[derive(Debug, Clone, Serialize)]
+pub struct AttestProofResponse {
+    pub root: String,
+    pub leaf_index: usize,
+    pub leaf: String,
+    pub proof: Vec<AttestProofStep>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
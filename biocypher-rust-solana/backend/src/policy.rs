@@ -0,0 +1,296 @@
+//! Declarative, TOML-loaded policy gating on-chain attestation requests.
+//!
+//! Borrows the idea of a declarative validation config from enclave
+//! attestation tooling: rather than hardcoding who may request an
+//! attestation transaction and for which operations/modes, an operator
+//! drops a `biocypher.toml` next to the binary (or points
+//! `BIOCYPHER_POLICY_PATH` at one) describing the rules. With no config
+//! file present, every request is allowed, so existing behavior is
+//! unchanged until an operator opts in.
+//!
+//! ```toml
+//! allowed_operations = ["encode", "safety"]
+//! payer_allowlist = ["9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin"]
+//! max_sequence_len = 2000
+//! require_status_for = ["safety"]
+//!
+//! [allowed_modes]
+//! encode = ["basic", "secure"]
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+
+use actix_web::HttpResponse;
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::dna::EncodingMode;
+use crate::models::SafetyStatus;
+
+/// Default location [`PolicyStore::load_default`] looks for, relative to
+/// the working directory the server was started from.
+pub const DEFAULT_POLICY_PATH: &str = "biocypher.toml";
+
+/// Declarative attestation policy, loaded from TOML. Every field is
+/// optional; an absent field means "no restriction" along that dimension.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AttestationPolicy {
+    /// Operations a `build_attest` request may name. `None` allows any.
+    #[serde(default)]
+    pub allowed_operations: Option<HashSet<String>>,
+
+    /// Per-operation allowed `EncodingMode`s. An operation absent from
+    /// this map is unrestricted; one present but mapped to an empty list
+    /// allows no mode at all.
+    #[serde(default)]
+    pub allowed_modes: HashMap<String, Vec<EncodingMode>>,
+
+    /// Base58 payer pubkeys allowed to request a transaction. `None`
+    /// allows any payer.
+    #[serde(default)]
+    pub payer_allowlist: Option<HashSet<String>>,
+
+    /// Maximum `sequence` length (characters). `None` means unbounded.
+    #[serde(default)]
+    pub max_sequence_len: Option<usize>,
+
+    /// Operations that must carry a `SafetyStatus`.
+    #[serde(default)]
+    pub require_status_for: HashSet<String>,
+}
+
+/// One rule an attestation request failed, reported back to the caller so
+/// they know exactly what to fix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyViolation {
+    OperationNotAllowed(String),
+    ModeNotAllowed { operation: String, mode: EncodingMode },
+    PayerNotAllowlisted(String),
+    SequenceTooLong { len: usize, max: usize },
+    StatusRequired(String),
+}
+
+impl std::fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolicyViolation::OperationNotAllowed(op) => {
+                write!(f, "operation '{}' is not permitted by policy", op)
+            }
+            PolicyViolation::ModeNotAllowed { operation, mode } => {
+                write!(f, "mode '{}' is not permitted for operation '{}' by policy", mode, operation)
+            }
+            PolicyViolation::PayerNotAllowlisted(payer) => {
+                write!(f, "payer '{}' is not on the policy allowlist", payer)
+            }
+            PolicyViolation::SequenceTooLong { len, max } => {
+                write!(f, "sequence length {} exceeds policy maximum {}", len, max)
+            }
+            PolicyViolation::StatusRequired(op) => {
+                write!(f, "operation '{}' requires a status by policy", op)
+            }
+        }
+    }
+}
+
+impl PolicyViolation {
+    /// Structured HTTP response describing which rule failed. Payer/mode
+    /// restrictions are authorization decisions (403); the rest are
+    /// malformed-request decisions (400).
+    pub fn into_response(self) -> HttpResponse {
+        let body = serde_json::json!({
+            "error": "Rejected by attestation policy",
+            "reason": self.to_string(),
+        });
+        match self {
+            PolicyViolation::PayerNotAllowlisted(_)
+            | PolicyViolation::ModeNotAllowed { .. }
+            | PolicyViolation::OperationNotAllowed(_) => HttpResponse::Forbidden().json(body),
+            PolicyViolation::SequenceTooLong { .. } | PolicyViolation::StatusRequired(_) => {
+                HttpResponse::BadRequest().json(body)
+            }
+        }
+    }
+}
+
+impl AttestationPolicy {
+    /// Check a prospective attestation request against this policy,
+    /// returning the first rule it fails.
+    pub fn evaluate(
+        &self,
+        operation: &str,
+        sequence: &str,
+        payer: &Pubkey,
+        mode: Option<EncodingMode>,
+        status: Option<SafetyStatus>,
+    ) -> Result<(), PolicyViolation> {
+        if let Some(allowed) = &self.allowed_operations {
+            if !allowed.contains(operation) {
+                return Err(PolicyViolation::OperationNotAllowed(operation.to_string()));
+            }
+        }
+
+        if let (Some(modes), Some(mode)) = (self.allowed_modes.get(operation), mode) {
+            if !modes.contains(&mode) {
+                return Err(PolicyViolation::ModeNotAllowed {
+                    operation: operation.to_string(),
+                    mode,
+                });
+            }
+        }
+
+        if let Some(allowlist) = &self.payer_allowlist {
+            if !allowlist.contains(&payer.to_string()) {
+                return Err(PolicyViolation::PayerNotAllowlisted(payer.to_string()));
+            }
+        }
+
+        if let Some(max_len) = self.max_sequence_len {
+            if sequence.len() > max_len {
+                return Err(PolicyViolation::SequenceTooLong {
+                    len: sequence.len(),
+                    max: max_len,
+                });
+            }
+        }
+
+        if self.require_status_for.contains(operation) && status.is_none() {
+            return Err(PolicyViolation::StatusRequired(operation.to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Thread-safe, hot-reloadable policy store, shared across requests as
+/// `web::Data<PolicyStore>`.
+pub struct PolicyStore {
+    path: String,
+    policy: RwLock<AttestationPolicy>,
+}
+
+impl PolicyStore {
+    /// Load from `BIOCYPHER_POLICY_PATH` (default [`DEFAULT_POLICY_PATH`]).
+    /// A missing file resolves to "allow all", preserving pre-policy
+    /// behavior for operators who haven't opted in.
+    pub fn load_default() -> Self {
+        let path = std::env::var("BIOCYPHER_POLICY_PATH")
+            .unwrap_or_else(|_| DEFAULT_POLICY_PATH.to_string());
+        let policy = Self::read(&path).unwrap_or_default();
+        Self {
+            path,
+            policy: RwLock::new(policy),
+        }
+    }
+
+    fn read(path: &str) -> Option<AttestationPolicy> {
+        let contents = std::fs::read_to_string(path).ok()?;
+        toml::from_str(&contents).ok()
+    }
+
+    /// Re-read the policy file from disk, replacing the in-memory policy.
+    /// A file that's gone resets to "allow all"; a file that fails to
+    /// parse leaves the previously loaded policy in place rather than
+    /// locking every request out over a config typo.
+    pub fn reload(&self) {
+        let mut guard = self.policy.write().expect("policy lock poisoned");
+        if !std::path::Path::new(&self.path).exists() {
+            *guard = AttestationPolicy::default();
+        } else if let Some(policy) = Self::read(&self.path) {
+            *guard = policy;
+        }
+    }
+
+    /// Clone of the currently active policy.
+    pub fn current(&self) -> AttestationPolicy {
+        self.policy.read().expect("policy lock poisoned").clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payer() -> Pubkey {
+        Pubkey::new_unique()
+    }
+
+    #[test]
+    fn test_default_policy_allows_everything() {
+        let policy = AttestationPolicy::default();
+        assert!(policy
+            .evaluate("encode", "ATCG", &payer(), Some(EncodingMode::Basic), None)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_operation_not_allowlisted_is_rejected() {
+        let mut policy = AttestationPolicy::default();
+        policy.allowed_operations = Some(["encode".to_string()].into_iter().collect());
+        let result = policy.evaluate("decode", "ATCG", &payer(), Some(EncodingMode::Basic), None);
+        assert_eq!(result, Err(PolicyViolation::OperationNotAllowed("decode".to_string())));
+    }
+
+    #[test]
+    fn test_mode_not_allowed_for_operation_is_rejected() {
+        let mut policy = AttestationPolicy::default();
+        policy
+            .allowed_modes
+            .insert("encode".to_string(), vec![EncodingMode::Secure]);
+        let result = policy.evaluate("encode", "ATCG", &payer(), Some(EncodingMode::Basic), None);
+        assert_eq!(
+            result,
+            Err(PolicyViolation::ModeNotAllowed {
+                operation: "encode".to_string(),
+                mode: EncodingMode::Basic,
+            })
+        );
+    }
+
+    #[test]
+    fn test_payer_not_on_allowlist_is_rejected() {
+        let mut policy = AttestationPolicy::default();
+        let allowed = payer();
+        policy.payer_allowlist = Some([allowed.to_string()].into_iter().collect());
+        let result = policy.evaluate("encode", "ATCG", &payer(), None, None);
+        assert!(matches!(result, Err(PolicyViolation::PayerNotAllowlisted(_))));
+        assert!(policy.evaluate("encode", "ATCG", &allowed, None, None).is_ok());
+    }
+
+    #[test]
+    fn test_sequence_too_long_is_rejected() {
+        let mut policy = AttestationPolicy::default();
+        policy.max_sequence_len = Some(3);
+        let result = policy.evaluate("encode", "ATCG", &payer(), None, None);
+        assert_eq!(result, Err(PolicyViolation::SequenceTooLong { len: 4, max: 3 }));
+    }
+
+    #[test]
+    fn test_missing_required_status_is_rejected() {
+        let mut policy = AttestationPolicy::default();
+        policy.require_status_for.insert("safety".to_string());
+        let result = policy.evaluate("safety", "ATCG", &payer(), None, None);
+        assert_eq!(result, Err(PolicyViolation::StatusRequired("safety".to_string())));
+        assert!(policy
+            .evaluate("safety", "ATCG", &payer(), None, Some(SafetyStatus::Safe))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_store_defaults_to_allow_all_when_file_missing() {
+        std::env::remove_var("BIOCYPHER_POLICY_PATH");
+        let store = PolicyStore {
+            path: "/nonexistent/biocypher.toml".to_string(),
+            policy: RwLock::new(AttestationPolicy::default()),
+        };
+        assert!(store
+            .current()
+            .evaluate("encode", "ATCG", &payer(), Some(EncodingMode::Basic), None)
+            .is_ok());
+        store.reload();
+        assert!(store
+            .current()
+            .evaluate("encode", "ATCG", &payer(), Some(EncodingMode::Basic), None)
+            .is_ok());
+    }
+}
@@ -3,6 +3,12 @@
 //! Analyzes DNA sequences for potential pathogen risks and natural occurrence
 //! Ported from Python: biocypher/safety_screener.py
 
+pub mod pathogen_filter;
+pub mod resistance;
 pub mod screener;
+pub mod signature_db;
 
+pub use pathogen_filter::PathogenFilter;
+pub use resistance::{AntibioticClass, ResistanceProfile};
 pub use screener::DNASafetyScreener;
+pub use signature_db::SignatureDatabase;
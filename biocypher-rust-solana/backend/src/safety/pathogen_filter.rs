@@ -0,0 +1,274 @@
+//! Golomb-Rice coded pathogen k-mer filter
+//!
+//! A compressed probabilistic membership filter (a Golomb-Coded Set) that
+//! stands in front of the exact pathogen signature lookup in
+//! [`crate::safety::screener::DNASafetyScreener`]. As the signature database
+//! grows, keeping every k-mer resident in an uncompressed set becomes
+//! expensive; this filter lets a large k-mer set be queried from a few bits
+//! per entry with a tunable false-positive rate of `1/M` and no false
+//! negatives.
+//!
+//! Each k-mer is hashed with a fixed-key SipHash-1-3, scaled into
+//! `[0, N*M)` (`N` entries, `M = 2^P`), sorted, and the successive deltas
+//! between scaled values are Golomb-Rice coded: quotient `q = d >> P` in
+//! unary followed by the low `P` bits of `d` as the remainder. A query scales
+//! its own hash the same way and streams the deltas, reconstructing
+//! cumulative sums until one meets or exceeds the target.
+
+use siphasher::sip::SipHasher13;
+use std::hash::Hasher;
+
+/// Fixed SipHash key so the same k-mer always scales to the same value
+/// across builds (membership testing, not adversarial hashing).
+const SIPHASH_KEY: (u64, u64) = (0x5349_4745_4E41_5455, 0x5041_5448_4F47_454E);
+
+/// Golomb-Rice coded set over 64-bit hashed, scaled k-mers.
+pub struct PathogenFilter {
+    /// Golomb-Rice parameter: remainder width in bits (`M = 2^p`).
+    p: u32,
+    /// Number of entries this filter was built over (before dedup), so the
+    /// scaling universe can be reconstructed identically on query.
+    n: usize,
+    /// Golomb-Rice coded delta stream, bit-packed MSB-first.
+    bits: Vec<u8>,
+    /// Number of valid bits in `bits` (the final byte may be zero-padded).
+    bit_len: usize,
+}
+
+impl PathogenFilter {
+    /// Build a filter over `kmers` with Golomb-Rice parameter `p`
+    /// (`M = 2^p` controls the false-positive rate `1/M`: larger `p` means
+    /// fewer false positives at the cost of more bits per entry).
+    pub fn build(kmers: &[String], p: u32) -> Self {
+        // `n` is the entry count the scaling universe is derived from, so it
+        // must stay the *pre-dedup* count and must never be overwritten with
+        // `scaled.len()` afterwards - `contains` has no way to know how many
+        // entries collided, so it can only reconstruct the same universe
+        // `build` used if `n` always means "entries this filter was built
+        // over", not "distinct scaled values after dedup".
+        let n = kmers.len().max(1);
+        let universe = Self::universe(n, p);
+
+        let mut scaled: Vec<u64> = kmers.iter().map(|kmer| Self::scale_hash(kmer, universe)).collect();
+        scaled.sort_unstable();
+        scaled.dedup();
+
+        let mut writer = BitWriter::new();
+        let mut prev = 0u64;
+        for value in &scaled {
+            Self::write_golomb_rice(&mut writer, value - prev, p);
+            prev = *value;
+        }
+
+        let (bits, bit_len) = writer.into_parts();
+        Self { p, n, bits, bit_len }
+    }
+
+    /// Check whether `kmer` may be a member. A `true` result can be a false
+    /// positive (at rate `1/M`); a `false` result means `kmer` is definitely
+    /// not in the set that built this filter.
+    pub fn contains(&self, kmer: &str) -> bool {
+        if self.n == 0 {
+            return false;
+        }
+        let universe = Self::universe(self.n, self.p);
+        let target = Self::scale_hash(kmer, universe);
+
+        let mut reader = BitReader::new(&self.bits, self.bit_len);
+        let mut cumulative = 0u64;
+        while let Some(delta) = Self::read_golomb_rice(&mut reader, self.p) {
+            cumulative += delta;
+            if cumulative == target {
+                return true;
+            }
+            if cumulative > target {
+                return false;
+            }
+        }
+        false
+    }
+
+    /// Serialize the filter to a compact byte blob: `p` (1 byte), `n` and
+    /// `bit_len` (8 bytes little-endian each), then the packed Golomb-Rice
+    /// stream.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(17 + self.bits.len());
+        out.push(self.p as u8);
+        out.extend_from_slice(&(self.n as u64).to_le_bytes());
+        out.extend_from_slice(&(self.bit_len as u64).to_le_bytes());
+        out.extend_from_slice(&self.bits);
+        out
+    }
+
+    /// Parse a filter produced by [`Self::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 17 {
+            return None;
+        }
+        let p = bytes[0] as u32;
+        let n = u64::from_le_bytes(bytes[1..9].try_into().ok()?) as usize;
+        let bit_len = u64::from_le_bytes(bytes[9..17].try_into().ok()?) as usize;
+        Some(Self { p, n, bits: bytes[17..].to_vec(), bit_len })
+    }
+
+    fn universe(n: usize, p: u32) -> u64 {
+        (n as u64).saturating_mul(1u64 << p).max(1)
+    }
+
+    fn scale_hash(kmer: &str, universe: u64) -> u64 {
+        let mut hasher = SipHasher13::new_with_keys(SIPHASH_KEY.0, SIPHASH_KEY.1);
+        hasher.write(kmer.to_uppercase().as_bytes());
+        let hash = hasher.finish();
+        ((hash as u128 * universe as u128) >> 64) as u64
+    }
+
+    fn write_golomb_rice(writer: &mut BitWriter, delta: u64, p: u32) {
+        let quotient = delta >> p;
+        for _ in 0..quotient {
+            writer.push_bit(1);
+        }
+        writer.push_bit(0);
+        for i in (0..p).rev() {
+            writer.push_bit(((delta >> i) & 1) as u8);
+        }
+    }
+
+    fn read_golomb_rice(reader: &mut BitReader, p: u32) -> Option<u64> {
+        let mut quotient = 0u64;
+        loop {
+            match reader.next_bit()? {
+                1 => quotient += 1,
+                _ => break,
+            }
+        }
+        let mut remainder = 0u64;
+        for _ in 0..p {
+            remainder = (remainder << 1) | reader.next_bit()? as u64;
+        }
+        Some((quotient << p) | remainder)
+    }
+}
+
+/// Appends bits MSB-first into a byte buffer, zero-padding the final byte.
+struct BitWriter {
+    bytes: Vec<u8>,
+    cur: u8,
+    cur_len: u8,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self { bytes: Vec::new(), cur: 0, cur_len: 0 }
+    }
+
+    fn push_bit(&mut self, bit: u8) {
+        self.cur = (self.cur << 1) | (bit & 1);
+        self.cur_len += 1;
+        if self.cur_len == 8 {
+            self.bytes.push(self.cur);
+            self.cur = 0;
+            self.cur_len = 0;
+        }
+    }
+
+    fn into_parts(mut self) -> (Vec<u8>, usize) {
+        let bit_len = self.bytes.len() * 8 + self.cur_len as usize;
+        if self.cur_len > 0 {
+            self.cur <<= 8 - self.cur_len;
+            self.bytes.push(self.cur);
+        }
+        (self.bytes, bit_len)
+    }
+}
+
+/// Reads bits MSB-first out of a byte slice, stopping at `bit_len`.
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    bit_len: usize,
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8], bit_len: usize) -> Self {
+        Self { bytes, bit_len, pos: 0 }
+    }
+
+    fn next_bit(&mut self) -> Option<u8> {
+        if self.pos >= self.bit_len {
+            return None;
+        }
+        let byte = self.bytes[self.pos / 8];
+        let bit = (byte >> (7 - (self.pos % 8))) & 1;
+        self.pos += 1;
+        Some(bit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_kmers() -> Vec<String> {
+        vec![
+            "ATGGATCCGTATGAC".to_string(),
+            "CCGTATGACTCCATG".to_string(),
+            "ATGAAGCTGTATGAC".to_string(),
+            "GGGTCATACAGCTTC".to_string(),
+            "ATGAGCCATATTCAA".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_contains_all_built_kmers() {
+        let kmers = sample_kmers();
+        let filter = PathogenFilter::build(&kmers, 8);
+        for kmer in &kmers {
+            assert!(filter.contains(kmer));
+        }
+    }
+
+    #[test]
+    fn test_no_false_negatives_with_larger_set() {
+        let kmers: Vec<String> = (0..500).map(|i| format!("KMER{:05}SAMPLEDATA", i)).collect();
+        let filter = PathogenFilter::build(&kmers, 10);
+        for kmer in &kmers {
+            assert!(filter.contains(kmer));
+        }
+    }
+
+    #[test]
+    fn test_absent_kmer_usually_rejected() {
+        let kmers = sample_kmers();
+        let filter = PathogenFilter::build(&kmers, 10);
+        assert!(!filter.contains("TOTALLYABSENTKMER"));
+    }
+
+    #[test]
+    fn test_duplicate_kmers_do_not_cause_false_negatives() {
+        let kmers = vec![
+            "AAAAAAAAAAAAAAAA".to_string(),
+            "AAAAAAAAAAAAAAAA".to_string(),
+            "CCCCCCCCCCCCCCCC".to_string(),
+        ];
+        let filter = PathogenFilter::build(&kmers, 8);
+        assert!(filter.contains("AAAAAAAAAAAAAAAA"));
+        assert!(filter.contains("CCCCCCCCCCCCCCCC"));
+    }
+
+    #[test]
+    fn test_empty_filter_contains_nothing() {
+        let filter = PathogenFilter::build(&[], 8);
+        assert!(!filter.contains("ANYTHING"));
+    }
+
+    #[test]
+    fn test_serialization_roundtrip() {
+        let kmers = sample_kmers();
+        let filter = PathogenFilter::build(&kmers, 8);
+        let bytes = filter.to_bytes();
+        let restored = PathogenFilter::from_bytes(&bytes).unwrap();
+        for kmer in &kmers {
+            assert!(restored.contains(kmer));
+        }
+    }
+}
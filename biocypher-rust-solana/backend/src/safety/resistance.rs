@@ -0,0 +1,84 @@
+//! Types describing the gene, drug class and external identifiers a matched
+//! antibiotic-resistance pathogen signature carries, mirroring how
+//! antimicrobial-resistance surveillance tools group raw detections into
+//! standards-coded resistance profiles instead of a bare "resistance
+//! detected" flag.
+//!
+//! A profile is attached directly to a [`crate::safety::signature_db::SignatureEntry`]
+//! by whoever supplies the signature database, the same way `risk_level` is,
+//! rather than resolved from a fixed table of known sequences - an operator
+//! loading their own `antibiotic_resistance` signatures (via
+//! [`crate::safety::signature_db::SignatureDatabase::from_json`]) carries
+//! their own resistance metadata along with them.
+
+use serde::{Deserialize, Serialize};
+
+/// Antibiotic class a resistance gene confers resistance to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AntibioticClass {
+    BetaLactam,
+    Aminoglycoside,
+}
+
+impl std::fmt::Display for AntibioticClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AntibioticClass::BetaLactam => write!(f, "beta-lactam"),
+            AntibioticClass::Aminoglycoside => write!(f, "aminoglycoside"),
+        }
+    }
+}
+
+/// An external terminology reference for a resistance gene (e.g. SNOMED CT
+/// or Gene Ontology), so a report can be cross-referenced against other
+/// systems instead of only carrying a free-text gene name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalCode {
+    pub system: String,
+    pub code: String,
+}
+
+impl ExternalCode {
+    pub fn new(system: &str, code: &str) -> Self {
+        Self {
+            system: system.to_string(),
+            code: code.to_string(),
+        }
+    }
+}
+
+/// Gene, drug class and external identifiers an antibiotic-resistance
+/// signature represents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResistanceProfile {
+    pub gene: String,
+    pub antibiotic_class: AntibioticClass,
+    #[serde(default)]
+    pub codes: Vec<ExternalCode>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resistance_profile_json_roundtrip() {
+        let profile = ResistanceProfile {
+            gene: "blaTEM-1".to_string(),
+            antibiotic_class: AntibioticClass::BetaLactam,
+            codes: vec![ExternalCode::new("SNOMED", "716881009")],
+        };
+        let json = serde_json::to_string(&profile).unwrap();
+        let parsed: ResistanceProfile = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.gene, "blaTEM-1");
+        assert_eq!(parsed.antibiotic_class, AntibioticClass::BetaLactam);
+        assert_eq!(parsed.codes[0].code, "716881009");
+    }
+
+    #[test]
+    fn test_antibiotic_class_display() {
+        assert_eq!(AntibioticClass::BetaLactam.to_string(), "beta-lactam");
+        assert_eq!(AntibioticClass::Aminoglycoside.to_string(), "aminoglycoside");
+    }
+}
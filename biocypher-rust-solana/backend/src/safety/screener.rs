@@ -3,17 +3,89 @@
 //! Analyzes DNA sequences for potential pathogen risks and natural occurrence
 //! Ported from Python: biocypher/safety_screener.py
 
+use std::io::Read;
+
 use crate::dna::traits::SequenceStatistics;
 use crate::error::{SafetyScreenerError as Error, Result};
 use crate::models::*;
+use crate::safety::pathogen_filter::PathogenFilter;
+use crate::safety::signature_db::SignatureDatabase;
 
 /// DNA Safety Screening System
-pub struct DNASafetyScreener;
+pub struct DNASafetyScreener {
+    /// Compressed Golomb-Rice pre-screen over known pathogen signature
+    /// k-mers, checked before the exact signature search below.
+    pathogen_filter: PathogenFilter,
+
+    /// Maximum Hamming distance (substitutions only) tolerated when
+    /// matching a signature, so a sequence that differs from a known
+    /// signature by a handful of SNPs is still flagged. Zero reproduces the
+    /// previous exact-match-only behavior.
+    max_mismatches: usize,
+
+    /// Pathogen, housekeeping-gene and genome signatures screened against,
+    /// plus the policy mapping a matched risk level to a safety status.
+    database: SignatureDatabase,
+}
 
 impl DNASafetyScreener {
-    /// Create new safety screener instance
+    /// Length of the k-mers indexed in `pathogen_filter` (the shortest
+    /// pathogen signature, so every signature contributes at least one k-mer).
+    const PATHOGEN_KMER_LEN: usize = 16;
+
+    /// Golomb-Rice tuning parameter: `M = 2^P` false-positive rate.
+    const PATHOGEN_FILTER_P: u32 = 8;
+
+    /// Default Hamming distance tolerated by [`Self::new`]: tolerate a
+    /// single substitution so a lone SNP doesn't let a signature slip past.
+    ///
+    /// Note this is `> 0`, which means `scan_pathogen_signatures`'s
+    /// Golomb-Rice pre-screen (exact-match-only) never gets to skip a scan
+    /// for a screener built via `Self::new`; it only still earns its keep for
+    /// callers that opt into `with_max_mismatches(0)`.
+    const DEFAULT_MAX_MISMATCHES: usize = 1;
+
+    /// Create new safety screener instance, using the built-in signature
+    /// database.
     pub fn new() -> Self {
-        Self
+        Self::with_max_mismatches(Self::DEFAULT_MAX_MISMATCHES)
+    }
+
+    /// Create a screener that tolerates up to `max_mismatches` substitutions
+    /// when matching pathogen and natural-occurrence signatures, for callers
+    /// that want to tune fuzziness (e.g. `0` for exact-match-only screening).
+    /// Uses the built-in signature database; see [`Self::with_database`] to
+    /// screen against an operator-supplied one instead.
+    pub fn with_max_mismatches(max_mismatches: usize) -> Self {
+        Self::build(SignatureDatabase::built_in(), max_mismatches)
+    }
+
+    /// Create a screener backed by an operator-loaded signature database
+    /// (see [`SignatureDatabase::load`]) instead of the built-in tables, at
+    /// the default Hamming-distance tolerance.
+    pub fn with_database(database: SignatureDatabase) -> Self {
+        Self::build(database, Self::DEFAULT_MAX_MISMATCHES)
+    }
+
+    fn build(database: SignatureDatabase, max_mismatches: usize) -> Self {
+        let kmers = Self::signature_kmers(&database);
+        Self {
+            pathogen_filter: PathogenFilter::build(&kmers, Self::PATHOGEN_FILTER_P),
+            max_mismatches,
+            database,
+        }
+    }
+
+    /// All `PATHOGEN_KMER_LEN`-length k-mers contained in every pathogen
+    /// signature in `database`.
+    fn signature_kmers(database: &SignatureDatabase) -> Vec<String> {
+        let mut kmers = Vec::new();
+        for entry in database.pathogen_entries() {
+            for window in entry.signature.as_bytes().windows(Self::PATHOGEN_KMER_LEN) {
+                kmers.push(String::from_utf8_lossy(window).to_string());
+            }
+        }
+        kmers
     }
 
     /// Perform comprehensive safety screening
@@ -51,9 +123,61 @@ impl DNASafetyScreener {
             natural_occurrence: natural_check,
             sequence_characteristics: characteristics,
             recommendations,
+            database_version: self.database.version.clone(),
         })
     }
 
+    /// Screen every record in a FASTA file, one record at a time, so a
+    /// whole synthesis order or assembly can be screened without loading it
+    /// all into memory. Returns each record's id paired with its report, in
+    /// file order.
+    pub fn screen_fasta<R: Read>(&self, reader: R) -> Result<Vec<(String, SafetyReport)>> {
+        let mut reports = Vec::new();
+        for result in bio::io::fasta::Reader::new(reader).records() {
+            let record = result?;
+            let sequence = String::from_utf8_lossy(record.seq()).to_string();
+            let report = self.perform_comprehensive_screening(&sequence)?;
+            reports.push((record.id().to_string(), report));
+        }
+        Ok(reports)
+    }
+
+    /// Screen every record in a FASTQ file; see [`Self::screen_fasta`].
+    /// Quality scores aren't consulted - only the base calls are screened.
+    pub fn screen_fastq<R: Read>(&self, reader: R) -> Result<Vec<(String, SafetyReport)>> {
+        let mut reports = Vec::new();
+        for result in bio::io::fastq::Reader::new(reader).records() {
+            let record = result?;
+            let sequence = String::from_utf8_lossy(record.seq()).to_string();
+            let report = self.perform_comprehensive_screening(&sequence)?;
+            reports.push((record.id().to_string(), report));
+        }
+        Ok(reports)
+    }
+
+    /// Summarize a batch of screening reports: counts per status, plus the
+    /// worst status observed (an empty batch summarizes as `Safe`).
+    pub fn summarize_batch(reports: &[(String, SafetyReport)]) -> BatchScreeningSummary {
+        let mut summary = BatchScreeningSummary {
+            total: reports.len(),
+            safe_count: 0,
+            caution_count: 0,
+            unsafe_count: 0,
+            worst_status: SafetyStatus::Safe,
+        };
+
+        for (_, report) in reports {
+            match report.safety_status {
+                SafetyStatus::Safe => summary.safe_count += 1,
+                SafetyStatus::Caution => summary.caution_count += 1,
+                SafetyStatus::Unsafe => summary.unsafe_count += 1,
+            }
+            summary.worst_status = summary.worst_status.max(report.safety_status);
+        }
+
+        summary
+    }
+
     /// Clean DNA sequence (remove non-ATCG characters)
     fn clean_sequence(&self, sequence: &str) -> Result<String> {
         let cleaned: String = sequence
@@ -68,47 +192,69 @@ impl DNASafetyScreener {
         Ok(cleaned)
     }
 
-    /// Check for known pathogen signatures
-    fn check_pathogen_signatures(&self, sequence: &str) -> PathogenAnalysis {
-        // Simplified pathogen signatures
-        let signatures = vec![
-            ("viral_polymerase", vec!["ATGGATCCGTATGACTCC", "CCGTATGACTCCATGG"]),
-            ("toxin_genes", vec!["ATGAAGCTGTATGACCC", "GGGTCATACAGCTTCAT"]),
-            ("antibiotic_resistance", vec![
-                "ATGAGCCATATTCAACG", "CGTTGAATATGGCTCAT",
-                "ATGTCGCAGTTCGATCC", "GGATCGAACTGCGACAT"
-            ]),
-            ("virulence_factors", vec!["ATGCTGAAACGTTATGC", "GCATAACGTTTCAGCAT"]),
-        ];
+    /// Scan a single strand (already uppercased, `'+'` forward or `'-'`
+    /// reverse complement) for pathogen signatures, reporting positions in
+    /// forward-strand coordinates via `original_len`.
+    fn scan_pathogen_signatures(
+        &self,
+        strand_sequence: &str,
+        strand: char,
+        original_len: usize,
+    ) -> Vec<PathogenMatch> {
+        // Fast pre-screen: if none of the query's own k-mers are possible
+        // members of the compressed pathogen k-mer set, no *exact* signature
+        // can match (the filter has no false negatives), so skip the search
+        // below entirely. A tolerated substitution can knock out every k-mer
+        // overlapping it, so the pre-screen only holds when we require an
+        // exact match; fuzzy screening always falls through to the full scan.
+        // `DEFAULT_MAX_MISMATCHES` is `1`, so this is dead weight for every
+        // `DNASafetyScreener::new()` instance today - it only pays for itself
+        // for a caller that explicitly asks for exact matching via
+        // `with_max_mismatches(0)`.
+        let may_contain_signature = self.max_mismatches > 0
+            || strand_sequence
+                .as_bytes()
+                .windows(Self::PATHOGEN_KMER_LEN)
+                .any(|window| self.pathogen_filter.contains(&String::from_utf8_lossy(window)));
 
         let mut matches = Vec::new();
-        let sequence_upper = sequence.to_uppercase();
 
-        for (category, sigs) in signatures {
-            for signature in sigs {
-                if let Some(pos) = sequence_upper.find(signature) {
+        if may_contain_signature {
+            let text = strand_sequence.as_bytes();
+            for entry in self.database.pathogen_entries() {
+                for (position, distance) in
+                    approximate_matches(text, entry.signature.as_bytes(), self.max_mismatches)
+                {
                     matches.push(PathogenMatch {
-                        category: category.to_string(),
-                        signature: signature.to_string(),
-                        position: pos,
+                        category: entry.name.clone(),
+                        signature: entry.signature.clone(),
+                        position: forward_position(position, entry.signature.len(), strand, original_len),
+                        distance,
+                        strand,
+                        risk_level: entry.risk_level,
+                        resistance: entry.resistance.clone(),
                     });
                 }
             }
         }
 
-        let (pathogen_risk, risk_level) = if !matches.is_empty() {
-            if matches.iter().any(|m|
-                m.category == "toxin_genes" || m.category == "virulence_factors"
-            ) {
-                (true, RiskLevel::High)
-            } else if matches.iter().any(|m| m.category == "antibiotic_resistance") {
-                (true, RiskLevel::Medium)
-            } else {
-                (true, RiskLevel::Low)
-            }
-        } else {
-            (false, RiskLevel::Low)
-        };
+        matches
+    }
+
+    /// Check for known pathogen signatures
+    fn check_pathogen_signatures(&self, sequence: &str) -> PathogenAnalysis {
+        let sequence_upper = sequence.to_uppercase();
+        let reverse_upper = crate::dna::reverse_complement(&sequence_upper);
+
+        let mut matches = self.scan_pathogen_signatures(&sequence_upper, '+', sequence_upper.len());
+        matches.extend(self.scan_pathogen_signatures(&reverse_upper, '-', sequence_upper.len()));
+
+        let pathogen_risk = !matches.is_empty();
+        let risk_level = matches
+            .iter()
+            .map(|m| m.risk_level)
+            .max()
+            .unwrap_or(RiskLevel::Low);
 
         PathogenAnalysis {
             pathogen_risk,
@@ -117,42 +263,31 @@ impl DNASafetyScreener {
         }
     }
 
-    /// Check for natural genome occurrences
-    fn check_natural_occurrence(&self, sequence: &str) -> NaturalOccurrence {
-        // Simplified natural genome signatures
-        let housekeeping = vec![
-            ("ribosomal_rna", "TACCTGGTTGATCCTGC"),
-            ("actin", "ATGGATGATGATATCGC"),
-            ("gapdh", "ATGGTGAAGGTCGGTGT"),
-            ("tubulin", "ATGCGTGAGATCGTGCA"),
-        ];
-
-        let ecoli = vec![
-            "GATCCTGGAAAGTGCAG",
-            "CTGCACTTTCCAGGATC",
-            "ATGAAACGCATTAGCAC",
-            "GTGCTAATGCGTTTCAT",
-        ];
-
-        let human = vec![
-            "ATGCCCTGTGATTTCGG",
-            "CCGAAATCACAGGGCAT",
-            "GAGCTGAAGGGCGTGAA",
-            "TTCACGCCCTTCAGCTC",
-        ];
-
+    /// Scan a single strand (already uppercased, `'+'` forward or `'-'`
+    /// reverse complement) for natural genome signatures, reporting
+    /// positions in forward-strand coordinates via `original_len`.
+    fn scan_natural_occurrence(
+        &self,
+        strand_sequence: &str,
+        strand: char,
+        original_len: usize,
+    ) -> (Vec<NaturalMatch>, Vec<String>) {
         let mut matches = Vec::new();
         let mut organisms = Vec::new();
-        let sequence_upper = sequence.to_uppercase();
+        let text = strand_sequence.as_bytes();
 
         // Check housekeeping genes
-        for (gene, signature) in housekeeping {
-            if let Some(pos) = sequence_upper.find(signature) {
+        for entry in self.database.housekeeping_entries() {
+            for (position, distance) in
+                approximate_matches(text, entry.signature.as_bytes(), self.max_mismatches)
+            {
                 matches.push(NaturalMatch {
                     match_type: "housekeeping_gene".to_string(),
-                    name: gene.to_string(),
-                    signature: signature.to_string(),
-                    position: pos,
+                    name: entry.name.clone(),
+                    signature: entry.signature.clone(),
+                    position: forward_position(position, entry.signature.len(), strand, original_len),
+                    distance,
+                    strand,
                 });
                 if !organisms.contains(&"Multiple species (housekeeping)".to_string()) {
                     organisms.push("Multiple species (housekeeping)".to_string());
@@ -160,33 +295,42 @@ impl DNASafetyScreener {
             }
         }
 
-        // Check E. coli
-        for signature in ecoli {
-            if let Some(pos) = sequence_upper.find(signature) {
+        // Check genome signatures (e.g. E. coli, human)
+        for entry in self.database.genome_entries() {
+            for (position, distance) in
+                approximate_matches(text, entry.signature.as_bytes(), self.max_mismatches)
+            {
                 matches.push(NaturalMatch {
                     match_type: "genome_signature".to_string(),
-                    name: "E. coli".to_string(),
-                    signature: signature.to_string(),
-                    position: pos,
+                    name: entry.name.clone(),
+                    signature: entry.signature.clone(),
+                    position: forward_position(position, entry.signature.len(), strand, original_len),
+                    distance,
+                    strand,
                 });
-                if !organisms.contains(&"E. coli".to_string()) {
-                    organisms.push("E. coli".to_string());
+                if !organisms.contains(&entry.name) {
+                    organisms.push(entry.name.clone());
                 }
             }
         }
 
-        // Check human
-        for signature in human {
-            if let Some(pos) = sequence_upper.find(signature) {
-                matches.push(NaturalMatch {
-                    match_type: "genome_signature".to_string(),
-                    name: "Human".to_string(),
-                    signature: signature.to_string(),
-                    position: pos,
-                });
-                if !organisms.contains(&"Human".to_string()) {
-                    organisms.push("Human".to_string());
-                }
+        (matches, organisms)
+    }
+
+    /// Check for natural genome occurrences
+    fn check_natural_occurrence(&self, sequence: &str) -> NaturalOccurrence {
+        let sequence_upper = sequence.to_uppercase();
+        let reverse_upper = crate::dna::reverse_complement(&sequence_upper);
+
+        let (mut matches, mut organisms) =
+            self.scan_natural_occurrence(&sequence_upper, '+', sequence_upper.len());
+        let (reverse_matches, reverse_organisms) =
+            self.scan_natural_occurrence(&reverse_upper, '-', sequence_upper.len());
+
+        matches.extend(reverse_matches);
+        for organism in reverse_organisms {
+            if !organisms.contains(&organism) {
+                organisms.push(organism);
             }
         }
 
@@ -240,32 +384,12 @@ impl DNASafetyScreener {
             }
         }
 
-        // Find ORFs (simplified)
-        let start_codons = vec!["ATG"];
-        let stop_codons = vec!["TAA", "TAG", "TGA"];
-
-        for frame in 0..3 {
-            let mut i = frame;
-            while i + 2 < sequence.len() {
-                let codon = &sequence[i..i+3];
-                if start_codons.contains(&codon) {
-                    for j in (i + 3..sequence.len() - 2).step_by(3) {
-                        let stop_codon = &sequence[j..j+3];
-                        if stop_codons.contains(&stop_codon) {
-                            if j - i >= 30 {
-                                orfs.push(OpenReadingFrame {
-                                    start: i,
-                                    end: j + 3,
-                                    frame,
-                                });
-                            }
-                            break;
-                        }
-                    }
-                }
-                i += 3;
-            }
-        }
+        // Find ORFs across all six reading frames (3 forward, 3 reverse
+        // complement) - a toxin or virulence gene can just as easily be
+        // encoded on the reverse strand.
+        let reverse_sequence = crate::dna::reverse_complement(sequence);
+        orfs.extend(find_orfs_in_frames(sequence, '+', sequence.len()));
+        orfs.extend(find_orfs_in_frames(&reverse_sequence, '-', sequence.len()));
 
         if !orfs.is_empty() {
             warnings.push(format!("Found {} potential protein-coding sequences", orfs.len()));
@@ -306,10 +430,7 @@ impl DNASafetyScreener {
         characteristics: &SequenceCharacteristics,
     ) -> SafetyStatus {
         if pathogen_check.pathogen_risk {
-            return match pathogen_check.risk_level {
-                RiskLevel::High => SafetyStatus::Unsafe,
-                RiskLevel::Medium | RiskLevel::Low => SafetyStatus::Caution,
-            };
+            return self.database.risk_status.status_for(pathogen_check.risk_level);
         }
 
         if natural_check.natural_occurrence {
@@ -342,6 +463,21 @@ impl DNASafetyScreener {
         if pathogen_check.pathogen_risk {
             recommendations.push("❌ DO NOT SYNTHESIZE - Pathogen signatures detected".to_string());
             recommendations.push("🔬 Consult biosafety experts before proceeding".to_string());
+
+            let mut resistant_classes: Vec<String> = pathogen_check
+                .matches
+                .iter()
+                .filter_map(|m| m.resistance.as_ref())
+                .map(|r| r.antibiotic_class.to_string())
+                .collect();
+            resistant_classes.sort();
+            resistant_classes.dedup();
+            if !resistant_classes.is_empty() {
+                recommendations.push(format!(
+                    "💊 Confers resistance to: {}",
+                    resistant_classes.join(", ")
+                ));
+            }
         }
 
         if natural_check.natural_occurrence {
@@ -368,6 +504,104 @@ impl DNASafetyScreener {
     }
 }
 
+/// Find open reading frames (simplified: first stop codon in-frame after an
+/// ATG, with a minimum length) across all three reading frames of a single
+/// strand, reporting start/end in forward-strand coordinates via
+/// `original_len`.
+fn find_orfs_in_frames(strand_sequence: &str, strand: char, original_len: usize) -> Vec<OpenReadingFrame> {
+    let start_codons = vec!["ATG"];
+    let stop_codons = vec!["TAA", "TAG", "TGA"];
+    let mut orfs = Vec::new();
+
+    for frame in 0..3 {
+        let mut i = frame;
+        while i + 2 < strand_sequence.len() {
+            let codon = &strand_sequence[i..i + 3];
+            if start_codons.contains(&codon) {
+                for j in (i + 3..strand_sequence.len() - 2).step_by(3) {
+                    let stop_codon = &strand_sequence[j..j + 3];
+                    if stop_codons.contains(&stop_codon) {
+                        let length = j + 3 - i;
+                        if length >= 30 {
+                            let start = forward_position(i, length, strand, original_len);
+                            orfs.push(OpenReadingFrame {
+                                start,
+                                end: start + length,
+                                frame,
+                                strand,
+                            });
+                        }
+                        break;
+                    }
+                }
+            }
+            i += 3;
+        }
+    }
+
+    orfs
+}
+
+/// Translate a match position found in a strand's own sequence back into
+/// forward-strand coordinates. Forward-strand positions pass through
+/// unchanged; a reverse-complement position `p` of a `match_len`-long match
+/// covers forward bases `[original_len - p - match_len, original_len - p)`.
+fn forward_position(position: usize, match_len: usize, strand: char, original_len: usize) -> usize {
+    match strand {
+        '-' => original_len - position - match_len,
+        _ => position,
+    }
+}
+
+/// Longest pattern [`approximate_matches`] can search for - its registers
+/// are single `u64`s indexed by pattern position, so a longer pattern can't
+/// fit. [`crate::safety::signature_db::SignatureDatabase`] rejects any
+/// signature over this length at load time rather than silently loading one
+/// that would never match here.
+pub(crate) const MAX_SIGNATURE_LEN: usize = 64;
+
+/// Bit-parallel approximate (Hamming-distance) substring search, a
+/// Wu-Manber-style extension of the classic bitap algorithm. `Peq[c]` is a
+/// bitmask over `pattern`'s `m` positions with bit `i` set iff
+/// `pattern[i] == c`. `registers[d]` tracks, as an `m`-bit mask, which
+/// pattern prefixes match the text ending at the current base with at most
+/// `d` substitutions; bit `m - 1` set means the whole pattern matches there.
+/// Only substitutions are modeled (no insertions/deletions), matching the
+/// SNP-style variation these signatures are screened for. Patterns over
+/// `MAX_SIGNATURE_LEN` bases can't fit a single `u64` register and are
+/// skipped.
+fn approximate_matches(text: &[u8], pattern: &[u8], max_mismatches: usize) -> Vec<(usize, usize)> {
+    let m = pattern.len();
+    if m == 0 || m > MAX_SIGNATURE_LEN || text.len() < m {
+        return Vec::new();
+    }
+
+    let mut peq = [0u64; 256];
+    for (i, &base) in pattern.iter().enumerate() {
+        peq[base as usize] |= 1 << i;
+    }
+    let top_bit = 1u64 << (m - 1);
+
+    let mut registers = vec![0u64; max_mismatches + 1];
+    let mut found = Vec::new();
+
+    for (j, &base) in text.iter().enumerate() {
+        let eq = peq[base as usize];
+        let previous = registers.clone();
+
+        registers[0] = ((registers[0] << 1) | 1) & eq;
+        for d in 1..=max_mismatches {
+            registers[d] = (((registers[d] << 1) | 1) & eq) | (previous[d - 1] << 1);
+        }
+
+        if let Some(distance) = registers.iter().position(|r| r & top_bit != 0) {
+            found.push((j + 1 - m, distance));
+        }
+    }
+
+    found
+}
+
 /// Internal safety report
 pub struct SafetyReport {
     pub dna_sequence: String,
@@ -376,6 +610,10 @@ pub struct SafetyReport {
     pub natural_occurrence: NaturalOccurrence,
     pub sequence_characteristics: SequenceCharacteristics,
     pub recommendations: Vec<String>,
+
+    /// Version of the [`SignatureDatabase`] this report was screened
+    /// against, so results stay attributable after the database is updated.
+    pub database_version: String,
 }
 
 #[cfg(test)]
@@ -426,4 +664,179 @@ mod tests {
         let check = screener.check_natural_occurrence("ATGGATGATGATATCGC");
         assert!(check.natural_occurrence);
     }
+
+    #[test]
+    fn test_pathogen_filter_prescreen_skips_clean_sequence() {
+        // The Golomb-Rice pre-screen is only sound for exact matching, so
+        // exercise it with max_mismatches = 0.
+        let screener = DNASafetyScreener::with_max_mismatches(0);
+        let analysis = screener.check_pathogen_signatures("ACACACACACACACACACAC");
+        assert!(!analysis.pathogen_risk);
+        assert!(analysis.matches.is_empty());
+    }
+
+    #[test]
+    fn test_pathogen_detection_tolerates_single_snp() {
+        // "ATGGATCCGTATGACTCC" with one base flipped (T -> C at index 1).
+        let screener = DNASafetyScreener::with_max_mismatches(1);
+        let analysis = screener.check_pathogen_signatures("ACGGATCCGTATGACTCC");
+        assert!(analysis.pathogen_risk);
+        let hit = analysis
+            .matches
+            .iter()
+            .find(|m| m.signature == "ATGGATCCGTATGACTCC")
+            .expect("expected a fuzzy match for the mutated signature");
+        assert_eq!(hit.distance, 1);
+    }
+
+    #[test]
+    fn test_pathogen_detection_rejects_snp_beyond_tolerance() {
+        let screener = DNASafetyScreener::with_max_mismatches(0);
+        let analysis = screener.check_pathogen_signatures("ACGGATCCGTATGACTCC");
+        assert!(!analysis
+            .matches
+            .iter()
+            .any(|m| m.signature == "ATGGATCCGTATGACTCC"));
+    }
+
+    #[test]
+    fn test_approximate_matches_exact_match_has_zero_distance() {
+        let hits = approximate_matches(b"ACGTACGT", b"ACGT", 0);
+        assert!(hits.contains(&(0, 0)));
+        assert!(hits.contains(&(4, 0)));
+    }
+
+    #[test]
+    fn test_approximate_matches_reports_smallest_distance() {
+        // "ACGT" vs "ACCT" differ at one position.
+        let hits = approximate_matches(b"ACCT", b"ACGT", 2);
+        let (position, distance) = hits
+            .iter()
+            .find(|&&(pos, _)| pos == 0)
+            .copied()
+            .expect("expected a match at position 0");
+        assert_eq!(position, 0);
+        assert_eq!(distance, 1);
+    }
+
+    #[test]
+    fn test_approximate_matches_pattern_too_long_is_skipped() {
+        let pattern = vec![b'A'; 65];
+        let text = vec![b'A'; 65];
+        assert!(approximate_matches(&text, &pattern, 0).is_empty());
+    }
+
+    #[test]
+    fn test_pathogen_detection_finds_reverse_strand_signature() {
+        let screener = DNASafetyScreener::with_max_mismatches(0);
+        // Reverse complement of "ATGGATCCGTATGACTCC".
+        let analysis = screener.check_pathogen_signatures("GGAGTCATACGGATCCAT");
+        let hit = analysis
+            .matches
+            .iter()
+            .find(|m| m.signature == "ATGGATCCGTATGACTCC")
+            .expect("expected to find the signature on the reverse strand");
+        assert_eq!(hit.strand, '-');
+        assert_eq!(hit.position, 0);
+    }
+
+    #[test]
+    fn test_find_orfs_in_frames_translates_reverse_positions() {
+        let sequence = "CCATGAAAAAAAAAAAAAAAAAAAAAAAAAAATAAGGGG";
+        let orfs = find_orfs_in_frames(sequence, '-', sequence.len());
+        let orf = orfs.first().expect("expected one ORF");
+        assert_eq!(orf.strand, '-');
+        assert_eq!(orf.frame, 2);
+        assert_eq!(orf.start, 4);
+        assert_eq!(orf.end, 37);
+    }
+
+    #[test]
+    fn test_screen_fasta_returns_one_report_per_record() {
+        let screener = DNASafetyScreener::new();
+        let fasta = b">seq1\nATCGATCGATCGATCG\n>seq2\nATGGATCCGTATGACTCC\n";
+        let reports = screener.screen_fasta(&fasta[..]).unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].0, "seq1");
+        assert_eq!(reports[1].0, "seq2");
+    }
+
+    #[test]
+    fn test_screen_fastq_returns_one_report_per_record() {
+        let screener = DNASafetyScreener::new();
+        let fastq = b"@seq1\nATCGATCGATCGATCG\n+\nIIIIIIIIIIIIIIII\n";
+        let reports = screener.screen_fastq(&fastq[..]).unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].0, "seq1");
+    }
+
+    #[test]
+    fn test_with_database_detects_custom_signature() {
+        let tsv = "category\tname\tsignature\trisk_level\npathogen\tcustom_toxin\tAAAACCCCGGGGTTTTAA\thigh\n";
+        let database = crate::safety::signature_db::SignatureDatabase::from_tsv(tsv, "custom-1").unwrap();
+        let screener = DNASafetyScreener::with_database(database);
+
+        let analysis = screener.check_pathogen_signatures("AAAACCCCGGGGTTTTAA");
+        assert!(analysis.pathogen_risk);
+        assert_eq!(analysis.risk_level, RiskLevel::High);
+
+        let report = screener
+            .perform_comprehensive_screening("AAAACCCCGGGGTTTTAA")
+            .unwrap();
+        assert_eq!(report.database_version, "custom-1");
+        assert_eq!(report.safety_status, SafetyStatus::Unsafe);
+    }
+
+    #[test]
+    fn test_built_in_database_version_flows_into_report() {
+        let screener = DNASafetyScreener::new();
+        let report = screener
+            .perform_comprehensive_screening("ATCGATCGATCGATCG")
+            .unwrap();
+        assert_eq!(report.database_version, "built-in");
+    }
+
+    #[test]
+    fn test_antibiotic_resistance_match_resolves_gene_and_class() {
+        let screener = DNASafetyScreener::with_max_mismatches(0);
+        let analysis = screener.check_pathogen_signatures("ATGAGCCATATTCAACG");
+        let hit = analysis
+            .matches
+            .iter()
+            .find(|m| m.signature == "ATGAGCCATATTCAACG")
+            .expect("expected an antibiotic-resistance match");
+        let resistance = hit.resistance.as_ref().expect("expected a resolved resistance profile");
+        assert_eq!(resistance.gene, "blaTEM-1");
+        assert_eq!(resistance.antibiotic_class, crate::safety::AntibioticClass::BetaLactam);
+    }
+
+    #[test]
+    fn test_recommendations_surface_resistant_drug_classes() {
+        let screener = DNASafetyScreener::with_max_mismatches(0);
+        let report = screener
+            .perform_comprehensive_screening("ATGAGCCATATTCAACG")
+            .unwrap();
+        assert!(report
+            .recommendations
+            .iter()
+            .any(|r| r.contains("beta-lactam")));
+    }
+
+    #[test]
+    fn test_summarize_batch_tracks_worst_status() {
+        let screener = DNASafetyScreener::new();
+        let safe = screener
+            .perform_comprehensive_screening("ATCGATCGATCGATCG")
+            .unwrap();
+        let flagged = screener
+            .perform_comprehensive_screening("ATGAAGCTGTATGACCC")
+            .unwrap();
+        let reports = vec![("safe".to_string(), safe), ("risky".to_string(), flagged)];
+
+        let summary = DNASafetyScreener::summarize_batch(&reports);
+        assert_eq!(summary.total, 2);
+        assert_eq!(summary.safe_count, 1);
+        assert_eq!(summary.unsafe_count, 1);
+        assert_eq!(summary.worst_status, SafetyStatus::Unsafe);
+    }
 }
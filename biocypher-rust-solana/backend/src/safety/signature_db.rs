@@ -0,0 +1,482 @@
+//! Versioned, operator-loadable pathogen/natural-occurrence signature
+//! database.
+//!
+//! The signature tables were previously `vec!`s compiled straight into the
+//! binary, so updating the watch-list meant shipping a new build. This
+//! loads them from a TSV or JSON file instead - mirroring how external
+//! breakpoint/reference tables are kept outside application code - so an
+//! operator can swap in a curated or private watch-list via
+//! [`crate::safety::DNASafetyScreener::with_database`] without recompiling.
+//!
+//! TSV schema: one header row, then one row per signature:
+//! `category\tname\tsignature\trisk_level`, where `category` is one of
+//! `pathogen`/`housekeeping`/`genome` and `risk_level` is one of
+//! `low`/`medium`/`high`. A TSV file has nowhere to declare its own version
+//! or risk-to-status policy, so [`SignatureDatabase::from_tsv`] takes the
+//! version as a parameter and falls back to [`RiskStatusMapping::default`].
+//!
+//! JSON schema mirrors the same fields plus top-level `version` and an
+//! optional `risk_status` override, and lets an entry carry an optional
+//! `resistance` profile (gene, antibiotic class, external codes) - a TSV row
+//! has nowhere to express a nested object, so TSV-loaded entries always get
+//! `resistance: None`, the same way they always fall back to
+//! [`RiskStatusMapping::default`]:
+//!
+//! ```json
+//! {
+//!   "version": "2026-07-31",
+//!   "risk_status": {"low": "CAUTION", "medium": "CAUTION", "high": "UNSAFE"},
+//!   "entries": [
+//!     {"category": "pathogen", "name": "toxin_genes", "signature": "ATGAAGCTGTATGACCC", "risk_level": "high"},
+//!     {
+//!       "category": "pathogen", "name": "antibiotic_resistance", "signature": "ATGAGCCATATTCAACG", "risk_level": "medium",
+//!       "resistance": {"gene": "blaTEM-1", "antibiotic_class": "beta-lactam", "codes": [{"system": "SNOMED", "code": "716881009"}]}
+//!     }
+//!   ]
+//! }
+//! ```
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::error::{BioCypherError, Result};
+use crate::models::{RiskLevel, SafetyStatus};
+use crate::safety::resistance::{ExternalCode, ResistanceProfile};
+use crate::safety::screener::MAX_SIGNATURE_LEN;
+
+/// Which of the screener's three signature tables an entry belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SignatureCategory {
+    /// A pathogen gene signature (e.g. `toxin_genes`, `antibiotic_resistance`).
+    Pathogen,
+    /// A conserved housekeeping gene shared across species.
+    Housekeeping,
+    /// A whole-genome fingerprint for a specific organism.
+    Genome,
+}
+
+/// One watched nucleotide signature.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SignatureEntry {
+    pub category: SignatureCategory,
+    /// For `Pathogen` entries, the pathogen category (e.g. `toxin_genes`);
+    /// for `Housekeeping`/`Genome` entries, the gene or organism name.
+    pub name: String,
+    /// Nucleotide signature (upper-case A/T/C/G).
+    pub signature: String,
+    /// Risk this signature represents if matched.
+    pub risk_level: RiskLevel,
+    /// Gene/drug-class/external-code profile this signature carries if it's
+    /// a known antibiotic-resistance marker. `None` for any entry that isn't
+    /// (and for every TSV-loaded entry, which has no field for it).
+    #[serde(default)]
+    pub resistance: Option<ResistanceProfile>,
+}
+
+/// Maps each [`RiskLevel`] to the [`SafetyStatus`] it should produce, so
+/// that policy is data carried by the database rather than a fixed `match`
+/// in [`crate::safety::DNASafetyScreener::determine_status`].
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct RiskStatusMapping {
+    pub low: SafetyStatus,
+    pub medium: SafetyStatus,
+    pub high: SafetyStatus,
+}
+
+impl Default for RiskStatusMapping {
+    /// Reproduces the status mapping the screener had hardcoded before it
+    /// became data-driven.
+    fn default() -> Self {
+        Self {
+            low: SafetyStatus::Caution,
+            medium: SafetyStatus::Caution,
+            high: SafetyStatus::Unsafe,
+        }
+    }
+}
+
+impl RiskStatusMapping {
+    pub fn status_for(&self, risk_level: RiskLevel) -> SafetyStatus {
+        match risk_level {
+            RiskLevel::Low => self.low,
+            RiskLevel::Medium => self.medium,
+            RiskLevel::High => self.high,
+        }
+    }
+}
+
+/// Versioned collection of watched signatures plus the risk-to-status
+/// policy, loadable from a TSV or JSON file.
+#[derive(Debug, Clone)]
+pub struct SignatureDatabase {
+    pub version: String,
+    pub entries: Vec<SignatureEntry>,
+    pub risk_status: RiskStatusMapping,
+}
+
+impl SignatureDatabase {
+    /// The signatures and risk-to-status policy the screener had hardcoded
+    /// before the database became loadable; used by `DNASafetyScreener::new`.
+    pub fn built_in() -> Self {
+        Self {
+            version: "built-in".to_string(),
+            risk_status: RiskStatusMapping::default(),
+            entries: Self::built_in_entries(),
+        }
+    }
+
+    fn built_in_entries() -> Vec<SignatureEntry> {
+        // Each antibiotic-resistance signature's forward and reverse-complement
+        // sequence carries the same resistance profile, since
+        // `DNASafetyScreener` screens both strands independently and either
+        // one matching should resolve to the same gene.
+        let bla_tem_1 = ResistanceProfile {
+            gene: "blaTEM-1".to_string(),
+            antibiotic_class: crate::safety::AntibioticClass::BetaLactam,
+            codes: vec![
+                ExternalCode::new("SNOMED", "716881009"),
+                ExternalCode::new("GO", "GO:0008800"),
+            ],
+        };
+        let aac_6_ib = ResistanceProfile {
+            gene: "aac(6')-Ib".to_string(),
+            antibiotic_class: crate::safety::AntibioticClass::Aminoglycoside,
+            codes: vec![
+                ExternalCode::new("SNOMED", "716860002"),
+                ExternalCode::new("GO", "GO:0016410"),
+            ],
+        };
+
+        let pathogen = [
+            ("viral_polymerase", RiskLevel::Low, vec![
+                ("ATGGATCCGTATGACTCC", None), ("CCGTATGACTCCATGG", None),
+            ]),
+            ("toxin_genes", RiskLevel::High, vec![
+                ("ATGAAGCTGTATGACCC", None), ("GGGTCATACAGCTTCAT", None),
+            ]),
+            ("antibiotic_resistance", RiskLevel::Medium, vec![
+                ("ATGAGCCATATTCAACG", Some(bla_tem_1.clone())), ("CGTTGAATATGGCTCAT", Some(bla_tem_1)),
+                ("ATGTCGCAGTTCGATCC", Some(aac_6_ib.clone())), ("GGATCGAACTGCGACAT", Some(aac_6_ib)),
+            ]),
+            ("virulence_factors", RiskLevel::High, vec![
+                ("ATGCTGAAACGTTATGC", None), ("GCATAACGTTTCAGCAT", None),
+            ]),
+        ];
+
+        let housekeeping = [
+            ("ribosomal_rna", "TACCTGGTTGATCCTGC"),
+            ("actin", "ATGGATGATGATATCGC"),
+            ("gapdh", "ATGGTGAAGGTCGGTGT"),
+            ("tubulin", "ATGCGTGAGATCGTGCA"),
+        ];
+
+        let ecoli = [
+            "GATCCTGGAAAGTGCAG",
+            "CTGCACTTTCCAGGATC",
+            "ATGAAACGCATTAGCAC",
+            "GTGCTAATGCGTTTCAT",
+        ];
+
+        let human = [
+            "ATGCCCTGTGATTTCGG",
+            "CCGAAATCACAGGGCAT",
+            "GAGCTGAAGGGCGTGAA",
+            "TTCACGCCCTTCAGCTC",
+        ];
+
+        let mut entries = Vec::new();
+
+        for (name, risk_level, signatures) in pathogen {
+            for (signature, resistance) in signatures {
+                entries.push(SignatureEntry {
+                    category: SignatureCategory::Pathogen,
+                    name: name.to_string(),
+                    signature: signature.to_string(),
+                    risk_level,
+                    resistance,
+                });
+            }
+        }
+
+        for (gene, signature) in housekeeping {
+            entries.push(SignatureEntry {
+                category: SignatureCategory::Housekeeping,
+                name: gene.to_string(),
+                signature: signature.to_string(),
+                risk_level: RiskLevel::Low,
+                resistance: None,
+            });
+        }
+
+        for signature in ecoli {
+            entries.push(SignatureEntry {
+                category: SignatureCategory::Genome,
+                name: "E. coli".to_string(),
+                signature: signature.to_string(),
+                risk_level: RiskLevel::Low,
+                resistance: None,
+            });
+        }
+
+        for signature in human {
+            entries.push(SignatureEntry {
+                category: SignatureCategory::Genome,
+                name: "Human".to_string(),
+                signature: signature.to_string(),
+                risk_level: RiskLevel::Low,
+                resistance: None,
+            });
+        }
+
+        entries
+    }
+
+    /// Parse a tab-separated signature table (see module docs for the
+    /// schema). `version` is carried through to every [`crate::models::SafetyReport`]
+    /// produced using this database.
+    pub fn from_tsv(contents: &str, version: impl Into<String>) -> Result<Self> {
+        let mut entries = Vec::new();
+
+        for (i, line) in contents.lines().enumerate() {
+            if i == 0 || line.trim().is_empty() {
+                continue; // header row / blank line
+            }
+
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() != 4 {
+                return Err(BioCypherError::Validation(format!(
+                    "signature TSV line {} has {} fields, expected 4",
+                    i + 1,
+                    fields.len()
+                )));
+            }
+
+            let category = match fields[0] {
+                "pathogen" => SignatureCategory::Pathogen,
+                "housekeeping" => SignatureCategory::Housekeeping,
+                "genome" => SignatureCategory::Genome,
+                other => {
+                    return Err(BioCypherError::Validation(format!(
+                        "signature TSV line {} has unknown category '{}'",
+                        i + 1,
+                        other
+                    )))
+                }
+            };
+
+            let risk_level = match fields[3].to_lowercase().as_str() {
+                "low" => RiskLevel::Low,
+                "medium" => RiskLevel::Medium,
+                "high" => RiskLevel::High,
+                other => {
+                    return Err(BioCypherError::Validation(format!(
+                        "signature TSV line {} has unknown risk level '{}'",
+                        i + 1,
+                        other
+                    )))
+                }
+            };
+
+            let signature = fields[2].to_uppercase();
+            if signature.len() > MAX_SIGNATURE_LEN {
+                return Err(BioCypherError::Validation(format!(
+                    "signature TSV line {} has a {}-base signature, longer than the {}-base limit the matcher supports",
+                    i + 1,
+                    signature.len(),
+                    MAX_SIGNATURE_LEN
+                )));
+            }
+
+            entries.push(SignatureEntry {
+                category,
+                name: fields[1].to_string(),
+                signature,
+                risk_level,
+                resistance: None,
+            });
+        }
+
+        Ok(Self {
+            version: version.into(),
+            entries,
+            risk_status: RiskStatusMapping::default(),
+        })
+    }
+
+    /// Parse a JSON signature database (see module docs for the schema).
+    pub fn from_json(contents: &str) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct Raw {
+            version: String,
+            #[serde(default)]
+            risk_status: RiskStatusMapping,
+            entries: Vec<SignatureEntry>,
+        }
+
+        let raw: Raw = serde_json::from_str(contents).map_err(|e| {
+            BioCypherError::Validation(format!("invalid signature database JSON: {}", e))
+        })?;
+
+        Self::reject_oversized_signatures(&raw.entries)?;
+
+        Ok(Self {
+            version: raw.version,
+            entries: raw.entries,
+            risk_status: raw.risk_status,
+        })
+    }
+
+    /// Reject any entry whose signature is too long for
+    /// [`crate::safety::screener`]'s matcher to ever match, rather than
+    /// silently loading a signature `scan_pathogen_signatures` can never
+    /// flag.
+    fn reject_oversized_signatures(entries: &[SignatureEntry]) -> Result<()> {
+        for entry in entries {
+            if entry.signature.len() > MAX_SIGNATURE_LEN {
+                return Err(BioCypherError::Validation(format!(
+                    "signature '{}' is {} bases, longer than the {}-base limit the matcher supports",
+                    entry.name,
+                    entry.signature.len(),
+                    MAX_SIGNATURE_LEN
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Load a signature database from a file, dispatching on extension:
+    /// `.json` is parsed with [`Self::from_json`], anything else with
+    /// [`Self::from_tsv`] (`version` is only used in the TSV case, which has
+    /// nowhere to declare its own).
+    pub fn load(path: impl AsRef<Path>, version: impl Into<String>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        if path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+            Self::from_json(&contents)
+        } else {
+            Self::from_tsv(&contents, version)
+        }
+    }
+
+    pub fn pathogen_entries(&self) -> impl Iterator<Item = &SignatureEntry> {
+        self.entries.iter().filter(|e| e.category == SignatureCategory::Pathogen)
+    }
+
+    pub fn housekeeping_entries(&self) -> impl Iterator<Item = &SignatureEntry> {
+        self.entries.iter().filter(|e| e.category == SignatureCategory::Housekeeping)
+    }
+
+    pub fn genome_entries(&self) -> impl Iterator<Item = &SignatureEntry> {
+        self.entries.iter().filter(|e| e.category == SignatureCategory::Genome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_built_in_matches_previous_hardcoded_counts() {
+        let db = SignatureDatabase::built_in();
+        assert_eq!(db.pathogen_entries().count(), 8);
+        assert_eq!(db.housekeeping_entries().count(), 4);
+        assert_eq!(db.genome_entries().count(), 8);
+    }
+
+    #[test]
+    fn test_built_in_antibiotic_resistance_entries_carry_resistance_profile() {
+        let db = SignatureDatabase::built_in();
+        let entry = db
+            .pathogen_entries()
+            .find(|e| e.signature == "ATGAGCCATATTCAACG")
+            .expect("expected the blaTEM-1 built-in entry");
+        let resistance = entry.resistance.as_ref().expect("expected a resistance profile");
+        assert_eq!(resistance.gene, "blaTEM-1");
+        assert_eq!(resistance.antibiotic_class, crate::safety::AntibioticClass::BetaLactam);
+    }
+
+    #[test]
+    fn test_built_in_non_resistance_entries_have_no_resistance_profile() {
+        let db = SignatureDatabase::built_in();
+        let entry = db
+            .pathogen_entries()
+            .find(|e| e.name == "toxin_genes")
+            .expect("expected a toxin_genes built-in entry");
+        assert!(entry.resistance.is_none());
+    }
+
+    #[test]
+    fn test_risk_status_mapping_default() {
+        let mapping = RiskStatusMapping::default();
+        assert_eq!(mapping.status_for(RiskLevel::Low), SafetyStatus::Caution);
+        assert_eq!(mapping.status_for(RiskLevel::Medium), SafetyStatus::Caution);
+        assert_eq!(mapping.status_for(RiskLevel::High), SafetyStatus::Unsafe);
+    }
+
+    #[test]
+    fn test_from_tsv_parses_rows() {
+        let tsv = "category\tname\tsignature\trisk_level\npathogen\tcustom_toxin\tATCGATCGATCGATCGAT\thigh\n";
+        let db = SignatureDatabase::from_tsv(tsv, "2026-07-31").unwrap();
+        assert_eq!(db.version, "2026-07-31");
+        assert_eq!(db.entries.len(), 1);
+        assert_eq!(db.entries[0].name, "custom_toxin");
+        assert_eq!(db.entries[0].risk_level, RiskLevel::High);
+    }
+
+    #[test]
+    fn test_from_tsv_rejects_unknown_category() {
+        let tsv = "category\tname\tsignature\trisk_level\nbogus\tfoo\tATCG\thigh\n";
+        assert!(SignatureDatabase::from_tsv(tsv, "v1").is_err());
+    }
+
+    #[test]
+    fn test_from_tsv_rejects_oversized_signature() {
+        let signature = "A".repeat(MAX_SIGNATURE_LEN + 1);
+        let tsv = format!("category\tname\tsignature\trisk_level\npathogen\tfoo\t{}\thigh\n", signature);
+        assert!(SignatureDatabase::from_tsv(&tsv, "v1").is_err());
+    }
+
+    #[test]
+    fn test_from_json_parses_entries_and_overrides() {
+        let json = r#"{
+            "version": "2026-07-31",
+            "risk_status": {"low": "SAFE", "medium": "CAUTION", "high": "UNSAFE"},
+            "entries": [
+                {"category": "pathogen", "name": "custom_toxin", "signature": "ATCGATCGATCGATCGAT", "risk_level": "high"}
+            ]
+        }"#;
+        let db = SignatureDatabase::from_json(json).unwrap();
+        assert_eq!(db.version, "2026-07-31");
+        assert_eq!(db.entries.len(), 1);
+        assert_eq!(db.risk_status.status_for(RiskLevel::Low), SafetyStatus::Safe);
+    }
+
+    #[test]
+    fn test_from_json_parses_custom_resistance_profile() {
+        let json = r#"{
+            "version": "2026-07-31",
+            "entries": [
+                {
+                    "category": "pathogen", "name": "antibiotic_resistance", "signature": "ATCGATCGATCGATCGAT", "risk_level": "medium",
+                    "resistance": {"gene": "custom-gene", "antibiotic_class": "beta-lactam", "codes": [{"system": "SNOMED", "code": "123"}]}
+                }
+            ]
+        }"#;
+        let db = SignatureDatabase::from_json(json).unwrap();
+        let resistance = db.entries[0].resistance.as_ref().expect("expected a resistance profile");
+        assert_eq!(resistance.gene, "custom-gene");
+        assert_eq!(resistance.codes[0].code, "123");
+    }
+
+    #[test]
+    fn test_from_json_rejects_oversized_signature() {
+        let signature = "A".repeat(MAX_SIGNATURE_LEN + 1);
+        let json = format!(
+            r#"{{"version": "v1", "entries": [{{"category": "pathogen", "name": "foo", "signature": "{}", "risk_level": "high"}}]}}"#,
+            signature
+        );
+        assert!(SignatureDatabase::from_json(&json).is_err());
+    }
+}
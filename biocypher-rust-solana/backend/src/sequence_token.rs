@@ -0,0 +1,255 @@
+//! Detached signature tokens over DNA sequence digests
+//!
+//! A compact JWS (`base64url(header).base64url(payload).base64url(signature)`)
+//! whose `payload` is nothing more than the raw SHA-256 digest of the
+//! sequence's normalized (uppercase) bytes — the same digest
+//! [`crate::solana::hash_sequence`] produces for on-chain attestation, so a
+//! token signed here and an attestation recorded on-chain cover the same
+//! bytes. Unlike [`crate::credential`]'s JWS, the payload isn't a JSON claims
+//! object: a detached signature only needs to assert "this exact sequence,
+//! unmodified", so the digest alone is the whole payload.
+//!
+//! Two key types are supported, selected by the signer and recorded in the
+//! header's `alg`: `EdDSA` (ed25519) and `ES256` (ECDSA P-256) — the same
+//! JWS algorithm names [`crate::credential`] and most JWT libraries use.
+//! [`Ed25519Token`] and [`EcdsaP256Token`] each implement
+//! [`crate::dna::traits::SequenceSigner`] for their key type; [`sign`] and
+//! [`verify`] dispatch across both without the caller needing to match on
+//! the algorithm itself.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use ed25519_dalek::{
+    Signature as Ed25519Signature, Signer as _, SigningKey as Ed25519SigningKey, Verifier as _,
+    VerifyingKey as Ed25519VerifyingKey,
+};
+use p256::ecdsa::{
+    signature::Signer as _, signature::Verifier as _, Signature as P256Signature, SigningKey as P256SigningKey,
+    VerifyingKey as P256VerifyingKey,
+};
+
+use crate::dna::traits::SequenceSigner;
+use crate::error::{ApiError, BioCypherError, Result};
+use crate::solana::hash_sequence;
+
+const JWS_ALG_ED25519: &str = "EdDSA";
+const JWS_ALG_ES256: &str = "ES256";
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct JwsHeader<'a> {
+    alg: &'a str,
+}
+
+/// Which key type a token was (or should be) signed with. Needed up front
+/// for signing, since a 32-byte private key is ambiguous between an Ed25519
+/// seed and a P-256 scalar; verification instead reads this back out of the
+/// token's own header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAlgorithm {
+    Ed25519,
+    EcdsaP256,
+}
+
+/// [`SequenceSigner`] for Ed25519 keys. `private_key` is the raw 32-byte
+/// seed and `public_key` the raw 32-byte point, the same representation used
+/// throughout [`crate::auth::capability`] and [`crate::credential`].
+pub struct Ed25519Token;
+
+impl SequenceSigner for Ed25519Token {
+    fn sign_sequence(sequence: &str, private_key: &[u8]) -> Result<String> {
+        let seed: [u8; 32] = private_key
+            .try_into()
+            .map_err(|_| BioCypherError::Validation("Ed25519 private key must be 32 bytes".to_string()))?;
+        let signing_key = Ed25519SigningKey::from_bytes(&seed);
+
+        let signing_input = signing_input(JWS_ALG_ED25519, sequence)?;
+        let signature = signing_key.sign(signing_input.as_bytes());
+        Ok(format!("{}.{}", signing_input, URL_SAFE_NO_PAD.encode(signature.to_bytes())))
+    }
+
+    fn verify_sequence(sequence: &str, token: &str, public_key: &[u8]) -> Result<()> {
+        let parsed = split_token(token)?;
+        if parsed.alg != JWS_ALG_ED25519 {
+            return Err(ApiError::Unauthorized.into());
+        }
+        check_payload_digest(&parsed.signing_input, sequence)?;
+
+        let key_arr: [u8; 32] = public_key.try_into().map_err(|_| ApiError::Unauthorized)?;
+        let verifying_key = Ed25519VerifyingKey::from_bytes(&key_arr).map_err(|_| ApiError::Unauthorized)?;
+
+        let sig_bytes = URL_SAFE_NO_PAD.decode(parsed.signature_b64).map_err(|_| ApiError::Unauthorized)?;
+        let sig_arr: [u8; 64] = sig_bytes.try_into().map_err(|_| ApiError::Unauthorized)?;
+        let signature = Ed25519Signature::from_bytes(&sig_arr);
+
+        verifying_key
+            .verify(parsed.signing_input.as_bytes(), &signature)
+            .map_err(|_| ApiError::Unauthorized.into())
+    }
+}
+
+/// [`SequenceSigner`] for ECDSA P-256 keys. `private_key` is the raw 32-byte
+/// scalar and `public_key` the SEC1-encoded point (compressed or
+/// uncompressed).
+pub struct EcdsaP256Token;
+
+impl SequenceSigner for EcdsaP256Token {
+    fn sign_sequence(sequence: &str, private_key: &[u8]) -> Result<String> {
+        let signing_key = P256SigningKey::from_slice(private_key)
+            .map_err(|_| BioCypherError::Validation("Invalid ECDSA P-256 private key".to_string()))?;
+
+        let signing_input = signing_input(JWS_ALG_ES256, sequence)?;
+        let signature: P256Signature = signing_key.sign(signing_input.as_bytes());
+        Ok(format!("{}.{}", signing_input, URL_SAFE_NO_PAD.encode(signature.to_bytes())))
+    }
+
+    fn verify_sequence(sequence: &str, token: &str, public_key: &[u8]) -> Result<()> {
+        let parsed = split_token(token)?;
+        if parsed.alg != JWS_ALG_ES256 {
+            return Err(ApiError::Unauthorized.into());
+        }
+        check_payload_digest(&parsed.signing_input, sequence)?;
+
+        let verifying_key = P256VerifyingKey::from_sec1_bytes(public_key).map_err(|_| ApiError::Unauthorized)?;
+        let sig_bytes = URL_SAFE_NO_PAD.decode(parsed.signature_b64).map_err(|_| ApiError::Unauthorized)?;
+        let signature = P256Signature::from_slice(&sig_bytes).map_err(|_| ApiError::Unauthorized)?;
+
+        verifying_key
+            .verify(parsed.signing_input.as_bytes(), &signature)
+            .map_err(|_| ApiError::Unauthorized.into())
+    }
+}
+
+/// Sign `sequence` with `private_key`, dispatching to the [`SequenceSigner`]
+/// impl matching `algorithm`.
+pub fn sign(sequence: &str, algorithm: KeyAlgorithm, private_key: &[u8]) -> Result<String> {
+    match algorithm {
+        KeyAlgorithm::Ed25519 => Ed25519Token::sign_sequence(sequence, private_key),
+        KeyAlgorithm::EcdsaP256 => EcdsaP256Token::sign_sequence(sequence, private_key),
+    }
+}
+
+/// Verify `token` against `sequence` and `public_key`, reading which
+/// algorithm to use from the token's own header rather than requiring the
+/// caller to know it in advance.
+pub fn verify(sequence: &str, token: &str, public_key: &[u8]) -> Result<()> {
+    match split_token(token)?.alg.as_str() {
+        JWS_ALG_ED25519 => Ed25519Token::verify_sequence(sequence, token, public_key),
+        JWS_ALG_ES256 => EcdsaP256Token::verify_sequence(sequence, token, public_key),
+        _ => Err(ApiError::Unauthorized.into()),
+    }
+}
+
+/// `base64url(header).base64url(payload)`, where `payload` is the SHA-256
+/// digest of `sequence` normalized to uppercase — the bytes the signature
+/// covers.
+fn signing_input(alg: &str, sequence: &str) -> Result<String> {
+    let header = JwsHeader { alg };
+    let header_json = serde_json::to_vec(&header)
+        .map_err(|e| BioCypherError::Internal(format!("Failed to encode JWS header: {}", e)))?;
+    let digest = hash_sequence(&sequence.to_uppercase());
+    Ok(format!("{}.{}", URL_SAFE_NO_PAD.encode(header_json), URL_SAFE_NO_PAD.encode(digest)))
+}
+
+struct ParsedToken<'a> {
+    signing_input: String,
+    alg: String,
+    signature_b64: &'a str,
+}
+
+/// Split a compact token into its signing input, declared algorithm, and
+/// signature, rejecting anything that isn't exactly three dot-separated
+/// parts or whose header doesn't parse as JSON.
+fn split_token(token: &str) -> Result<ParsedToken<'_>> {
+    let mut parts = token.split('.');
+    let (Some(header_b64), Some(payload_b64), Some(signature_b64), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(ApiError::Unauthorized.into());
+    };
+
+    let header_json = URL_SAFE_NO_PAD.decode(header_b64).map_err(|_| ApiError::Unauthorized)?;
+    let header: JwsHeader = serde_json::from_slice(&header_json).map_err(|_| ApiError::Unauthorized)?;
+
+    Ok(ParsedToken {
+        signing_input: format!("{}.{}", header_b64, payload_b64),
+        alg: header.alg.to_string(),
+        signature_b64,
+    })
+}
+
+/// Confirm the token's payload is exactly the digest of `sequence`
+/// (normalized uppercase), not just any validly-signed payload.
+fn check_payload_digest(signing_input: &str, sequence: &str) -> Result<()> {
+    let payload_b64 = signing_input.split('.').nth(1).ok_or(ApiError::Unauthorized)?;
+    let payload = URL_SAFE_NO_PAD.decode(payload_b64).map_err(|_| ApiError::Unauthorized)?;
+    if payload != hash_sequence(&sequence.to_uppercase()) {
+        return Err(ApiError::Unauthorized.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ed25519_roundtrip() {
+        let signing_key = Ed25519SigningKey::from_bytes(&[7u8; 32]);
+        let public_key = signing_key.verifying_key().to_bytes();
+
+        let token = sign("ATCGATCG", KeyAlgorithm::Ed25519, &signing_key.to_bytes()).unwrap();
+        assert!(verify("ATCGATCG", &token, &public_key).is_ok());
+    }
+
+    #[test]
+    fn test_ed25519_case_insensitive_sequence() {
+        let signing_key = Ed25519SigningKey::from_bytes(&[7u8; 32]);
+        let public_key = signing_key.verifying_key().to_bytes();
+
+        let token = sign("atcgatcg", KeyAlgorithm::Ed25519, &signing_key.to_bytes()).unwrap();
+        assert!(verify("ATCGATCG", &token, &public_key).is_ok());
+    }
+
+    #[test]
+    fn test_ecdsa_p256_roundtrip() {
+        let signing_key = P256SigningKey::from_bytes(&[9u8; 32].into()).unwrap();
+        let public_key = P256VerifyingKey::from(&signing_key).to_sec1_bytes();
+
+        let token = sign("GATTACA", KeyAlgorithm::EcdsaP256, &signing_key.to_bytes()).unwrap();
+        assert!(verify("GATTACA", &token, &public_key).is_ok());
+    }
+
+    #[test]
+    fn test_tampered_sequence_rejected() {
+        let signing_key = Ed25519SigningKey::from_bytes(&[7u8; 32]);
+        let public_key = signing_key.verifying_key().to_bytes();
+
+        let token = sign("ATCGATCG", KeyAlgorithm::Ed25519, &signing_key.to_bytes()).unwrap();
+        assert!(verify("GGGGGGGG", &token, &public_key).is_err());
+    }
+
+    #[test]
+    fn test_wrong_key_rejected() {
+        let signing_key = Ed25519SigningKey::from_bytes(&[7u8; 32]);
+        let other_public_key = Ed25519SigningKey::from_bytes(&[8u8; 32]).verifying_key().to_bytes();
+
+        let token = sign("ATCGATCG", KeyAlgorithm::Ed25519, &signing_key.to_bytes()).unwrap();
+        assert!(verify("ATCGATCG", &token, &other_public_key).is_err());
+    }
+
+    #[test]
+    fn test_algorithm_confusion_rejected() {
+        let ed25519_key = Ed25519SigningKey::from_bytes(&[7u8; 32]);
+        let p256_key = P256SigningKey::from_bytes(&[9u8; 32].into()).unwrap();
+        let p256_public_key = P256VerifyingKey::from(&p256_key).to_sec1_bytes();
+
+        let token = sign("ATCGATCG", KeyAlgorithm::Ed25519, &ed25519_key.to_bytes()).unwrap();
+        // Verifying an Ed25519 token against a P-256 key must fail, not panic.
+        assert!(EcdsaP256Token::verify_sequence("ATCGATCG", &token, &p256_public_key).is_err());
+    }
+
+    #[test]
+    fn test_malformed_token_rejected() {
+        assert!(verify("ATCGATCG", "not-a-token", &[0u8; 32]).is_err());
+        assert!(verify("ATCGATCG", "a.b.c.d", &[0u8; 32]).is_err());
+    }
+}
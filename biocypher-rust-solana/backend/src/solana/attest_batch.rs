@@ -0,0 +1,75 @@
+//! In-memory store of inclusion proofs for committed attestation batches.
+//!
+//! `POST /api/attest/batch` builds an [`crate::solana::merkle::AttestMerkleTree`]
+//! over many `(operation, sequence)` attestations at once and hands back
+//! every item's proof inline, but a caller may come back later (or a
+//! third party may only have the root) and want to re-fetch one proof by
+//! index — that's what [`AttestProofStore`] serves via
+//! `GET /api/attest/proof/{root}/{index}`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::solana::merkle::MerkleProof;
+
+/// Thread-safe store of `(leaf, proof)` pairs keyed by batch root and leaf
+/// index, shared across requests as `web::Data<AttestProofStore>`.
+#[derive(Default)]
+pub struct AttestProofStore {
+    batches: Mutex<HashMap<[u8; 32], Vec<([u8; 32], MerkleProof)>>>,
+}
+
+impl AttestProofStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record every item's `(leaf, proof)` for a freshly committed batch,
+    /// in leaf-index order.
+    pub fn store(&self, root: [u8; 32], entries: Vec<([u8; 32], MerkleProof)>) {
+        self.batches
+            .lock()
+            .expect("attest proof store lock poisoned")
+            .insert(root, entries);
+    }
+
+    /// Look up the `(leaf, proof)` for one item of a committed batch.
+    pub fn get(&self, root: [u8; 32], index: usize) -> Option<([u8; 32], MerkleProof)> {
+        self.batches
+            .lock()
+            .expect("attest proof store lock poisoned")
+            .get(&root)?
+            .get(index)
+            .cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solana::merkle::ProofStep;
+
+    #[test]
+    fn test_store_and_get_roundtrip() {
+        let store = AttestProofStore::new();
+        let root = [1u8; 32];
+        let proof = MerkleProof {
+            steps: vec![ProofStep {
+                sibling: [2u8; 32],
+                sibling_is_left: true,
+            }],
+        };
+        store.store(root, vec![([3u8; 32], proof.clone())]);
+        let (leaf, stored_proof) = store.get(root, 0).unwrap();
+        assert_eq!(leaf, [3u8; 32]);
+        assert_eq!(stored_proof, proof);
+    }
+
+    #[test]
+    fn test_get_missing_root_or_index_is_none() {
+        let store = AttestProofStore::new();
+        assert!(store.get([9u8; 32], 0).is_none());
+        store.store([1u8; 32], vec![]);
+        assert!(store.get([1u8; 32], 0).is_none());
+    }
+}
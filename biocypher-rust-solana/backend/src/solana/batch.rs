@@ -0,0 +1,154 @@
+//! In-memory batching of safety attestations into a single on-chain Merkle
+//! root commitment.
+//!
+//! Collects `(seq_hash, safety_status)` leaves from many `safety_screen`
+//! calls, and once [`SAFETY_BATCH_SIZE`] have accumulated commits only the
+//! batch's Merkle root on-chain in one transaction via
+//! [`SolanaClient::record_safety_batch`]. Each caller that contributed a
+//! leaf gets back a [`crate::solana::merkle::MerkleProof`] it can keep
+//! alongside the root and transaction signature to prove its result was
+//! committed, without needing to trust the server after the fact.
+
+use std::sync::Mutex;
+
+use crate::error::Result;
+use crate::models::SafetyStatus;
+use crate::solana::merkle::{self, MerkleProof, MerkleTree};
+use crate::solana::SolanaClient;
+
+/// Number of leaves accumulated before a batch is committed on-chain.
+pub const SAFETY_BATCH_SIZE: usize = 16;
+
+/// A freshly committed batch: the Merkle root recorded on-chain, the
+/// transaction signature recording it (`None` if Solana isn't configured),
+/// and the inclusion proof for the one leaf the caller contributed.
+pub struct BatchCommitment {
+    pub root: [u8; 32],
+    pub transaction_signature: Option<String>,
+    pub proof: MerkleProof,
+}
+
+/// Thread-safe accumulator for pending safety-attestation leaves, shared
+/// across requests as `web::Data<SafetyBatcher>`.
+#[derive(Default)]
+pub struct SafetyBatcher {
+    pending: Mutex<Vec<[u8; 32]>>,
+}
+
+impl SafetyBatcher {
+    pub fn new() -> Self {
+        Self {
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Add a `(seq_hash, status)` leaf to the pending batch.
+    ///
+    /// Once [`SAFETY_BATCH_SIZE`] leaves have accumulated, commits the
+    /// batch's Merkle root on-chain (via `client`, if Solana is configured)
+    /// and returns the caller's inclusion proof. Returns `Ok(None)` if the
+    /// batch is still filling.
+    pub async fn add(
+        &self,
+        client: Option<&SolanaClient>,
+        seq_hash: [u8; 32],
+        status: SafetyStatus,
+    ) -> Result<Option<BatchCommitment>> {
+        let leaf = merkle::leaf_hash(seq_hash, status);
+
+        let leaves = {
+            let mut pending = self.pending.lock().expect("safety batch lock poisoned");
+            pending.push(leaf);
+            if pending.len() < SAFETY_BATCH_SIZE {
+                return Ok(None);
+            }
+            std::mem::take(&mut *pending)
+        };
+
+        self.commit(client, leaf, leaves).await.map(Some)
+    }
+
+    /// Commit whatever is currently pending, even if short of
+    /// [`SAFETY_BATCH_SIZE`]. Returns the committed root, or `None` if
+    /// nothing was pending.
+    pub async fn flush(&self, client: Option<&SolanaClient>) -> Result<Option<[u8; 32]>> {
+        let leaves = {
+            let mut pending = self.pending.lock().expect("safety batch lock poisoned");
+            if pending.is_empty() {
+                return Ok(None);
+            }
+            std::mem::take(&mut *pending)
+        };
+
+        let tree = MerkleTree::build(leaves);
+        let root = tree.root();
+        if let Some(client) = client {
+            client.record_safety_batch(root).await?;
+        }
+        Ok(Some(root))
+    }
+
+    async fn commit(
+        &self,
+        client: Option<&SolanaClient>,
+        leaf: [u8; 32],
+        leaves: Vec<[u8; 32]>,
+    ) -> Result<BatchCommitment> {
+        let leaf_index = leaves
+            .iter()
+            .position(|l| *l == leaf)
+            .expect("leaf was just pushed into this batch");
+        let tree = MerkleTree::build(leaves);
+        let root = tree.root();
+        let proof = tree.proof(leaf_index).expect("leaf_index is within bounds");
+
+        let transaction_signature = match client {
+            Some(client) => Some(client.record_safety_batch(root).await?),
+            None => None,
+        };
+
+        Ok(BatchCommitment {
+            root,
+            transaction_signature,
+            proof,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solana::merkle::verify_inclusion;
+
+    #[tokio::test]
+    async fn test_batch_fills_and_commits_with_no_client() {
+        let batcher = SafetyBatcher::new();
+        let mut last = None;
+        for i in 0..SAFETY_BATCH_SIZE {
+            let seq_hash = [i as u8; 32];
+            last = batcher.add(None, seq_hash, SafetyStatus::Safe).await.unwrap();
+        }
+        let commitment = last.expect("batch should commit once full");
+        assert!(commitment.transaction_signature.is_none());
+
+        let leaf = merkle::leaf_hash([(SAFETY_BATCH_SIZE - 1) as u8; 32], SafetyStatus::Safe);
+        assert!(verify_inclusion(leaf, &commitment.proof, commitment.root));
+    }
+
+    #[tokio::test]
+    async fn test_partial_batch_does_not_commit() {
+        let batcher = SafetyBatcher::new();
+        let result = batcher.add(None, [1u8; 32], SafetyStatus::Safe).await.unwrap();
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_flush_commits_partial_batch() {
+        let batcher = SafetyBatcher::new();
+        batcher.add(None, [1u8; 32], SafetyStatus::Safe).await.unwrap();
+        batcher.add(None, [2u8; 32], SafetyStatus::Caution).await.unwrap();
+        let root = batcher.flush(None).await.unwrap();
+        assert!(root.is_some());
+        assert!(batcher.flush(None).await.unwrap().is_none());
+    }
+}
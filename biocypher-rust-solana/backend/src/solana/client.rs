@@ -6,13 +6,17 @@
 use crate::dna::EncodingMode;
 use crate::error::{BioCypherError, Result};
 use crate::models::SafetyStatus;
+use rand::RngCore;
 use sha2::{Digest, Sha256};
 use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{
     commitment_config::CommitmentConfig,
+    hash::Hash,
     instruction::{AccountMeta, Instruction},
+    nonce::{state::Versions as NonceVersions, State as NonceState},
     pubkey::Pubkey,
-    signature::{read_keypair_file, Keypair, Signer},
+    signature::{read_keypair_file, Keypair, Signature, Signer},
+    system_instruction,
     system_program::ID as SYSTEM_PROGRAM_ID,
     transaction::Transaction,
 };
@@ -22,6 +26,32 @@ use std::sync::Arc;
 /// Default program ID for biocypher-storage (from anchor keys list)
 const DEFAULT_PROGRAM_ID: &str = "FtXEkJEXm8bJbEc9DHPwuV8W7C9PLdszt8vnzsDgk9Rj";
 
+/// Solana's legacy transaction wire-size limit. `record_encode_batch`
+/// packs as many instructions as fit under this before starting a new
+/// transaction.
+const MAX_TRANSACTION_SIZE: usize = 1232;
+
+/// Default program ID for the Arcium MXE program (`biocypher-mxe/`),
+/// separate from the `biocypher-storage` attestation program above.
+const DEFAULT_MXE_PROGRAM_ID: &str = "EneGTgWJJwnxLeBkD128NtpuGQVCmq14cUnPCNEVyueE";
+
+fn mxe_program_id() -> Result<Pubkey> {
+    Pubkey::from_str(
+        std::env::var("BIOCYPHER_MXE_PROGRAM_ID")
+            .as_deref()
+            .unwrap_or(DEFAULT_MXE_PROGRAM_ID),
+    )
+    .map_err(|_| BioCypherError::Solana("Invalid MXE program ID".into()))
+}
+
+fn mxe_computation_pda(program_id: &Pubkey, computation_offset: u64) -> Pubkey {
+    let (pda, _) = Pubkey::find_program_address(
+        &[b"computation", &computation_offset.to_le_bytes()],
+        program_id,
+    );
+    pda
+}
+
 /// Compute Anchor instruction discriminator (first 8 bytes of sha256("global:name"))
 fn instruction_discriminator(name: &str) -> [u8; 8] {
     let mut hasher = Sha256::new();
@@ -39,6 +69,7 @@ fn encoding_mode_to_u8(mode: EncodingMode) -> u8 {
         EncodingMode::Nanopore => 1,
         EncodingMode::Secure => 2,
         EncodingMode::SplitKey => 3,
+        EncodingMode::OpenPgp => 4,
     }
 }
 
@@ -50,6 +81,66 @@ fn safety_status_to_u8(status: SafetyStatus) -> u8 {
     }
 }
 
+fn encoding_mode_from_u8(value: u8) -> Result<EncodingMode> {
+    match value {
+        0 => Ok(EncodingMode::Basic),
+        1 => Ok(EncodingMode::Nanopore),
+        2 => Ok(EncodingMode::Secure),
+        3 => Ok(EncodingMode::SplitKey),
+        4 => Ok(EncodingMode::OpenPgp),
+        other => Err(BioCypherError::Solana(format!("Unknown encoding mode byte: {other}"))),
+    }
+}
+
+fn safety_status_from_u8(value: u8) -> Result<SafetyStatus> {
+    match value {
+        0 => Ok(SafetyStatus::Safe),
+        1 => Ok(SafetyStatus::Caution),
+        2 => Ok(SafetyStatus::Unsafe),
+        other => Err(BioCypherError::Solana(format!("Unknown safety status byte: {other}"))),
+    }
+}
+
+/// Raw Borsh layout of the on-chain `EncodeRecord`/`DecodeRecord` account
+/// (`biocypher-storage`), after the 8-byte Anchor discriminator.
+#[derive(borsh::BorshDeserialize)]
+struct RawModeRecord {
+    owner: Pubkey,
+    mode: u8,
+    sequence_hash: [u8; 32],
+    timestamp: i64,
+    bump: u8,
+}
+
+/// Raw Borsh layout of the on-chain `SafetyRecord` account, after the
+/// 8-byte Anchor discriminator.
+#[derive(borsh::BorshDeserialize)]
+struct RawSafetyRecord {
+    owner: Pubkey,
+    sequence_hash: [u8; 32],
+    status: u8,
+    timestamp: i64,
+    bump: u8,
+}
+
+/// Deserialized on-chain encode or decode attestation record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModeAttestationRecord {
+    pub owner: Pubkey,
+    pub mode: EncodingMode,
+    pub sequence_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Deserialized on-chain safety attestation record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SafetyAttestationRecord {
+    pub owner: Pubkey,
+    pub sequence_hash: [u8; 32],
+    pub status: SafetyStatus,
+    pub timestamp: i64,
+}
+
 /// Solana client for biocypher-storage attestation.
 pub struct SolanaClient {
     rpc_url: String,
@@ -108,6 +199,19 @@ impl SolanaClient {
             .ok_or_else(|| BioCypherError::Solana("Solana keypair not configured".into()))
     }
 
+    /// Sign arbitrary bytes with the server's ed25519 keypair (the same key
+    /// used to sign on-chain attestation transactions). Used by
+    /// `/api/attest/credential` to issue off-chain-verifiable credentials
+    /// without needing RPC access.
+    pub fn sign_bytes(&self, message: &[u8]) -> Result<[u8; 64]> {
+        let payer = self.payer()?;
+        Ok(payer
+            .sign_message(message)
+            .as_ref()
+            .try_into()
+            .expect("ed25519 signature is always 64 bytes"))
+    }
+
     fn encode_record_pda(&self, sequence_hash: &[u8; 32]) -> Result<Pubkey> {
         let (pda, _) = Pubkey::find_program_address(
             &[
@@ -132,6 +236,18 @@ impl SolanaClient {
         Ok(pda)
     }
 
+    fn safety_batch_record_pda(&self, root: &[u8; 32]) -> Result<Pubkey> {
+        let (pda, _) = Pubkey::find_program_address(
+            &[
+                b"safety_batch",
+                self.payer()?.pubkey().as_ref(),
+                root,
+            ],
+            &self.program_id,
+        );
+        Ok(pda)
+    }
+
     fn safety_record_pda(&self, sequence_hash: &[u8; 32]) -> Result<Pubkey> {
         let (pda, _) = Pubkey::find_program_address(
             &[
@@ -145,6 +261,10 @@ impl SolanaClient {
     }
 
     async fn send_transaction(&self, instruction: Instruction) -> Result<String> {
+        self.send_transaction_multi(vec![instruction]).await
+    }
+
+    async fn send_transaction_multi(&self, instructions: Vec<Instruction>) -> Result<String> {
         let payer = self.payer()?;
         let client = Arc::new(RpcClient::new_with_commitment(
             self.rpc_url.clone(),
@@ -157,7 +277,7 @@ impl SolanaClient {
             .map_err(|e| BioCypherError::Solana(e.to_string()))?;
 
         let transaction = Transaction::new_signed_with_payer(
-            &[instruction],
+            &instructions,
             Some(&payer.pubkey()),
             &[payer],
             recent_blockhash,
@@ -175,6 +295,93 @@ impl SolanaClient {
         Ok(sig.to_string())
     }
 
+    /// Estimate the wire size of a transaction carrying `instructions`,
+    /// signed by `payer`, without needing a real blockhash (signature
+    /// length doesn't depend on the blockhash value, so a zeroed one
+    /// sizes identically to a live one).
+    fn packed_size(instructions: &[Instruction], payer: &Keypair) -> usize {
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &[payer],
+            Hash::default(),
+        );
+        bincode::serialize(&transaction)
+            .map(|bytes| bytes.len())
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Record many encode attestations, packing as many
+    /// `record_encode` instructions as fit under
+    /// [`MAX_TRANSACTION_SIZE`] into each transaction and automatically
+    /// starting a new one when the batch would overflow. Amortizes the
+    /// blockhash fetch and confirmation cost across all records, which
+    /// matters for bulk-ingest workflows submitting a whole file of
+    /// sequences at once.
+    pub async fn record_encode_batch(&self, entries: &[(EncodingMode, [u8; 32])]) -> Result<Vec<String>> {
+        let payer = self.payer()?;
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        let mut signatures = Vec::new();
+        let mut pending: Vec<Instruction> = Vec::new();
+
+        for (mode, sequence_hash) in entries {
+            let encode_record = self.encode_record_pda(sequence_hash)?;
+            let mut data = instruction_discriminator("record_encode").to_vec();
+            data.push(encoding_mode_to_u8(*mode));
+            data.extend_from_slice(sequence_hash);
+            data.extend_from_slice(&timestamp.to_le_bytes());
+
+            let ix = Instruction {
+                program_id: self.program_id,
+                accounts: vec![
+                    AccountMeta::new(encode_record, false),
+                    AccountMeta::new(payer.pubkey(), true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+                data,
+            };
+
+            let mut candidate = pending.clone();
+            candidate.push(ix.clone());
+
+            if !pending.is_empty() && Self::packed_size(&candidate, payer) > MAX_TRANSACTION_SIZE {
+                signatures.push(self.send_transaction_multi(pending).await?);
+                pending = vec![ix];
+            } else {
+                pending = candidate;
+            }
+        }
+
+        if !pending.is_empty() {
+            signatures.push(self.send_transaction_multi(pending).await?);
+        }
+
+        Ok(signatures)
+    }
+
+    /// Submit `instruction` through [`crate::solana::tx_sender::TxSender`]
+    /// instead of the blocking, spinner-based [`Self::send_transaction`]:
+    /// a cached blockhash (refreshed in the background) avoids an RPC
+    /// round-trip per call, the send skips the blocking
+    /// confirm-via-spinner, and the transaction is rebroadcast until it
+    /// confirms or its blockhash expires. Returns the signature and
+    /// whether it ultimately confirmed, so bursts of encode/decode
+    /// attestations under load don't get silently dropped.
+    pub async fn send_transaction_resilient(
+        &self,
+        instruction: Instruction,
+        skip_preflight: bool,
+    ) -> Result<(String, bool)> {
+        let payer = self.payer()?;
+        let sender = crate::solana::tx_sender::TxSender::new(self.rpc_url.clone(), skip_preflight);
+        let (signature, confirmed) = sender.send_resilient(instruction, payer).await?;
+        Ok((signature.to_string(), confirmed))
+    }
+
     /// Record encode attestation on-chain.
     pub async fn record_encode(
         &self,
@@ -237,12 +444,17 @@ impl SolanaClient {
         self.send_transaction(ix).await
     }
 
-    /// Record safety attestation on-chain.
+    /// Record safety attestation on-chain. When `WORMHOLE_CORE_BRIDGE_ID`
+    /// is set, also mirrors the attestation cross-chain via
+    /// [`crate::solana::wormhole::post_attestation_message`] and returns
+    /// its Wormhole sequence number alongside the Solana transaction
+    /// signature; mirroring is off by default and its failure doesn't
+    /// fail the underlying on-chain record, which already succeeded.
     pub async fn record_safety(
         &self,
         sequence_hash: [u8; 32],
         status: SafetyStatus,
-    ) -> Result<String> {
+    ) -> Result<(String, Option<u64>)> {
         let payer = self.payer()?;
         let safety_record = self.safety_record_pda(&sequence_hash)?;
         let timestamp = std::time::SystemTime::now()
@@ -265,8 +477,207 @@ impl SolanaClient {
             data,
         };
 
+        let signature = self.send_transaction(ix).await?;
+
+        let bridge_sequence = crate::solana::wormhole::post_attestation_message(
+            &self.rpc_url,
+            payer,
+            sequence_hash,
+            status,
+            timestamp,
+        )
+        .await
+        .unwrap_or(None);
+
+        Ok((signature, bridge_sequence))
+    }
+
+    /// Record a batch's Merkle root on-chain, attesting to every safety
+    /// screening result committed under it in a single transaction. See
+    /// [`crate::solana::batch::SafetyBatcher`] for how batches are built.
+    pub async fn record_safety_batch(&self, root: [u8; 32]) -> Result<String> {
+        let payer = self.payer()?;
+        let batch_record = self.safety_batch_record_pda(&root)?;
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        let mut data = instruction_discriminator("record_safety_batch").to_vec();
+        data.extend_from_slice(&root);
+        data.extend_from_slice(&timestamp.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(batch_record, false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
         self.send_transaction(ix).await
     }
+
+    /// Queue an `encode_private` computation on the Arcium MXE (see
+    /// `biocypher-mxe/`). `ciphertext` is the client-encrypted message,
+    /// already chunked into the 32-byte words the circuit expects;
+    /// `client_pubkey`/`nonce` are the x25519 key and nonce the client
+    /// encrypted under, so the MXE can derive the same shared secret to
+    /// re-encrypt its output.
+    ///
+    /// Returns the `computation_offset` correlating this request with its
+    /// callback, picked at random the same way the Arcium client examples
+    /// do.
+    pub async fn queue_mxe_computation(
+        &self,
+        ciphertext: &[[u8; 32]],
+        client_pubkey: [u8; 32],
+        nonce: u128,
+    ) -> Result<u64> {
+        let payer = self.payer()?;
+        let mxe_program_id = mxe_program_id()?;
+        let computation_offset = rand::thread_rng().next_u64();
+        let computation_account = mxe_computation_pda(&mxe_program_id, computation_offset);
+
+        let mut data = instruction_discriminator("encode_private").to_vec();
+        data.extend_from_slice(&computation_offset.to_le_bytes());
+        data.extend_from_slice(&(ciphertext.len() as u32).to_le_bytes());
+        for word in ciphertext {
+            data.extend_from_slice(word);
+        }
+        data.extend_from_slice(&client_pubkey);
+        data.extend_from_slice(&nonce.to_le_bytes());
+
+        let ix = Instruction {
+            program_id: mxe_program_id,
+            accounts: vec![
+                AccountMeta::new(computation_account, false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        self.send_transaction(ix).await?;
+        Ok(computation_offset)
+    }
+
+    /// Poll the MXE for the callback result of a previously queued
+    /// computation. Returns `Ok(None)` while the computation is still
+    /// in-flight (the computation account hasn't been written with a
+    /// result yet), `Ok(Some(ciphertext))` once the encrypted DNA result
+    /// has landed.
+    pub async fn poll_mxe_callback(&self, computation_offset: u64) -> Result<Option<Vec<u8>>> {
+        let mxe_program_id = mxe_program_id()?;
+        let computation_account = mxe_computation_pda(&mxe_program_id, computation_offset);
+
+        let client = Arc::new(RpcClient::new_with_commitment(
+            self.rpc_url.clone(),
+            CommitmentConfig::confirmed(),
+        ));
+
+        match client.get_account(&computation_account).await {
+            Ok(account) if !account.data.is_empty() => Ok(Some(account.data)),
+            Ok(_) => Ok(None),
+            Err(e) if e.to_string().contains("AccountNotFound") => Ok(None),
+            Err(e) => Err(BioCypherError::Solana(e.to_string())),
+        }
+    }
+
+    /// Fetch the raw account bytes at `pda`, stripping the 8-byte Anchor
+    /// account discriminator so callers can Borsh-deserialize just the
+    /// struct body.
+    async fn fetch_record_body(&self, pda: &Pubkey) -> Result<Vec<u8>> {
+        let client = Arc::new(RpcClient::new_with_commitment(
+            self.rpc_url.clone(),
+            CommitmentConfig::confirmed(),
+        ));
+        let data = client
+            .get_account_data(pda)
+            .await
+            .map_err(|e| BioCypherError::Solana(e.to_string()))?;
+        if data.len() < 8 {
+            return Err(BioCypherError::Solana("Account data too short for Anchor discriminator".into()));
+        }
+        Ok(data[8..].to_vec())
+    }
+
+    /// Read back a previously recorded encode attestation.
+    pub async fn fetch_encode_record(&self, sequence_hash: [u8; 32]) -> Result<ModeAttestationRecord> {
+        let pda = self.encode_record_pda(&sequence_hash)?;
+        let body = self.fetch_record_body(&pda).await?;
+        let raw: RawModeRecord = borsh::BorshDeserialize::try_from_slice(&body)
+            .map_err(|e| BioCypherError::Solana(format!("Failed to deserialize encode record: {e}")))?;
+        Ok(ModeAttestationRecord {
+            owner: raw.owner,
+            mode: encoding_mode_from_u8(raw.mode)?,
+            sequence_hash: raw.sequence_hash,
+            timestamp: raw.timestamp,
+        })
+    }
+
+    /// Read back a previously recorded decode attestation.
+    pub async fn fetch_decode_record(&self, sequence_hash: [u8; 32]) -> Result<ModeAttestationRecord> {
+        let pda = self.decode_record_pda(&sequence_hash)?;
+        let body = self.fetch_record_body(&pda).await?;
+        let raw: RawModeRecord = borsh::BorshDeserialize::try_from_slice(&body)
+            .map_err(|e| BioCypherError::Solana(format!("Failed to deserialize decode record: {e}")))?;
+        Ok(ModeAttestationRecord {
+            owner: raw.owner,
+            mode: encoding_mode_from_u8(raw.mode)?,
+            sequence_hash: raw.sequence_hash,
+            timestamp: raw.timestamp,
+        })
+    }
+
+    /// Read back a previously recorded safety attestation.
+    pub async fn fetch_safety_record(&self, sequence_hash: [u8; 32]) -> Result<SafetyAttestationRecord> {
+        let pda = self.safety_record_pda(&sequence_hash)?;
+        let body = self.fetch_record_body(&pda).await?;
+        let raw: RawSafetyRecord = borsh::BorshDeserialize::try_from_slice(&body)
+            .map_err(|e| BioCypherError::Solana(format!("Failed to deserialize safety record: {e}")))?;
+        Ok(SafetyAttestationRecord {
+            owner: raw.owner,
+            sequence_hash: raw.sequence_hash,
+            status: safety_status_from_u8(raw.status)?,
+            timestamp: raw.timestamp,
+        })
+    }
+
+    /// Confirm that `sequence_hash` was already attested to as an encode
+    /// with `expected_mode`, and that the record is genuinely owned by
+    /// this client's program (not some unrelated account sharing the
+    /// PDA's address on a different program). Lets `/api/decode` and
+    /// `/api/safety-screen` check provenance before operating on a
+    /// sequence, without failing the request just because no attestation
+    /// exists yet.
+    pub async fn verify_attestation(&self, sequence_hash: [u8; 32], expected_mode: EncodingMode) -> Result<bool> {
+        let pda = self.encode_record_pda(&sequence_hash)?;
+        let client = Arc::new(RpcClient::new_with_commitment(
+            self.rpc_url.clone(),
+            CommitmentConfig::confirmed(),
+        ));
+
+        let account = match client.get_account(&pda).await {
+            Ok(account) => account,
+            Err(_) => return Ok(false),
+        };
+
+        if account.owner != self.program_id || account.data.len() < 8 {
+            return Ok(false);
+        }
+
+        let raw: RawModeRecord = match borsh::BorshDeserialize::try_from_slice(&account.data[8..]) {
+            Ok(raw) => raw,
+            Err(_) => return Ok(false),
+        };
+
+        Ok(encoding_mode_from_u8(raw.mode)
+            .map(|mode| mode == expected_mode)
+            .unwrap_or(false))
+    }
 }
 
 /// Hash a DNA sequence with SHA-256 for on-chain attestation.
@@ -279,17 +690,47 @@ pub fn hash_sequence(sequence: &str) -> [u8; 32] {
     arr
 }
 
-/// Build unsigned attestation transaction for user wallet signing.
-/// Does not require server keypair; uses RPC for blockhash only.
-pub async fn build_attest_transaction(
+/// A durable nonce account/authority to stamp onto a built transaction
+/// instead of a live blockhash, so it stays valid until the nonce is
+/// advanced on-chain — for offline or hardware-wallet signing flows where
+/// the ~2-minute lifetime of a recent blockhash isn't enough. See
+/// [`build_attest_transaction`] and [`build_create_nonce_account_transaction`].
+pub struct NonceConfig {
+    pub nonce_account: Pubkey,
+    pub nonce_authority: Pubkey,
+}
+
+/// Fetch a durable nonce account and extract its currently stored
+/// blockhash, which a transaction can use as `recent_blockhash` for as
+/// long as the nonce remains unadvanced.
+async fn fetch_nonce_blockhash(client: &RpcClient, nonce_account: &Pubkey) -> Result<Hash> {
+    let account = client
+        .get_account(nonce_account)
+        .await
+        .map_err(|e| BioCypherError::Solana(e.to_string()))?;
+
+    let versions: NonceVersions = bincode::deserialize(&account.data)
+        .map_err(|e| BioCypherError::Solana(format!("Invalid nonce account data: {e}")))?;
+
+    match versions.state() {
+        NonceState::Initialized(data) => Ok(data.blockhash()),
+        NonceState::Uninitialized => {
+            Err(BioCypherError::Solana("Nonce account is not initialized".into()))
+        }
+    }
+}
+
+/// Build the `record_encode`/`record_decode`/`record_safety` instruction
+/// for `operation`, along with the record PDA it will create. Shared by
+/// [`build_attest_transaction`] and [`build_attest_sign_only`], which only
+/// differ in what they do with the resulting instruction.
+fn build_attest_instruction(
     payer: Pubkey,
     operation: &str,
     sequence_hash: [u8; 32],
     mode: Option<EncodingMode>,
     status: Option<SafetyStatus>,
-) -> Result<Vec<u8>> {
-    let rpc_url = std::env::var("SOLANA_RPC_URL")
-        .unwrap_or_else(|_| "http://127.0.0.1:8899".to_string());
+) -> Result<(Instruction, Pubkey)> {
     let program_id = Pubkey::from_str(
         std::env::var("BIOCYPHER_STORAGE_PROGRAM_ID")
             .as_deref()
@@ -302,7 +743,7 @@ pub async fn build_attest_transaction(
         .unwrap()
         .as_secs() as i64;
 
-    let ix = match operation {
+    match operation {
         "encode" => {
             let mode = mode.ok_or_else(|| BioCypherError::Solana("mode required for encode".into()))?;
             let (encode_record, _) = Pubkey::find_program_address(
@@ -313,15 +754,18 @@ pub async fn build_attest_transaction(
             data.push(encoding_mode_to_u8(mode));
             data.extend_from_slice(&sequence_hash);
             data.extend_from_slice(&timestamp.to_le_bytes());
-            Instruction {
-                program_id,
-                accounts: vec![
-                    AccountMeta::new(encode_record, false),
-                    AccountMeta::new(payer, true),
-                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-                ],
-                data,
-            }
+            Ok((
+                Instruction {
+                    program_id,
+                    accounts: vec![
+                        AccountMeta::new(encode_record, false),
+                        AccountMeta::new(payer, true),
+                        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    ],
+                    data,
+                },
+                encode_record,
+            ))
         }
         "decode" => {
             let mode = mode.ok_or_else(|| BioCypherError::Solana("mode required for decode".into()))?;
@@ -333,15 +777,18 @@ pub async fn build_attest_transaction(
             data.push(encoding_mode_to_u8(mode));
             data.extend_from_slice(&sequence_hash);
             data.extend_from_slice(&timestamp.to_le_bytes());
-            Instruction {
-                program_id,
-                accounts: vec![
-                    AccountMeta::new(decode_record, false),
-                    AccountMeta::new(payer, true),
-                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-                ],
-                data,
-            }
+            Ok((
+                Instruction {
+                    program_id,
+                    accounts: vec![
+                        AccountMeta::new(decode_record, false),
+                        AccountMeta::new(payer, true),
+                        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    ],
+                    data,
+                },
+                decode_record,
+            ))
         }
         "safety" => {
             let status = status.ok_or_else(|| BioCypherError::Solana("status required for safety".into()))?;
@@ -353,17 +800,251 @@ pub async fn build_attest_transaction(
             data.extend_from_slice(&sequence_hash);
             data.push(safety_status_to_u8(status));
             data.extend_from_slice(&timestamp.to_le_bytes());
-            Instruction {
-                program_id,
-                accounts: vec![
-                    AccountMeta::new(safety_record, false),
-                    AccountMeta::new(payer, true),
-                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-                ],
-                data,
-            }
+            Ok((
+                Instruction {
+                    program_id,
+                    accounts: vec![
+                        AccountMeta::new(safety_record, false),
+                        AccountMeta::new(payer, true),
+                        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    ],
+                    data,
+                },
+                safety_record,
+            ))
+        }
+        _ => Err(BioCypherError::Solana(format!("Unknown operation: {}", operation)).into()),
+    }
+}
+
+/// Resolve the instructions and `recent_blockhash` to stamp onto an
+/// attestation transaction: a live RPC blockhash, or — when `nonce` is
+/// given — an `advance_nonce_account` instruction prepended ahead of `ix`
+/// and the nonce account's stored blockhash, so the transaction stays
+/// signable indefinitely until the nonce advances.
+async fn resolve_attest_blockhash(
+    client: &RpcClient,
+    ix: Instruction,
+    nonce: Option<NonceConfig>,
+) -> Result<(Vec<Instruction>, Hash)> {
+    match nonce {
+        Some(NonceConfig {
+            nonce_account,
+            nonce_authority,
+        }) => {
+            let advance_ix = system_instruction::advance_nonce_account(&nonce_account, &nonce_authority);
+            let blockhash = fetch_nonce_blockhash(client, &nonce_account).await?;
+            Ok((vec![advance_ix, ix], blockhash))
         }
-        _ => return Err(BioCypherError::Solana(format!("Unknown operation: {}", operation)).into()),
+        None => {
+            let blockhash = client
+                .get_latest_blockhash()
+                .await
+                .map_err(|e| BioCypherError::Solana(e.to_string()))?;
+            Ok((vec![ix], blockhash))
+        }
+    }
+}
+
+/// Build unsigned attestation transaction for user wallet signing.
+/// Does not require server keypair; uses RPC for blockhash only.
+///
+/// When `nonce` is given, `system_instruction::advance_nonce_account` is
+/// prepended as the very first instruction and the nonce account's stored
+/// blockhash is used as `recent_blockhash` instead of a live one, letting
+/// the transaction stay signable indefinitely until the nonce advances.
+pub async fn build_attest_transaction(
+    payer: Pubkey,
+    operation: &str,
+    sequence_hash: [u8; 32],
+    mode: Option<EncodingMode>,
+    status: Option<SafetyStatus>,
+    nonce: Option<NonceConfig>,
+) -> Result<Vec<u8>> {
+    let rpc_url = std::env::var("SOLANA_RPC_URL")
+        .unwrap_or_else(|_| "http://127.0.0.1:8899".to_string());
+    let (ix, _record_pda) = build_attest_instruction(payer, operation, sequence_hash, mode, status)?;
+
+    let client = Arc::new(RpcClient::new_with_commitment(
+        rpc_url,
+        CommitmentConfig::confirmed(),
+    ));
+    let (instructions, recent_blockhash) = resolve_attest_blockhash(&client, ix, nonce).await?;
+
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer));
+    transaction.message.recent_blockhash = recent_blockhash;
+    bincode::serialize(&transaction).map_err(|e| BioCypherError::Solana(e.to_string()))
+}
+
+/// Everything an external signer (browser wallet, offline CLI, hardware
+/// wallet) needs to sign an attestation transaction without the backend
+/// ever holding its key, and everything the backend needs to reassemble
+/// and rebroadcast it once a detached signature comes back via
+/// [`apply_signature`].
+pub struct SignOnlyAttestation {
+    /// The transaction's serialized `Message`, i.e. exactly the bytes an
+    /// Ed25519 signer signs — not the whole `Transaction`.
+    pub message: Vec<u8>,
+    /// The unsigned transaction (empty signature slots, same message),
+    /// bincode-serialized. Round-trips through [`apply_signature`] once a
+    /// signature for `signer` is available.
+    pub unsigned_transaction: Vec<u8>,
+    /// The pubkey expected to produce the signature over `message`.
+    pub signer: Pubkey,
+    /// The blockhash (or durable nonce's stored blockhash) the message was
+    /// stamped with.
+    pub recent_blockhash: Hash,
+    /// The record PDA this transaction will create once confirmed.
+    pub record_pda: Pubkey,
+}
+
+/// Build an attestation transaction for out-of-process signing: returns
+/// the raw message bytes to sign plus enough context (signer, blockhash,
+/// record PDA) to drive a wallet prompt or an offline CLI signer, instead
+/// of the fully-serialized transaction [`build_attest_transaction`]
+/// returns. Pair with [`apply_signature`] to turn the resulting detached
+/// signature back into a submittable transaction.
+pub async fn build_attest_sign_only(
+    payer: Pubkey,
+    operation: &str,
+    sequence_hash: [u8; 32],
+    mode: Option<EncodingMode>,
+    status: Option<SafetyStatus>,
+    nonce: Option<NonceConfig>,
+) -> Result<SignOnlyAttestation> {
+    let rpc_url = std::env::var("SOLANA_RPC_URL")
+        .unwrap_or_else(|_| "http://127.0.0.1:8899".to_string());
+    let (ix, record_pda) = build_attest_instruction(payer, operation, sequence_hash, mode, status)?;
+
+    let client = Arc::new(RpcClient::new_with_commitment(
+        rpc_url,
+        CommitmentConfig::confirmed(),
+    ));
+    let (instructions, recent_blockhash) = resolve_attest_blockhash(&client, ix, nonce).await?;
+
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer));
+    transaction.message.recent_blockhash = recent_blockhash;
+    let message = transaction.message.serialize();
+    let unsigned_transaction =
+        bincode::serialize(&transaction).map_err(|e| BioCypherError::Solana(e.to_string()))?;
+
+    Ok(SignOnlyAttestation {
+        message,
+        unsigned_transaction,
+        signer: payer,
+        recent_blockhash,
+        record_pda,
+    })
+}
+
+/// Insert an externally produced Ed25519 `signature` from `signer_pubkey`
+/// into `unsigned_transaction`'s matching signature slot, rejecting it if
+/// it doesn't verify against the transaction's message — so a bad or
+/// mismatched signature is caught here rather than surfacing as a
+/// confusing on-chain rejection when the backend later rebroadcasts it.
+pub fn apply_signature(
+    unsigned_transaction: &[u8],
+    signer_pubkey: Pubkey,
+    signature: Signature,
+) -> Result<Vec<u8>> {
+    let mut transaction: Transaction = bincode::deserialize(unsigned_transaction)
+        .map_err(|e| BioCypherError::Solana(format!("Invalid unsigned transaction: {e}")))?;
+
+    let slot = transaction
+        .message
+        .account_keys
+        .iter()
+        .position(|key| *key == signer_pubkey)
+        .filter(|&i| i < transaction.signatures.len())
+        .ok_or_else(|| {
+            BioCypherError::Solana("Signer is not a required signer of this transaction".into())
+        })?;
+
+    if !signature.verify(signer_pubkey.as_ref(), &transaction.message.serialize()) {
+        return Err(BioCypherError::Solana(
+            "Signature does not verify against the transaction message".into(),
+        ));
+    }
+
+    transaction.signatures[slot] = signature;
+    bincode::serialize(&transaction).map_err(|e| BioCypherError::Solana(e.to_string()))
+}
+
+/// Build an unsigned transaction that creates and initializes a new
+/// durable nonce account authorized to `nonce_authority`, funded by
+/// `payer`. The new nonce account keypair must also sign this transaction
+/// (it authorizes its own account creation), in addition to `payer`; once
+/// submitted, its pubkey can be passed as `NonceConfig::nonce_account` to
+/// [`build_attest_transaction`].
+pub async fn build_create_nonce_account_transaction(
+    payer: Pubkey,
+    nonce_account: Pubkey,
+    nonce_authority: Pubkey,
+) -> Result<Vec<u8>> {
+    let rpc_url = std::env::var("SOLANA_RPC_URL")
+        .unwrap_or_else(|_| "http://127.0.0.1:8899".to_string());
+
+    let client = Arc::new(RpcClient::new_with_commitment(
+        rpc_url,
+        CommitmentConfig::confirmed(),
+    ));
+
+    let rent = client
+        .get_minimum_balance_for_rent_exemption(NonceState::size())
+        .await
+        .map_err(|e| BioCypherError::Solana(e.to_string()))?;
+
+    let instructions =
+        system_instruction::create_nonce_account(&payer, &nonce_account, &nonce_authority, rent);
+
+    let blockhash = client
+        .get_latest_blockhash()
+        .await
+        .map_err(|e| BioCypherError::Solana(e.to_string()))?;
+
+    let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer));
+    transaction.message.recent_blockhash = blockhash;
+    bincode::serialize(&transaction).map_err(|e| BioCypherError::Solana(e.to_string()))
+}
+
+/// Build an unsigned transaction recording just a batch's Merkle root
+/// on-chain, for a client wallet to sign — the write counterpart to
+/// `GET /api/attest/proof/{root}/{index}`, which serves an inclusion
+/// proof against whatever root actually lands. Does not require a server
+/// keypair; uses RPC only for a blockhash, mirroring
+/// [`build_attest_transaction`].
+pub async fn build_attest_root_transaction(payer: Pubkey, root: [u8; 32]) -> Result<Vec<u8>> {
+    let rpc_url = std::env::var("SOLANA_RPC_URL")
+        .unwrap_or_else(|_| "http://127.0.0.1:8899".to_string());
+    let program_id = Pubkey::from_str(
+        std::env::var("BIOCYPHER_STORAGE_PROGRAM_ID")
+            .as_deref()
+            .unwrap_or(DEFAULT_PROGRAM_ID),
+    )
+    .map_err(|_| BioCypherError::Solana("Invalid program ID".into()))?;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    let (batch_root_record, _) = Pubkey::find_program_address(
+        &[b"attest_batch_root", payer.as_ref(), &root],
+        &program_id,
+    );
+
+    let mut data = instruction_discriminator("record_attest_batch_root").to_vec();
+    data.extend_from_slice(&root);
+    data.extend_from_slice(&timestamp.to_le_bytes());
+
+    let ix = Instruction {
+        program_id,
+        accounts: vec![
+            AccountMeta::new(batch_root_record, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
     };
 
     let client = Arc::new(RpcClient::new_with_commitment(
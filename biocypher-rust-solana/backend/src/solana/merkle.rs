@@ -0,0 +1,405 @@
+//! Merkle tree batching for on-chain safety attestations.
+//!
+//! Safety screening results are accumulated as `(seq_hash, safety_status)`
+//! leaves and periodically committed as a single 32-byte Merkle root in one
+//! Solana transaction, instead of one transaction per sequence (see
+//! [`crate::solana::batch`]). Each caller receives a [`MerkleProof`] proving
+//! their leaf's inclusion in the committed root, which [`verify_inclusion`]
+//! lets any third party confirm without trusting the server.
+
+use sha2::{Digest, Sha256};
+
+use crate::models::SafetyStatus;
+
+fn safety_status_to_u8(status: SafetyStatus) -> u8 {
+    match status {
+        SafetyStatus::Safe => 0,
+        SafetyStatus::Caution => 1,
+        SafetyStatus::Unsafe => 2,
+    }
+}
+
+/// Hash a `(seq_hash, safety_status)` pair into a Merkle leaf.
+pub fn leaf_hash(seq_hash: [u8; 32], status: SafetyStatus) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"biocypher-safety-leaf:");
+    hasher.update(seq_hash);
+    hasher.update([safety_status_to_u8(status)]);
+    let result = hasher.finalize();
+    let mut arr = [0u8; 32];
+    arr.copy_from_slice(&result);
+    arr
+}
+
+fn parent_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"biocypher-safety-node:");
+    hasher.update(left);
+    hasher.update(right);
+    let result = hasher.finalize();
+    let mut arr = [0u8; 32];
+    arr.copy_from_slice(&result);
+    arr
+}
+
+/// Lowercase hex encoding, for serializing hashes over JSON.
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Parse a lowercase hex string produced by [`to_hex`] back into bytes.
+/// Returns `None` if the string has an odd length or contains non-hex
+/// characters.
+pub fn from_hex(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return None;
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// One step from a leaf up to the root: the sibling hash at that level and
+/// whether the sibling sits to the left of the node being hashed (if not,
+/// it's to the right).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofStep {
+    pub sibling: [u8; 32],
+    pub sibling_is_left: bool,
+}
+
+/// Ordered list of [`ProofStep`]s from a leaf to a tree's root.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MerkleProof {
+    pub steps: Vec<ProofStep>,
+}
+
+/// A Merkle tree built over an ordered batch of leaf hashes.
+///
+/// A level with an odd number of nodes duplicates its last node to pair
+/// with itself, matching the common Bitcoin-style convention so every leaf
+/// count produces an unambiguous tree shape.
+pub struct MerkleTree {
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    /// Build a tree over `leaves`.
+    ///
+    /// # Panics
+    /// Panics if `leaves` is empty; callers must only build a tree once a
+    /// batch has at least one leaf.
+    pub fn build(leaves: Vec<[u8; 32]>) -> Self {
+        assert!(!leaves.is_empty(), "cannot build a Merkle tree over zero leaves");
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let current = levels.last().unwrap();
+            let mut next = Vec::with_capacity((current.len() + 1) / 2);
+            for pair in current.chunks(2) {
+                let left = &pair[0];
+                let right = pair.get(1).unwrap_or(left);
+                next.push(parent_hash(left, right));
+            }
+            levels.push(next);
+        }
+        Self { levels }
+    }
+
+    /// The tree's root hash.
+    pub fn root(&self) -> [u8; 32] {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// Number of leaves the tree was built over.
+    pub fn leaf_count(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Build the inclusion proof for the leaf at `index`, or `None` if
+    /// `index` is out of range.
+    pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+        if index >= self.leaf_count() {
+            return None;
+        }
+        let mut steps = Vec::new();
+        let mut idx = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling_idx = if idx % 2 == 0 { idx + 1 } else { idx - 1 };
+            let sibling = *level.get(sibling_idx).unwrap_or(&level[idx]);
+            steps.push(ProofStep {
+                sibling,
+                sibling_is_left: idx % 2 == 1,
+            });
+            idx /= 2;
+        }
+        Some(MerkleProof { steps })
+    }
+}
+
+/// Recompute the Merkle root from `leaf` and `proof`, and check it matches
+/// `expected_root`.
+///
+/// Lets a third party confirm a sequence's safety verdict was committed
+/// on-chain under `expected_root` without trusting whoever issued the
+/// proof.
+pub fn verify_inclusion(leaf: [u8; 32], proof: &MerkleProof, expected_root: [u8; 32]) -> bool {
+    let mut current = leaf;
+    for step in &proof.steps {
+        current = if step.sibling_is_left {
+            parent_hash(&step.sibling, &current)
+        } else {
+            parent_hash(&current, &step.sibling)
+        };
+    }
+    current == expected_root
+}
+
+/// Leaf hashing for [`AttestMerkleTree`]: `sha256(0x00 || seq_hash)`.
+///
+/// Unlike [`leaf_hash`] above (which is specific to safety-screening
+/// results and tags with an ASCII domain string), this is the generic
+/// batching scheme used by `POST /api/attest/batch` for any encode/decode/
+/// safety attestation, and matches the single-byte domain-separation
+/// convention common to Merkle-backed transparency logs.
+pub fn attest_leaf_hash(seq_hash: [u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([0x00]);
+    hasher.update(seq_hash);
+    let result = hasher.finalize();
+    let mut arr = [0u8; 32];
+    arr.copy_from_slice(&result);
+    arr
+}
+
+fn attest_parent_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([0x01]);
+    hasher.update(left);
+    hasher.update(right);
+    let result = hasher.finalize();
+    let mut arr = [0u8; 32];
+    arr.copy_from_slice(&result);
+    arr
+}
+
+/// A Merkle tree over generic attestation-batch leaves.
+///
+/// Unlike [`MerkleTree`], an odd node at a level is promoted to the next
+/// level unchanged instead of being paired with a duplicate of itself:
+/// duplicate-last would let a forged proof claim membership for a
+/// non-existent leaf via a second preimage of that level's last internal
+/// node.
+pub struct AttestMerkleTree {
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl AttestMerkleTree {
+    /// Build a tree over `leaves`.
+    ///
+    /// # Panics
+    /// Panics if `leaves` is empty; callers must only build a tree once a
+    /// batch has at least one leaf.
+    pub fn build(leaves: Vec<[u8; 32]>) -> Self {
+        assert!(!leaves.is_empty(), "cannot build a Merkle tree over zero leaves");
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let current = levels.last().unwrap();
+            let mut next = Vec::with_capacity((current.len() + 1) / 2);
+            for pair in current.chunks(2) {
+                let node = match pair {
+                    [left, right] => attest_parent_hash(left, right),
+                    [only] => *only,
+                    _ => unreachable!("chunks(2) never yields more than 2 elements"),
+                };
+                next.push(node);
+            }
+            levels.push(next);
+        }
+        Self { levels }
+    }
+
+    /// The tree's root hash.
+    pub fn root(&self) -> [u8; 32] {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// Number of leaves the tree was built over.
+    pub fn leaf_count(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Number of levels between a leaf and the root (0 for a single-leaf
+    /// tree, whose root is the leaf itself).
+    pub fn height(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    /// Build the inclusion proof for the leaf at `index`, or `None` if
+    /// `index` is out of range.
+    pub fn proof(&self, index: usize) -> Option<MerkleProof> {
+        if index >= self.leaf_count() {
+            return None;
+        }
+        let mut steps = Vec::new();
+        let mut idx = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            let promoted_unchanged = level.len() % 2 == 1 && idx == level.len() - 1;
+            if !promoted_unchanged {
+                let sibling_idx = if idx % 2 == 0 { idx + 1 } else { idx - 1 };
+                steps.push(ProofStep {
+                    sibling: level[sibling_idx],
+                    sibling_is_left: idx % 2 == 1,
+                });
+            }
+            idx /= 2;
+        }
+        Some(MerkleProof { steps })
+    }
+}
+
+/// Recompute an [`AttestMerkleTree`] root from `leaf` and `proof`, and
+/// check it matches `expected_root`.
+pub fn verify_attest_inclusion(leaf: [u8; 32], proof: &MerkleProof, expected_root: [u8; 32]) -> bool {
+    let mut current = leaf;
+    for step in &proof.steps {
+        current = if step.sibling_is_left {
+            attest_parent_hash(&step.sibling, &current)
+        } else {
+            attest_parent_hash(&current, &step.sibling)
+        };
+    }
+    current == expected_root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf(byte: u8) -> [u8; 32] {
+        [byte; 32]
+    }
+
+    #[test]
+    fn test_single_leaf_tree_root_is_the_leaf() {
+        let tree = MerkleTree::build(vec![leaf(1)]);
+        assert_eq!(tree.root(), leaf(1));
+        let proof = tree.proof(0).unwrap();
+        assert!(proof.steps.is_empty());
+        assert!(verify_inclusion(leaf(1), &proof, tree.root()));
+    }
+
+    #[test]
+    fn test_every_leaf_proof_verifies_against_root() {
+        let leaves: Vec<[u8; 32]> = (0..7u8).map(leaf).collect();
+        let tree = MerkleTree::build(leaves.clone());
+        let root = tree.root();
+        for (i, l) in leaves.iter().enumerate() {
+            let proof = tree.proof(i).unwrap();
+            assert!(verify_inclusion(*l, &proof, root), "leaf {} failed to verify", i);
+        }
+    }
+
+    #[test]
+    fn test_tampered_leaf_fails_verification() {
+        let leaves: Vec<[u8; 32]> = (0..5u8).map(leaf).collect();
+        let tree = MerkleTree::build(leaves);
+        let proof = tree.proof(2).unwrap();
+        assert!(!verify_inclusion(leaf(99), &proof, tree.root()));
+    }
+
+    #[test]
+    fn test_tampered_sibling_fails_verification() {
+        let leaves: Vec<[u8; 32]> = (0..5u8).map(leaf).collect();
+        let tree = MerkleTree::build(leaves.clone());
+        let mut proof = tree.proof(1).unwrap();
+        proof.steps[0].sibling = leaf(99);
+        assert!(!verify_inclusion(leaves[1], &proof, tree.root()));
+    }
+
+    #[test]
+    fn test_proof_out_of_range_is_none() {
+        let tree = MerkleTree::build(vec![leaf(1), leaf(2)]);
+        assert!(tree.proof(2).is_none());
+    }
+
+    #[test]
+    fn test_odd_leaf_count_duplicates_last_leaf() {
+        let leaves = vec![leaf(1), leaf(2), leaf(3)];
+        let tree = MerkleTree::build(leaves.clone());
+        let expected_root = parent_hash(
+            &parent_hash(&leaves[0], &leaves[1]),
+            &parent_hash(&leaves[2], &leaves[2]),
+        );
+        assert_eq!(tree.root(), expected_root);
+    }
+
+    #[test]
+    fn test_leaf_hash_differs_by_status() {
+        let seq_hash = [7u8; 32];
+        let safe = leaf_hash(seq_hash, SafetyStatus::Safe);
+        let unsafe_ = leaf_hash(seq_hash, SafetyStatus::Unsafe);
+        assert_ne!(safe, unsafe_);
+    }
+
+    #[test]
+    fn test_to_hex_roundtrips_known_value() {
+        assert_eq!(to_hex(&[0xde, 0xad, 0xbe, 0xef]), "deadbeef");
+    }
+
+    #[test]
+    fn test_from_hex_roundtrips_to_hex() {
+        let bytes = [0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(from_hex(&to_hex(&bytes)).unwrap(), bytes.to_vec());
+    }
+
+    #[test]
+    fn test_from_hex_rejects_odd_length_and_non_hex() {
+        assert!(from_hex("abc").is_none());
+        assert!(from_hex("zz").is_none());
+    }
+
+    #[test]
+    fn test_attest_tree_single_leaf_root_is_the_leaf() {
+        let tree = AttestMerkleTree::build(vec![leaf(1)]);
+        assert_eq!(tree.root(), leaf(1));
+        assert_eq!(tree.height(), 0);
+        let proof = tree.proof(0).unwrap();
+        assert!(proof.steps.is_empty());
+        assert!(verify_attest_inclusion(leaf(1), &proof, tree.root()));
+    }
+
+    #[test]
+    fn test_attest_tree_every_leaf_proof_verifies_against_root() {
+        let leaves: Vec<[u8; 32]> = (0..7u8).map(leaf).collect();
+        let tree = AttestMerkleTree::build(leaves.clone());
+        let root = tree.root();
+        for (i, l) in leaves.iter().enumerate() {
+            let proof = tree.proof(i).unwrap();
+            assert!(verify_attest_inclusion(*l, &proof, root), "leaf {} failed to verify", i);
+        }
+    }
+
+    #[test]
+    fn test_attest_tree_odd_node_is_promoted_not_duplicated() {
+        let leaves = vec![leaf(1), leaf(2), leaf(3)];
+        let tree = AttestMerkleTree::build(leaves.clone());
+        // Level 1: [parent(1,2), 3] (3 promoted unchanged, not parent(3,3)).
+        let expected_root = attest_parent_hash(&attest_parent_hash(&leaves[0], &leaves[1]), &leaves[2]);
+        assert_eq!(tree.root(), expected_root);
+    }
+
+    #[test]
+    fn test_attest_tree_tampered_leaf_fails_verification() {
+        let leaves: Vec<[u8; 32]> = (0..5u8).map(leaf).collect();
+        let tree = AttestMerkleTree::build(leaves);
+        let proof = tree.proof(2).unwrap();
+        assert!(!verify_attest_inclusion(leaf(99), &proof, tree.root()));
+    }
+
+    #[test]
+    fn test_attest_leaf_hash_differs_from_safety_leaf_hash() {
+        let seq_hash = [7u8; 32];
+        assert_ne!(attest_leaf_hash(seq_hash), leaf_hash(seq_hash, SafetyStatus::Safe));
+    }
+}
@@ -3,6 +3,18 @@
 //! Records encode, decode, and safety attestations on the biocypher-storage program.
 //! When SOLANA_RPC_URL or SOLANA_KEYPAIR_PATH are unset, all operations are no-op.
 
+pub mod attest_batch;
+pub mod batch;
 pub mod client;
+pub mod merkle;
+pub mod tx_sender;
+pub mod wormhole;
 
-pub use client::{hash_sequence, SolanaClient};
+pub use attest_batch::AttestProofStore;
+pub use batch::SafetyBatcher;
+pub use client::{
+    apply_signature, build_attest_root_transaction, build_attest_sign_only, build_attest_transaction,
+    build_create_nonce_account_transaction, hash_sequence, ModeAttestationRecord, NonceConfig,
+    SafetyAttestationRecord, SignOnlyAttestation, SolanaClient,
+};
+pub use tx_sender::TxSender;
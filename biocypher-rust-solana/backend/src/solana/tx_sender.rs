@@ -0,0 +1,188 @@
+//! Resilient transaction submission: a background-refreshed blockhash
+//! cache, `send_transaction`-based (non-blocking-confirm) submission, and
+//! rebroadcast-until-confirmed polling.
+//!
+//! [`SolanaClient::send_transaction_resilient`](crate::solana::SolanaClient::send_transaction_resilient)
+//! is the write path for bursts of attestations under load, where the
+//! plain `send_and_confirm_transaction_with_spinner`-based
+//! `send_transaction` blocks a request thread per call and silently drops
+//! anything the leader doesn't forward.
+
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcSendTransactionConfig;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    hash::Hash,
+    instruction::Instruction,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use tokio::sync::RwLock;
+use tokio::time::sleep;
+
+use crate::error::{BioCypherError, Result};
+
+/// How often the background task refreshes the cached blockhash.
+const BLOCKHASH_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often a still-unconfirmed transaction is rebroadcast.
+const REBROADCAST_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How often signature statuses are polled between rebroadcasts.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+struct CachedBlockhash {
+    blockhash: Hash,
+    last_valid_block_height: u64,
+}
+
+/// Background-refreshed cache of the latest blockhash, so bursts of
+/// transaction builders don't each pay an RPC round-trip just to get one.
+struct BlockhashCache {
+    inner: RwLock<Option<CachedBlockhash>>,
+}
+
+impl BlockhashCache {
+    fn new() -> Self {
+        Self {
+            inner: RwLock::new(None),
+        }
+    }
+
+    async fn get(&self) -> Option<(Hash, u64)> {
+        self.inner
+            .read()
+            .await
+            .as_ref()
+            .map(|c| (c.blockhash, c.last_valid_block_height))
+    }
+
+    async fn refresh(&self, client: &RpcClient) -> Result<(Hash, u64)> {
+        let (blockhash, last_valid_block_height) = client
+            .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
+            .await
+            .map_err(|e| BioCypherError::Solana(e.to_string()))?;
+        *self.inner.write().await = Some(CachedBlockhash {
+            blockhash,
+            last_valid_block_height,
+        });
+        Ok((blockhash, last_valid_block_height))
+    }
+}
+
+/// One blockhash cache and refresh loop per process, shared by every
+/// [`TxSender`] regardless of how many are created — `SolanaClient` is
+/// cheaply reconstructed per request, but the cache and its background
+/// task should not be.
+static BLOCKHASH_CACHE: OnceLock<Arc<BlockhashCache>> = OnceLock::new();
+
+fn shared_blockhash_cache(client: Arc<RpcClient>) -> Arc<BlockhashCache> {
+    BLOCKHASH_CACHE
+        .get_or_init(|| {
+            let cache = Arc::new(BlockhashCache::new());
+            let background_cache = cache.clone();
+            tokio::spawn(async move {
+                loop {
+                    let _ = background_cache.refresh(&client).await;
+                    sleep(BLOCKHASH_REFRESH_INTERVAL).await;
+                }
+            });
+            cache
+        })
+        .clone()
+}
+
+/// Resilient transaction sender. Submits with `send_transaction`
+/// (optionally skipping preflight) against a cached blockhash, then polls
+/// `get_signature_statuses` and rebroadcasts the same signed transaction
+/// every [`REBROADCAST_INTERVAL`] until it confirms or its blockhash's
+/// last-valid-block-height is exceeded.
+pub struct TxSender {
+    client: Arc<RpcClient>,
+    blockhash_cache: Arc<BlockhashCache>,
+    skip_preflight: bool,
+}
+
+impl TxSender {
+    pub fn new(rpc_url: String, skip_preflight: bool) -> Self {
+        let client = Arc::new(RpcClient::new_with_commitment(
+            rpc_url,
+            CommitmentConfig::confirmed(),
+        ));
+        let blockhash_cache = shared_blockhash_cache(client.clone());
+        Self {
+            client,
+            blockhash_cache,
+            skip_preflight,
+        }
+    }
+
+    async fn recent_blockhash(&self) -> Result<(Hash, u64)> {
+        if let Some(cached) = self.blockhash_cache.get().await {
+            return Ok(cached);
+        }
+        self.blockhash_cache.refresh(&self.client).await
+    }
+
+    /// Sign, submit, and confirm `instruction`, rebroadcasting it until it
+    /// confirms or its blockhash expires. Returns the signature and
+    /// whether it ultimately confirmed.
+    pub async fn send_resilient(
+        &self,
+        instruction: Instruction,
+        payer: &Keypair,
+    ) -> Result<(Signature, bool)> {
+        let (blockhash, last_valid_block_height) = self.recent_blockhash().await?;
+
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        );
+        let signature = transaction.signatures[0];
+
+        let send_config = RpcSendTransactionConfig {
+            skip_preflight: self.skip_preflight,
+            ..RpcSendTransactionConfig::default()
+        };
+
+        let mut next_broadcast_at = std::time::Instant::now();
+
+        loop {
+            let current_height = self
+                .client
+                .get_block_height()
+                .await
+                .map_err(|e| BioCypherError::Solana(e.to_string()))?;
+            if current_height > last_valid_block_height {
+                return Ok((signature, false));
+            }
+
+            if std::time::Instant::now() >= next_broadcast_at {
+                // Best-effort: a dropped rebroadcast just gets retried on
+                // the next tick, so failures here aren't fatal.
+                let _ = self
+                    .client
+                    .send_transaction_with_config(&transaction, send_config)
+                    .await;
+                next_broadcast_at = std::time::Instant::now() + REBROADCAST_INTERVAL;
+            }
+
+            let statuses = self
+                .client
+                .get_signature_statuses(&[signature])
+                .await
+                .map_err(|e| BioCypherError::Solana(e.to_string()))?;
+
+            if let Some(Some(status)) = statuses.value.into_iter().next() {
+                return Ok((signature, status.err.is_none()));
+            }
+
+            sleep(POLL_INTERVAL).await;
+        }
+    }
+}
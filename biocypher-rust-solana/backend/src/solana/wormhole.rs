@@ -0,0 +1,162 @@
+//! Optional cross-chain mirroring of safety attestations through the
+//! Wormhole Core Bridge, so an off-Solana verifier (e.g. an EVM contract)
+//! can confirm a sequence was safety-screened without trusting this
+//! server directly.
+//!
+//! Off by default: [`post_attestation_message`] returns `Ok(None)` unless
+//! `WORMHOLE_CORE_BRIDGE_ID` is set, mirroring how [`crate::solana::SolanaClient::from_env`]
+//! treats Solana itself as disabled when unconfigured.
+//!
+//! Unlike `biocypher-storage` and the Arcium MXE program, the Core Bridge
+//! is not an Anchor program — it dispatches instructions by a single
+//! leading enum-variant byte rather than an 8-byte `sha256("global:name")`
+//! discriminator, so this module doesn't reuse `instruction_discriminator`.
+
+use std::str::FromStr;
+use std::sync::Arc;
+
+use rand::RngCore;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcSendTransactionConfig;
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    system_program::ID as SYSTEM_PROGRAM_ID,
+    sysvar::clock,
+    transaction::Transaction,
+};
+
+use crate::error::{BioCypherError, Result};
+use crate::models::SafetyStatus;
+
+/// Core Bridge `post_message` instruction variant byte.
+const POST_MESSAGE_INSTRUCTION: u8 = 1;
+
+/// Placeholder `post_message` fee in lamports, used when the live fee
+/// can't be read off the bridge config account (devnet/testnet
+/// deployments commonly charge zero or a small fixed amount).
+const DEFAULT_BRIDGE_FEE_LAMPORTS: u64 = 100;
+
+fn core_bridge_id() -> Option<Pubkey> {
+    let raw = std::env::var("WORMHOLE_CORE_BRIDGE_ID").ok()?;
+    Pubkey::from_str(&raw).ok()
+}
+
+fn bridge_config_pda(bridge: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"Bridge"], bridge).0
+}
+
+fn fee_collector_pda(bridge: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"fee_collector"], bridge).0
+}
+
+fn emitter_sequence_pda(bridge: &Pubkey, emitter: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"Sequence", emitter.as_ref()], bridge).0
+}
+
+/// Compact cross-chain payload for a safety attestation: the sequence
+/// hash, its status, when it was recorded, and the nonce Wormhole uses
+/// for message deduplication.
+fn encode_payload(sequence_hash: [u8; 32], status: SafetyStatus, timestamp: i64, nonce: u32) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(32 + 1 + 8 + 4);
+    payload.extend_from_slice(&sequence_hash);
+    payload.push(match status {
+        SafetyStatus::Safe => 0,
+        SafetyStatus::Caution => 1,
+        SafetyStatus::Unsafe => 2,
+    });
+    payload.extend_from_slice(&timestamp.to_le_bytes());
+    payload.extend_from_slice(&nonce.to_le_bytes());
+    payload
+}
+
+/// Build, pay for, and submit a Wormhole Core Bridge `post_message`
+/// mirroring a safety attestation cross-chain.
+///
+/// Returns `Ok(None)` when `WORMHOLE_CORE_BRIDGE_ID` is unset (bridging
+/// is off by default). Otherwise returns `Ok(Some(sequence))`: the
+/// emitter's Wormhole sequence number, which a caller can use to fetch
+/// the signed VAA from a guardian/spy endpoint and redeem it on the
+/// destination chain.
+pub async fn post_attestation_message(
+    rpc_url: &str,
+    payer: &Keypair,
+    sequence_hash: [u8; 32],
+    status: SafetyStatus,
+    timestamp: i64,
+) -> Result<Option<u64>> {
+    let Some(bridge) = core_bridge_id() else {
+        return Ok(None);
+    };
+
+    let bridge_config = bridge_config_pda(&bridge);
+    let fee_collector = fee_collector_pda(&bridge);
+    let emitter_sequence = emitter_sequence_pda(&bridge, &payer.pubkey());
+
+    // Wormhole messages live in a fresh account per post, not a PDA.
+    let message_account = Keypair::new();
+    let nonce = rand::thread_rng().next_u32();
+    let payload = encode_payload(sequence_hash, status, timestamp, nonce);
+
+    let pay_fee = system_instruction::transfer(&payer.pubkey(), &fee_collector, DEFAULT_BRIDGE_FEE_LAMPORTS);
+
+    let mut data = vec![POST_MESSAGE_INSTRUCTION];
+    data.extend_from_slice(&nonce.to_le_bytes());
+    data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    data.extend_from_slice(&payload);
+    data.push(0); // finality: 0 = confirmed
+
+    let post_message = Instruction {
+        program_id: bridge,
+        accounts: vec![
+            AccountMeta::new(bridge_config, false),
+            AccountMeta::new(message_account.pubkey(), true),
+            AccountMeta::new_readonly(payer.pubkey(), true),
+            AccountMeta::new(emitter_sequence, false),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(fee_collector, false),
+            AccountMeta::new_readonly(clock::ID, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data,
+    };
+
+    let client = Arc::new(RpcClient::new_with_commitment(
+        rpc_url.to_string(),
+        CommitmentConfig::confirmed(),
+    ));
+    let recent_blockhash = client
+        .get_latest_blockhash()
+        .await
+        .map_err(|e| BioCypherError::Solana(e.to_string()))?;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[pay_fee, post_message],
+        Some(&payer.pubkey()),
+        &[payer, &message_account],
+        recent_blockhash,
+    );
+
+    client
+        .send_and_confirm_transaction_with_spinner_and_config(
+            &transaction,
+            CommitmentConfig::confirmed(),
+            RpcSendTransactionConfig::default(),
+        )
+        .await
+        .map_err(|e| BioCypherError::Solana(e.to_string()))?;
+
+    let sequence_data = client
+        .get_account_data(&emitter_sequence)
+        .await
+        .map_err(|e| BioCypherError::Solana(e.to_string()))?;
+    let sequence_bytes: [u8; 8] = sequence_data
+        .get(..8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| BioCypherError::Solana("Malformed Wormhole sequence tracker account".into()))?;
+
+    Ok(Some(u64::from_le_bytes(sequence_bytes)))
+}